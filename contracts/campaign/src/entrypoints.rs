@@ -94,6 +94,7 @@ pub fn execute(
         }
         ExecuteMsg::ClaimParticipationReward {} => crate::executions::claim_participation_reward(deps, env, info),
         ExecuteMsg::ClaimReferralReward {} => crate::executions::claim_referral_reward(deps, env, info),
+        ExecuteMsg::ClaimRewardFor { actor } => crate::executions::claim_reward_for(deps, env, info, actor),
         ExecuteMsg::Participate { actor, referrer } => {
             crate::executions::participate(deps, env, info, actor, referrer)
         },
@@ -1,13 +1,12 @@
 use cosmwasm_std::{Addr, Api, attr, Binary, CosmosMsg, Decimal, DepsMut, Env, from_binary, MessageInfo, QuerierWrapper, Reply, ReplyOn, Response, StdError, StdResult, Storage, SubMsg, to_binary, Uint128, WasmMsg};
 use cw20::{Cw20ExecuteMsg, Denom as Cw20Denom};
 use protobuf::Message;
-use terraswap::asset::AssetInfo;
-use terraswap::router::{QueryMsg, SimulateSwapOperationsResponse, SwapOperation};
 
 use valkyrie::campaign::enumerations::Referrer;
 use valkyrie::campaign::execute_msgs::{CampaignConfigMsg, DistributeResult, MigrateMsg, ReferralReward};
+use valkyrie::campaign_manager::enumerations::OverflowMode;
 use valkyrie::campaign_manager::execute_msgs::CampaignInstantiateMsg;
-use valkyrie::campaign_manager::query_msgs::ReferralRewardLimitOptionResponse;
+use valkyrie::campaign_manager::query_msgs::{QueryMsg as CampaignManagerQueryMsg, ReferralRewardLimitOptionResponse, PriceResponse, SimulateSwapResponse};
 use valkyrie::common::{ContractResult, Denom};
 use valkyrie::errors::ContractError;
 use valkyrie::message_factories;
@@ -316,6 +315,7 @@ pub fn add_reward_pool(
     let (key_denom, referral_reward_pool_ratio, add_pool_value) = validate_reward_pool_weight(
         &deps.querier,
         deps.api,
+        &env.contract.address,
         &campaign_config,
         &reward_config,
         participation_reward_amount,
@@ -329,12 +329,22 @@ pub fn add_reward_pool(
     response = response.add_attribute("referral_reward_pool_ratio", referral_reward_pool_ratio.to_string());
     response = response.add_attribute("add_pool_value", add_pool_value);
 
-    let global_campaign_config = load_global_campaign_config(&deps.querier, &campaign_config.campaign_manager)?;
+    let fee_quote = load_add_pool_fee_quote(
+        &deps.querier,
+        &campaign_config.campaign_manager,
+        &env.contract.address,
+        &campaign_config,
+    )?;
+    response = response.add_attribute("add_pool_fee_rate", fee_quote.fee_rate.to_string());
+    response = response.add_attribute(
+        "add_pool_fee_tier",
+        fee_quote.tier.map_or_else(|| "none".to_string(), |tier| tier.to_string()),
+    );
 
     let add_pool_fee_amount = calc_add_pool_fee_amount(
         referral_reward_amount,
         referral_reward_pool_ratio,
-        global_campaign_config.add_pool_fee_rate,
+        fee_quote.fee_rate,
     )?;
     response = response.add_attribute("add_pool_fee_amount", add_pool_fee_amount.to_string());
 
@@ -423,20 +433,27 @@ fn validate_native_send(
 fn validate_reward_pool_weight(
     querier: &QuerierWrapper,
     api: &dyn Api,
+    campaign: &Addr,
     campaign_config: &CampaignConfig,
     reward_config: &RewardConfig,
     participation_reward_amount: Uint128,
     referral_reward_amount: Uint128,
 ) -> StdResult<(cw20::Denom, Decimal, Uint128)> {
-    let global_campaign_config = load_global_campaign_config(
+    let effective_config = load_effective_campaign_config(
         &querier,
         &campaign_config.campaign_manager,
+        campaign,
     )?;
+    if effective_config.delisted {
+        return Err(StdError::generic_err("Delisted campaign"));
+    }
+    let global_campaign_config = effective_config.config;
     let key_denom = global_campaign_config.key_denom.to_cw20(api);
 
     let participation_reward_value = swap_simulate(
         &querier,
-        &global_campaign_config.terraswap_router,
+        &campaign_config.campaign_manager,
+        global_campaign_config.spot_price_fallback,
         reward_config.participation_reward_denom.clone(),
         key_denom.clone(),
         participation_reward_amount,
@@ -444,7 +461,8 @@ fn validate_reward_pool_weight(
 
     let referral_reward_value = swap_simulate(
         &querier,
-        &global_campaign_config.terraswap_router,
+        &campaign_config.campaign_manager,
+        global_campaign_config.spot_price_fallback,
         cw20::Denom::Cw20(reward_config.referral_reward_token.clone()),
         key_denom.clone(),
         referral_reward_amount,
@@ -467,7 +485,8 @@ fn validate_reward_pool_weight(
 
 fn swap_simulate(
     querier: &QuerierWrapper,
-    terraswap_router: &String,
+    campaign_manager: &Addr,
+    spot_price_fallback: bool,
     offer: cw20::Denom,
     ask: cw20::Denom,
     amount: Uint128,
@@ -476,49 +495,44 @@ fn swap_simulate(
         return Ok(amount);
     }
 
-    let response: SimulateSwapOperationsResponse = querier.query_wasm_smart(
-        terraswap_router,
-        &QueryMsg::SimulateSwapOperations {
-            offer_amount: amount,
-            operations: vec![swap_operation(offer, ask)],
+    // registered price source (fixed or twap) of campaign manager instead of spot price
+    let price: Option<PriceResponse> = querier.query_wasm_smart(
+        campaign_manager,
+        &CampaignManagerQueryMsg::RegisteredPrice {
+            base: Denom::from_cw20(offer.clone()),
+            quote: Denom::from_cw20(ask.clone()),
         },
     )?;
 
-    Ok(response.amount)
-}
+    if let Some(price) = price {
+        return Ok(amount * price.price);
+    }
 
-fn swap_operation(offer: cw20::Denom, ask: cw20::Denom) -> SwapOperation {
-    match offer {
-        cw20::Denom::Native(offer_denom) => {
-            match ask {
-                cw20::Denom::Native(ask_denom) => SwapOperation::NativeSwap {
-                    offer_denom,
-                    ask_denom,
-                },
-                cw20::Denom::Cw20(ask_token) => SwapOperation::TerraSwap {
-                    offer_asset_info: AssetInfo::NativeToken { denom: offer_denom },
-                    ask_asset_info: AssetInfo::Token { contract_addr: ask_token.to_string() },
-                },
-            }
-        }
-        cw20::Denom::Cw20(offer_token) => {
-            match ask {
-                cw20::Denom::Native(ask_denom) => SwapOperation::TerraSwap {
-                    offer_asset_info: AssetInfo::Token { contract_addr: offer_token.to_string() },
-                    ask_asset_info: AssetInfo::NativeToken { denom: ask_denom },
-                },
-                cw20::Denom::Cw20(ask_token) => SwapOperation::TerraSwap {
-                    offer_asset_info: AssetInfo::Token { contract_addr: offer_token.to_string() },
-                    ask_asset_info: AssetInfo::Token { contract_addr: ask_token.to_string() },
-                },
-            }
-        }
+    if !spot_price_fallback {
+        return Err(StdError::generic_err(format!(
+            "Price source not registered ({} > {})",
+            Denom::from_cw20(offer),
+            Denom::from_cw20(ask),
+        )));
     }
+
+    // campaign manager picks the best route among the routes registered by governance,
+    // only when governance allows the spot price
+    let response: SimulateSwapResponse = querier.query_wasm_smart(
+        campaign_manager,
+        &CampaignManagerQueryMsg::SimulateSwap {
+            offer: Denom::from_cw20(offer),
+            ask: Denom::from_cw20(ask),
+            amount,
+        },
+    )?;
+
+    Ok(response.amount)
 }
 
 pub fn remove_reward_pool(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     denom: Denom,
     amount: Option<Uint128>,
@@ -552,10 +566,12 @@ pub fn remove_reward_pool(
     let mut receive_amount = remove_amount;
     let mut remove_pool_fee_amount = Uint128::zero();
     if !campaign_state.is_pending() {
-        let global_campaign_config = load_global_campaign_config(
+        // delisted campaign can still return its funds
+        let global_campaign_config = load_effective_campaign_config(
             &deps.querier,
             &campaign_config.campaign_manager,
-        )?;
+            &env.contract.address,
+        )?.config;
 
         //destructuring assignments are unstable (https://github.com/rust-lang/rust/issues/71126)
         let (_remove_pool_fee_amount, _receive_amount) = calc_ratio_amount(
@@ -672,6 +688,77 @@ pub fn claim_referral_reward(deps: DepsMut, env: Env, info: MessageInfo) -> Cont
     Ok(response)
 }
 
+pub fn claim_reward_for(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    actor: String,
+) -> ContractResult<Response> {
+    // Validate
+    let campaign_config = CampaignConfig::load(deps.storage)?;
+    if campaign_config.campaign_manager != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let actor = deps.api.addr_validate(&actor)?;
+    let mut actor = Actor::may_load(deps.storage, &actor)?
+        .ok_or(ContractError::NotFound {})?;
+
+    let participation_reward_amount = actor.claim_participation_reward_amount(env.block.height);
+    let referral_reward_amount = actor.claim_referral_reward_amount(env.block.height);
+
+    if participation_reward_amount.is_zero() && referral_reward_amount.is_zero() {
+        return Err(ContractError::Std(StdError::generic_err("Not exist claimable reward")));
+    }
+
+    // Execute
+    let mut response = make_response("claim_reward_for");
+    response = response.add_attribute("actor", actor.address.to_string());
+
+    let reward_config = RewardConfig::load(deps.storage)?;
+    let mut campaign_state = CampaignState::load(deps.storage)?;
+
+    if !participation_reward_amount.is_zero() {
+        campaign_state.unlock_balance(&reward_config.participation_reward_denom, &participation_reward_amount)?;
+        campaign_state.withdraw(&reward_config.participation_reward_denom, &participation_reward_amount)?;
+
+        response = response.add_message(make_send_msg(
+            &deps.querier,
+            reward_config.participation_reward_denom.clone(),
+            participation_reward_amount,
+            &actor.address,
+        )?);
+    }
+
+    if !referral_reward_amount.is_zero() {
+        let referral_reward_denom = cw20::Denom::Cw20(reward_config.referral_reward_token.clone());
+        campaign_state.unlock_balance(&referral_reward_denom, &referral_reward_amount)?;
+        campaign_state.withdraw(&referral_reward_denom, &referral_reward_amount)?;
+
+        response = response.add_message(make_send_msg(
+            &deps.querier,
+            referral_reward_denom,
+            referral_reward_amount,
+            &actor.address,
+        )?);
+    }
+
+    actor.save(deps.storage)?;
+    campaign_state.save(deps.storage)?;
+
+    response = response.add_attribute(
+        "participation_reward_amount",
+        format!(
+            "{}{}",
+            participation_reward_amount,
+            Denom::from_cw20(reward_config.participation_reward_denom),
+        ),
+    );
+    response = response.add_attribute("referral_reward_amount", referral_reward_amount);
+
+    Ok(response)
+}
+
 pub const REPLY_QUALIFY_PARTICIPATION: u64 = 1;
 
 pub fn participate(
@@ -686,7 +773,7 @@ pub fn participate(
 
     let campaign_config = CampaignConfig::load(deps.storage)?;
     let campaign_state = CampaignState::load(deps.storage)?;
-    if !campaign_state.is_active(&campaign_config, &deps.querier, &env.block)? {
+    if !campaign_state.is_active(&campaign_config, &deps.querier, &env)? {
         return Err(ContractError::Std(StdError::generic_err(
             "Inactive campaign",
         )));
@@ -809,10 +896,23 @@ fn _participate(
         deposit.save(storage)?;
     }
 
-    let referral_reward_limit_option: ReferralRewardLimitOptionResponse = querier.query_wasm_smart(
+    let referral_reward_limit_option = load_effective_campaign_config(
+        querier,
         &campaign_config.campaign_manager,
-        &valkyrie::campaign_manager::query_msgs::QueryMsg::ReferralRewardLimitOption {},
-    )?;
+        &env.contract.address,
+    )?.referral_reward_limit_option;
+
+    // referrers need a stake history before their first referral reward
+    if referral_reward_limit_option.staking_window_period != 0 {
+        let stake = load_stake_snapshot(
+            &referral_reward_limit_option,
+            &campaign_config,
+            querier,
+            &actor,
+            env.block.height,
+        )?;
+        my_participation.record_stake_snapshot(stake, referral_reward_limit_option.staking_window_period);
+    }
 
     let distributed_participation_reward_amount = distribute_participation_reward(
         &mut my_participation,
@@ -837,7 +937,24 @@ fn _participate(
     )?;
 
     if !referral_reward_overflow_amount.is_zero() {
-        if let Some(recipient) = referral_reward_limit_option.overflow_amount_recipient {
+        campaign_state.cumulative_overflow_amount += referral_reward_overflow_amount;
+
+        let overflow_msg = match (
+            referral_reward_limit_option.overflow_mode,
+            referral_reward_limit_option.overflow_amount_recipient,
+        ) {
+            (OverflowMode::Recipient, Some(recipient)) => Some(Cw20ExecuteMsg::Transfer {
+                recipient,
+                amount: referral_reward_overflow_amount,
+            }),
+            (OverflowMode::Burn, _) => Some(Cw20ExecuteMsg::Burn {
+                amount: referral_reward_overflow_amount,
+            }),
+            // overflow amount stays in the free referral reward pool
+            _ => None,
+        };
+
+        if let Some(overflow_msg) = overflow_msg {
             campaign_state.withdraw(
                 &cw20::Denom::Cw20(reward_config.referral_reward_token.clone()),
                 &referral_reward_overflow_amount,
@@ -846,10 +963,7 @@ fn _participate(
             response.messages.push(SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
                 contract_addr: reward_config.referral_reward_token.to_string(),
                 funds: vec![],
-                msg: to_binary(&Cw20ExecuteMsg::Transfer {
-                    recipient,
-                    amount: referral_reward_overflow_amount,
-                })?,
+                msg: to_binary(&overflow_msg)?,
             })));
         }
     }
@@ -990,20 +1104,28 @@ fn distribute_referral_reward(
             return Err(StdError::generic_err("Actor must not contain on referrer chain"));
         }
 
-        let reward_limit = calc_referral_reward_limit(
-            &referral_limit_option,
-            &campaign_config,
-            &reward_config,
+        let stake = load_stake_snapshot(
+            referral_limit_option,
+            campaign_config,
             querier,
             &referrer_actor.address,
-        )?.limit_amount;
+            env.block.height,
+        )?;
+        let reward_limit = calc_referral_reward_limit(
+            referral_limit_option,
+            reward_config,
+            referrer_actor,
+            &stake,
+        )?;
+        referrer_actor.record_stake_snapshot(stake, referral_limit_option.staking_window_period);
         let mut actor_receive_amount = *reward_amount;
         let mut actor_overflow_amount = Uint128::zero();
-        let actor_reward_amount = referrer_actor.cumulative_referral_reward_amount + *reward_amount;
-        if reward_limit < actor_reward_amount {
-            actor_overflow_amount = actor_reward_amount.checked_sub(reward_limit)?;
-            actor_receive_amount = actor_receive_amount.checked_sub(actor_overflow_amount)
-                .unwrap_or(Uint128::zero());
+        let actor_reward_amount = reward_limit.window_usage_amount + *reward_amount;
+        if reward_limit.limit_amount < actor_reward_amount {
+            // usage can already exceed a limit lowered since, never withhold more than this reward
+            actor_overflow_amount = actor_reward_amount.checked_sub(reward_limit.limit_amount)?
+                .min(*reward_amount);
+            actor_receive_amount = actor_receive_amount.checked_sub(actor_overflow_amount)?;
         }
 
         referrer_actor.referral_count += 1;
@@ -1012,6 +1134,12 @@ fn distribute_referral_reward(
             reward_config.referral_reward_lock_period + env.block.height,
         );
         referrer_actor.cumulative_referral_reward_amount += actor_receive_amount;
+        referrer_actor.add_referral_reward_usage(
+            actor_receive_amount,
+            env.block.height,
+            referral_limit_option.limit_window_period,
+        );
+        referrer_actor.cumulative_overflow_amount += actor_overflow_amount;
         campaign_state.cumulative_referral_reward_amount += actor_receive_amount;
         campaign_state.lock_balance(&referral_reward_denom, &actor_receive_amount);
         distributed_amount += *reward_amount;
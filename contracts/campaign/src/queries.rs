@@ -6,7 +6,6 @@ use valkyrie::common::{ContractResult, Denom, OrderBy};
 use valkyrie::utils::{compress_addr, put_query_parameter};
 
 use crate::states::*;
-use valkyrie::campaign_manager::query_msgs::ReferralRewardLimitOptionResponse;
 
 pub fn get_campaign_config(deps: Deps, _env: Env) -> ContractResult<CampaignConfigResponse> {
     let campaign_config = CampaignConfig::load(deps.storage)?;
@@ -54,6 +53,7 @@ pub fn get_campaign_state(deps: Deps, env: Env) -> ContractResult<CampaignStateR
         participation_count: state.actor_count,
         cumulative_participation_reward_amount: state.cumulative_participation_reward_amount,
         cumulative_referral_reward_amount: state.cumulative_referral_reward_amount,
+        cumulative_overflow_amount: state.cumulative_overflow_amount,
         locked_balances: state.locked_balances.iter()
             .map(|(denom, amount)| (Denom::from_cw20(denom.clone()), amount.clone()))
             .collect(),
@@ -61,7 +61,7 @@ pub fn get_campaign_state(deps: Deps, env: Env) -> ContractResult<CampaignStateR
             .map(|(denom, amount)| (Denom::from_cw20(denom.clone()), amount.clone()))
             .collect(),
         deposit_amount: state.deposit_amount,
-        is_active: state.is_active(& campaign_config, &deps.querier, &env.block)?,
+        is_active: state.is_active(&campaign_config, &deps.querier, &env)?,
         is_pending: state.is_pending(),
     })
 }
@@ -96,25 +96,35 @@ pub fn get_address_from_referrer(
 
 pub fn get_referral_reward_limit_amount(
     deps: Deps,
-    _env: Env,
+    env: Env,
     address: String,
 ) -> ContractResult<ReferralRewardLimitAmount> {
     let address = deps.api.addr_validate(address.as_str())?;
 
     let config = CampaignConfig::load(deps.storage)?;
-    let option: ReferralRewardLimitOptionResponse = deps.querier.query_wasm_smart(
+    let option = load_effective_campaign_config(
+        &deps.querier,
         &config.campaign_manager,
-        &valkyrie::campaign_manager::query_msgs::QueryMsg::ReferralRewardLimitOption {},
-    )?;
+        &env.contract.address,
+    )?.referral_reward_limit_option;
 
     let reward_config = RewardConfig::load(deps.storage)?;
+    let actor = Actor::may_load(deps.storage, &address)?
+        .unwrap_or_else(|| Actor::new(address.clone(), None));
 
-    Ok(calc_referral_reward_limit(
+    let stake = load_stake_snapshot(
         &option,
         &config,
-        &reward_config,
         &deps.querier,
         &address,
+        env.block.height,
+    )?;
+
+    Ok(calc_referral_reward_limit(
+        &option,
+        &reward_config,
+        &actor,
+        &stake,
     )?)
 }
 
@@ -139,6 +149,7 @@ pub fn get_actor(
         referral_reward_amounts: actor.referral_reward_amounts,
         cumulative_participation_reward_amount: actor.cumulative_participation_reward_amount,
         cumulative_referral_reward_amount: actor.cumulative_referral_reward_amount,
+        cumulative_overflow_amount: actor.cumulative_overflow_amount,
         participation_count: actor.participation_count,
         referral_count: actor.referral_count,
         last_participated_at: actor.last_participated_at,
@@ -168,6 +179,7 @@ pub fn query_actors(
                 referral_reward_amounts: actor.referral_reward_amounts.clone(),
                 cumulative_participation_reward_amount: actor.cumulative_participation_reward_amount,
                 cumulative_referral_reward_amount: actor.cumulative_referral_reward_amount,
+                cumulative_overflow_amount: actor.cumulative_overflow_amount,
                 participation_count: actor.participation_count,
                 referral_count: actor.referral_count,
                 last_participated_at: actor.last_participated_at,
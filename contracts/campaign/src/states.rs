@@ -1,4 +1,4 @@
-use cosmwasm_std::{Addr, BlockInfo, QuerierWrapper, StdError, StdResult, Storage, Timestamp, Uint128, Decimal};
+use cosmwasm_std::{Addr, Env, QuerierWrapper, StdError, StdResult, Storage, Timestamp, Uint128, Decimal};
 use cw20::Denom;
 use cw_storage_plus::{Bound, Item, Map};
 use schemars::JsonSchema;
@@ -8,9 +8,11 @@ use valkyrie::common::OrderBy;
 use valkyrie::campaign_manager::query_msgs::ReferralRewardLimitOptionResponse;
 use valkyrie::campaign::query_msgs::ReferralRewardLimitAmount;
 use valkyrie::governance::query_msgs::StakerStateResponse;
+use valkyrie::lp_staking::query_msgs::StakerInfoResponse;
 
 const MAX_LIMIT: u32 = 30;
 const DEFAULT_LIMIT: u32 = 10;
+const REFERRAL_REWARD_BUCKET_COUNT: u64 = 10;
 
 
 const CAMPAIGN_CONFIG: Item<CampaignConfig> = Item::new("campaign_config");
@@ -64,6 +66,9 @@ pub struct CampaignState {
     pub participation_count: u64,
     pub cumulative_participation_reward_amount: Uint128,
     pub cumulative_referral_reward_amount: Uint128,
+    // default for states saved before overflow tracking
+    #[serde(default)]
+    pub cumulative_overflow_amount: Uint128,
     pub balances: Vec<(Denom, Uint128)>,
     pub locked_balances: Vec<(Denom, Uint128)>,
     pub deposit_amount: Uint128,
@@ -79,6 +84,7 @@ impl CampaignState {
             participation_count: 0,
             cumulative_participation_reward_amount: Uint128::zero(),
             cumulative_referral_reward_amount: Uint128::zero(),
+            cumulative_overflow_amount: Uint128::zero(),
             balances: vec![],
             locked_balances: vec![],
             deposit_amount: Uint128::zero(),
@@ -100,22 +106,26 @@ impl CampaignState {
         &self,
         campaign_config: &CampaignConfig,
         querier: &QuerierWrapper,
-        block: &BlockInfo,
+        env: &Env,
     ) -> StdResult<bool> {
         if !self.active_flag {
             return Ok(false);
         }
 
-        if self.chain_id != block.chain_id {
+        if self.chain_id != env.block.chain_id {
             return Ok(false);
         }
 
-        let global_campaign_config = load_global_campaign_config(
+        let effective_config = load_effective_campaign_config(
             querier,
             &campaign_config.campaign_manager,
+            &env.contract.address,
         )?;
+        if effective_config.delisted {
+            return Ok(false);
+        }
 
-        Ok(global_campaign_config.deactivate_period + self.last_active_height.unwrap_or_default() >= block.height)
+        Ok(effective_config.config.deactivate_period + self.last_active_height.unwrap_or_default() >= env.block.height)
     }
 
     pub fn is_pending(&self) -> bool {
@@ -261,9 +271,18 @@ pub struct Actor {
     pub referral_reward_amounts: Vec<(Uint128, u64)>,
     pub cumulative_participation_reward_amount: Uint128,
     pub cumulative_referral_reward_amount: Uint128,
+    // default for actors saved before overflow tracking
+    #[serde(default)]
+    pub cumulative_overflow_amount: Uint128,
     pub participation_count: u64,
     pub referral_count: u64,
     pub last_participated_at: Timestamp,
+    // default for actors saved before stake snapshots
+    #[serde(default)]
+    pub stake_snapshots: Vec<StakeSnapshot>,
+    // (bucket start height, referral reward amount), default for actors saved before limit windows
+    #[serde(default)]
+    pub referral_reward_buckets: Vec<(u64, Uint128)>,
 }
 
 impl Actor {
@@ -275,9 +294,12 @@ impl Actor {
             referral_reward_amounts: vec![],
             cumulative_participation_reward_amount: Uint128::zero(),
             cumulative_referral_reward_amount: Uint128::zero(),
+            cumulative_overflow_amount: Uint128::zero(),
             participation_count: 0,
             referral_count: 0,
             last_participated_at: Timestamp::default(),
+            stake_snapshots: vec![],
+            referral_reward_buckets: vec![],
         }
     }
 
@@ -346,6 +368,91 @@ impl Actor {
         self.referral_reward_amounts.push((amount, unlock_height));
     }
 
+    /// Lowest stake held during the window ending at `current`.
+    /// The last snapshot before the window is the stake in effect when the window started.
+    /// Without such a snapshot the stake at the start is unknown, so no stake is counted.
+    /// Stake is only sampled when the actor participates or earns a referral reward,
+    /// so stake withdrawn and restaked between two samples is not seen.
+    pub fn min_stake_over_window(&self, current: &StakeSnapshot, window_period: u64) -> StakeSnapshot {
+        let mut result = current.clone();
+        if window_period == 0 {
+            return result;
+        }
+
+        let window_start = current.height.saturating_sub(window_period);
+        let first_index = match self.stake_snapshots.iter().rposition(|s| s.height < window_start) {
+            Some(index) => index,
+            None => {
+                result.governance_staking_amount = Uint128::zero();
+                result.lp_staking_amount = Uint128::zero();
+                return result;
+            }
+        };
+
+        for snapshot in self.stake_snapshots[first_index..].iter() {
+            result.governance_staking_amount = result.governance_staking_amount.min(snapshot.governance_staking_amount);
+            result.lp_staking_amount = result.lp_staking_amount.min(snapshot.lp_staking_amount);
+        }
+
+        result
+    }
+
+    pub fn record_stake_snapshot(&mut self, snapshot: StakeSnapshot, window_period: u64) {
+        if window_period == 0 {
+            self.stake_snapshots.clear();
+            return;
+        }
+
+        if self.stake_snapshots.last().map_or(false, |s| s.height == snapshot.height) {
+            self.stake_snapshots.pop();
+        }
+
+        let window_start = snapshot.height.saturating_sub(window_period);
+        if let Some(index) = self.stake_snapshots.iter().rposition(|s| s.height < window_start) {
+            self.stake_snapshots.drain(..index);
+        }
+
+        self.stake_snapshots.push(snapshot);
+    }
+
+    /// Referral rewards received within the window ending at `height`, and the height at which
+    /// the oldest of them leaves the window. Without a window, the lifetime amount is used.
+    pub fn referral_reward_window_usage(&self, height: u64, window_period: u64) -> (Uint128, Option<u64>) {
+        if window_period == 0 {
+            return (self.cumulative_referral_reward_amount, None);
+        }
+
+        // a bucket leaves the window once its last height does
+        let expire_period = referral_reward_bucket_size(window_period) + window_period;
+        let buckets = self.referral_reward_buckets.iter()
+            .filter(|(start, _)| start + expire_period > height);
+
+        let usage = buckets.clone().map(|(_, amount)| *amount).sum::<Uint128>();
+        let reset_height = buckets.map(|(start, _)| start + expire_period).min();
+
+        (usage, reset_height)
+    }
+
+    pub fn add_referral_reward_usage(&mut self, amount: Uint128, height: u64, window_period: u64) {
+        if window_period == 0 {
+            self.referral_reward_buckets.clear();
+            return;
+        }
+
+        let bucket_size = referral_reward_bucket_size(window_period);
+        let bucket_start = height - height % bucket_size;
+
+        self.referral_reward_buckets.retain(|(start, _)| start + bucket_size + window_period > height);
+        if amount.is_zero() {
+            return;
+        }
+
+        match self.referral_reward_buckets.last_mut() {
+            Some((start, bucket_amount)) if *start == bucket_start => *bucket_amount += amount,
+            _ => self.referral_reward_buckets.push((bucket_start, amount)),
+        }
+    }
+
     pub fn participation_reward_amount(&self, height: u64) -> (Uint128, Uint128) {
         let mut unlocked_amount = Uint128::zero();
         let mut locked_amount = Uint128::zero();
@@ -507,42 +614,113 @@ impl QualifyParticipationContext {
     }
 }
 
-pub fn load_global_campaign_config(
+/// Queries the campaign manager config and referral reward limit option with the overrides
+/// of `campaign` applied, along with whether the campaign has been delisted.
+pub fn load_effective_campaign_config(
     querier: &QuerierWrapper,
     campaign_manager: &Addr,
-) -> StdResult<valkyrie::campaign_manager::query_msgs::ConfigResponse> {
+    campaign: &Addr,
+) -> StdResult<valkyrie::campaign_manager::query_msgs::EffectiveConfigResponse> {
     querier.query_wasm_smart(
         campaign_manager,
-        &valkyrie::campaign_manager::query_msgs::QueryMsg::Config {},
+        &valkyrie::campaign_manager::query_msgs::QueryMsg::EffectiveConfig {
+            campaign: campaign.to_string(),
+        },
     )
 }
 
-pub fn calc_referral_reward_limit(
+pub fn load_add_pool_fee_quote(
+    querier: &QuerierWrapper,
+    campaign_manager: &Addr,
+    campaign: &Addr,
+    campaign_config: &CampaignConfig,
+) -> StdResult<valkyrie::campaign_manager::query_msgs::FeeQuoteResponse> {
+    let mut addresses = vec![campaign_config.creator.to_string()];
+    if campaign_config.admin != campaign_config.creator {
+        addresses.push(campaign_config.admin.to_string());
+    }
+
+    querier.query_wasm_smart(
+        campaign_manager,
+        &valkyrie::campaign_manager::query_msgs::QueryMsg::FeeQuote {
+            campaign: Some(campaign.to_string()),
+            addresses,
+        },
+    )
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct StakeSnapshot {
+    pub height: u64,
+    pub governance_staking_amount: Uint128,
+    pub lp_staking_amount: Uint128,
+}
+
+pub fn load_stake_snapshot(
     limit_option: &ReferralRewardLimitOptionResponse,
     campaign_config: &CampaignConfig,
-    reward_config: &RewardConfig,
     querier: &QuerierWrapper,
     address: &Addr,
-) -> StdResult<ReferralRewardLimitAmount> {
-    let base_limit_amount = reward_config.referral_reward_amounts.iter().sum::<Uint128>()
-        .checked_mul(Uint128::from(limit_option.base_count))?;
-
+    height: u64,
+) -> StdResult<StakeSnapshot> {
     let gov_staker_state: StakerStateResponse = querier.query_wasm_smart(
         &campaign_config.governance,
         &valkyrie::governance::query_msgs::QueryMsg::StakerState {
             address: address.to_string(),
         },
     )?;
-    let gov_staking_amount = gov_staker_state.balance;
 
-    let actor_limit_amount = gov_staking_amount * Decimal::percent(limit_option.percent_for_governance_staking as u64);
+    let lp_staking_amount = match limit_option.lp_staking.as_ref() {
+        Some(lp_staking) if limit_option.percent_for_lp_staking != 0 => {
+            let lp_staker_info: StakerInfoResponse = querier.query_wasm_smart(
+                lp_staking,
+                &valkyrie::lp_staking::query_msgs::QueryMsg::StakerInfo {
+                    staker: address.to_string(),
+                },
+            )?;
+
+            lp_staker_info.bond_amount
+        }
+        _ => Uint128::zero(),
+    };
+
+    Ok(StakeSnapshot {
+        height,
+        governance_staking_amount: gov_staker_state.balance,
+        lp_staking_amount,
+    })
+}
+
+fn referral_reward_bucket_size(window_period: u64) -> u64 {
+    (window_period / REFERRAL_REWARD_BUCKET_COUNT).max(1)
+}
+
+pub fn calc_referral_reward_limit(
+    limit_option: &ReferralRewardLimitOptionResponse,
+    reward_config: &RewardConfig,
+    actor: &Actor,
+    current_stake: &StakeSnapshot,
+) -> StdResult<ReferralRewardLimitAmount> {
+    let stake = actor.min_stake_over_window(current_stake, limit_option.staking_window_period);
+    let (window_usage_amount, window_reset_height) = actor.referral_reward_window_usage(
+        current_stake.height,
+        limit_option.limit_window_period,
+    );
+
+    let base_limit_amount = reward_config.referral_reward_amounts.iter().sum::<Uint128>()
+        .checked_mul(Uint128::from(limit_option.base_count))?;
+
+    let actor_limit_amount = stake.governance_staking_amount * Decimal::percent(limit_option.percent_for_governance_staking as u64)
+        + stake.lp_staking_amount * Decimal::percent(limit_option.percent_for_lp_staking as u64);
 
     let limit_amount = base_limit_amount + actor_limit_amount;
 
     Ok(ReferralRewardLimitAmount {
-        address: address.to_string(),
+        address: actor.address.to_string(),
         limit_amount,
         base_limit_amount,
         actor_limit_amount,
+        window_usage_amount,
+        window_reset_height,
     })
 }
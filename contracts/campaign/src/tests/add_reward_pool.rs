@@ -1,16 +1,18 @@
-use cosmwasm_std::{Env, Response, MessageInfo, Uint128, coin, Addr, Decimal, SubMsg, CosmosMsg, WasmMsg, to_binary};
+use cosmwasm_std::{Env, Response, MessageInfo, Uint128, coin, Addr, Decimal, SubMsg, CosmosMsg, WasmMsg, to_binary, attr};
 
 use valkyrie::common::ContractResult;
 use valkyrie::mock_querier::{CustomDeps, custom_deps};
 
-use valkyrie::test_constants::campaign::{campaign_env, PARTICIPATION_REWARD_DENOM_NATIVE, CAMPAIGN_ADMIN};
+use valkyrie::test_constants::campaign::{CAMPAIGN, campaign_env, PARTICIPATION_REWARD_DENOM_NATIVE, CAMPAIGN_ADMIN};
 use crate::states::CampaignState;
 use crate::executions::{add_reward_pool, calc_add_pool_fee_amount};
 use cosmwasm_std::testing::mock_info;
 use valkyrie::test_constants::campaign_manager::{KEY_DENOM_NATIVE, ADD_POOL_MIN_REFERRAL_REWARD_RATE_PERCENT, CAMPAIGN_MANAGER};
 use valkyrie::test_utils::expect_generic_err;
 use cw20::Cw20ExecuteMsg;
+use valkyrie::campaign_manager::execute_msgs::FeeTierMsg;
 use valkyrie::campaign_manager::query_msgs::ConfigResponse;
+use valkyrie::governance::query_msgs::StakerStateResponse;
 use valkyrie::test_constants::VALKYRIE_TOKEN;
 
 pub fn exec(
@@ -20,10 +22,10 @@ pub fn exec(
     participation_reward_amount: Uint128,
     referral_reward_amount: Uint128,
 ) -> ContractResult<Response> {
-    deps.querier.with_terraswap_price(
+    deps.querier.with_price_source(
         VALKYRIE_TOKEN.to_string(),
         KEY_DENOM_NATIVE.to_string(),
-        1f64,
+        Decimal::one(),
     );
 
     let contract_address = env.contract.address.to_string();
@@ -159,6 +161,62 @@ fn succeed_more_referral_token() {
     );
 }
 
+#[test]
+fn succeed_fee_tier() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    let config = ConfigResponse {
+        add_pool_fee_rate: Decimal::percent(1),
+        ..ConfigResponse::default()
+    };
+    deps.querier.with_global_campaign_config(config);
+    deps.querier.with_add_pool_fee_tiers(vec![
+        FeeTierMsg {
+            min_staking_amount: Uint128::new(1000),
+            fee_rate: Decimal::permille(5),
+        },
+        FeeTierMsg {
+            min_staking_amount: Uint128::new(10000),
+            fee_rate: Decimal::zero(),
+        },
+    ]);
+    deps.querier.with_gov_staker_state(CAMPAIGN_ADMIN, StakerStateResponse {
+        balance: Uint128::new(1000),
+        share: Uint128::new(1000),
+        votes: vec![],
+        ..StakerStateResponse::default()
+    });
+
+    let (env, info, response) = will_success(
+        &mut deps,
+        100,
+        400,
+    );
+    assert_eq!(response.messages, vec![
+        SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: VALKYRIE_TOKEN.to_string(),
+            funds: vec![],
+            msg: to_binary(&Cw20ExecuteMsg::TransferFrom {
+                owner: info.sender.to_string(),
+                recipient: env.contract.address.to_string(),
+                amount: Uint128::new(400),
+            }).unwrap(),
+        })),
+        SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: VALKYRIE_TOKEN.to_string(),
+            funds: vec![],
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: CAMPAIGN_MANAGER.to_string(),
+                amount: Uint128::new(2),
+            }).unwrap(),
+        })),
+    ]);
+    assert!(response.attributes.contains(&attr("add_pool_fee_tier", "0")));
+    assert!(response.attributes.contains(&attr("add_pool_fee_rate", "0.005")));
+}
+
 #[test]
 fn succeed_zero_participation_token() {
     let mut deps = custom_deps();
@@ -250,6 +308,24 @@ fn failed_insufficient_referral_token() {
     ).as_str());
 }
 
+#[test]
+fn failed_delisted_campaign() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    deps.querier.with_delisted_campaign(CAMPAIGN);
+
+    let result = exec(
+        &mut deps,
+        campaign_env(),
+        mock_info(CAMPAIGN_ADMIN, &[coin(1000, PARTICIPATION_REWARD_DENOM_NATIVE)]),
+        Uint128::new(1000),
+        Uint128::new(1000),
+    );
+    expect_generic_err(&result, "Delisted campaign");
+}
+
 #[test]
 fn test_deposit_fee_amount() {
     let result = calc_add_pool_fee_amount(
@@ -308,3 +384,74 @@ fn test_deposit_fee_amount() {
     ).unwrap();
     assert_eq!(result, Uint128::new(62));
 }
+
+#[test]
+fn succeed_registered_price_source() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    // registered price source is used instead of the swap simulation
+    deps.querier.with_price_source(
+        VALKYRIE_TOKEN.to_string(),
+        KEY_DENOM_NATIVE.to_string(),
+        Decimal::percent(200),
+    );
+    deps.querier.with_terraswap_price(
+        VALKYRIE_TOKEN.to_string(),
+        KEY_DENOM_NATIVE.to_string(),
+        1f64,
+    );
+
+    let info = mock_info(CAMPAIGN_ADMIN, &[
+        coin(100 - ADD_POOL_MIN_REFERRAL_REWARD_RATE_PERCENT as u128, PARTICIPATION_REWARD_DENOM_NATIVE),
+    ]);
+
+    add_reward_pool(
+        deps.as_mut(),
+        campaign_env(),
+        info,
+        Uint128::from(100 - ADD_POOL_MIN_REFERRAL_REWARD_RATE_PERCENT),
+        Uint128::from(ADD_POOL_MIN_REFERRAL_REWARD_RATE_PERCENT - 1),
+    ).unwrap();
+}
+
+#[test]
+fn failed_no_price_source() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    // spot price is not used unless governance allows it
+    deps.querier.with_terraswap_price(
+        VALKYRIE_TOKEN.to_string(),
+        KEY_DENOM_NATIVE.to_string(),
+        1f64,
+    );
+
+    let info = mock_info(CAMPAIGN_ADMIN, &[
+        coin(100, PARTICIPATION_REWARD_DENOM_NATIVE),
+    ]);
+
+    let result = add_reward_pool(
+        deps.as_mut(),
+        campaign_env(),
+        info.clone(),
+        Uint128::new(100),
+        Uint128::new(100),
+    );
+    expect_generic_err(&result, &format!("Price source not registered ({} > {})", VALKYRIE_TOKEN, KEY_DENOM_NATIVE));
+
+    deps.querier.with_global_campaign_config(ConfigResponse {
+        spot_price_fallback: true,
+        ..ConfigResponse::default()
+    });
+
+    add_reward_pool(
+        deps.as_mut(),
+        campaign_env(),
+        info,
+        Uint128::new(100),
+        Uint128::new(100),
+    ).unwrap();
+}
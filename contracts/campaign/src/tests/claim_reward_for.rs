@@ -0,0 +1,119 @@
+use cosmwasm_std::{Addr, BankMsg, coin, CosmosMsg, Env, MessageInfo, Response, SubMsg, to_binary, Uint128, WasmMsg};
+use cosmwasm_std::testing::mock_info;
+use cw20::Cw20ExecuteMsg;
+
+use valkyrie::campaign::enumerations::Referrer;
+use valkyrie::common::ContractResult;
+use valkyrie::mock_querier::{custom_deps, CustomDeps};
+use valkyrie::test_constants::campaign::{campaign_env_height, PARTICIPATION_REWARD_AMOUNT, PARTICIPATION_REWARD_DENOM_NATIVE, REFERRAL_REWARD_AMOUNTS, REFERRAL_REWARD_LOCK_PERIOD};
+use valkyrie::test_constants::campaign_manager::CAMPAIGN_MANAGER;
+use valkyrie::test_constants::VALKYRIE_TOKEN;
+use valkyrie::test_utils::{expect_generic_err, expect_unauthorized_err};
+
+use crate::executions::claim_reward_for;
+use crate::states::{Actor, CampaignState};
+
+pub fn exec(deps: &mut CustomDeps, env: Env, info: MessageInfo, actor: String) -> ContractResult<Response> {
+    claim_reward_for(deps.as_mut(), env, info, actor)
+}
+
+pub fn will_success(deps: &mut CustomDeps, height: u64, actor: &str) -> (Env, MessageInfo, Response) {
+    let env = campaign_env_height(height);
+    let info = mock_info(CAMPAIGN_MANAGER, &[]);
+
+    let response = exec(deps, env.clone(), info.clone(), actor.to_string()).unwrap();
+
+    (env, info, response)
+}
+
+#[test]
+fn succeed() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::update_activation::will_success(&mut deps, true);
+    super::add_reward_pool::will_success(&mut deps, 1000, 1000);
+
+    let referrer = Addr::unchecked("Referrer");
+    super::participate::will_success(&mut deps, referrer.as_str(), None);
+    let (env, _, _) = super::participate::will_success(
+        &mut deps,
+        "Participator",
+        Some(Referrer::Address(referrer.to_string())),
+    );
+
+    let (_, _, response) = will_success(
+        &mut deps,
+        env.block.height + REFERRAL_REWARD_LOCK_PERIOD,
+        referrer.as_str(),
+    );
+    assert_eq!(response.messages, vec![
+        SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: referrer.to_string(),
+            amount: vec![coin(
+                PARTICIPATION_REWARD_AMOUNT.u128(),
+                PARTICIPATION_REWARD_DENOM_NATIVE.to_string(),
+            )],
+        })),
+        SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: VALKYRIE_TOKEN.to_string(),
+            funds: vec![],
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: referrer.to_string(),
+                amount: REFERRAL_REWARD_AMOUNTS[0],
+            }).unwrap(),
+        })),
+    ]);
+
+    let actor = Actor::load(&deps.storage, &referrer).unwrap();
+    assert_eq!(actor.participation_reward_amounts, vec![]);
+    assert_eq!(actor.referral_reward_amounts, vec![]);
+
+    let campaign_state = CampaignState::load(&deps.storage).unwrap();
+    assert_eq!(
+        campaign_state.locked_balance(&cw20::Denom::Native(PARTICIPATION_REWARD_DENOM_NATIVE.to_string())),
+        Uint128::new(5),
+    );
+    assert_eq!(
+        campaign_state.locked_balance(&cw20::Denom::Cw20(Addr::unchecked(VALKYRIE_TOKEN))),
+        Uint128::zero(),
+    );
+}
+
+#[test]
+fn failed_no_reward() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::update_activation::will_success(&mut deps, true);
+    super::add_reward_pool::will_success(&mut deps, 1000, 1000);
+
+    let (env, _, _) = super::participate::will_success(&mut deps, "Participator", None);
+
+    let result = exec(
+        &mut deps,
+        env.clone(),
+        mock_info(CAMPAIGN_MANAGER, &[]),
+        "Participator".to_string(),
+    );
+    expect_generic_err(&result, "Not exist claimable reward");
+}
+
+#[test]
+fn failed_invalid_permission() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::update_activation::will_success(&mut deps, true);
+    super::add_reward_pool::will_success(&mut deps, 1000, 1000);
+
+    let (env, _, _) = super::participate::will_success(&mut deps, "Participator", None);
+
+    let result = exec(
+        &mut deps,
+        campaign_env_height(env.block.height + REFERRAL_REWARD_LOCK_PERIOD),
+        mock_info("Participator", &[]),
+        "Participator".to_string(),
+    );
+    expect_unauthorized_err(&result);
+}
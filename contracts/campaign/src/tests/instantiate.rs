@@ -143,6 +143,7 @@ fn succeed() {
         participation_count: 0,
         cumulative_participation_reward_amount: Uint128::zero(),
         cumulative_referral_reward_amount: Uint128::zero(),
+        cumulative_overflow_amount: Uint128::zero(),
         locked_balances: vec![],
         balances: vec![],
         deposit_amount: Uint128::zero(),
@@ -8,6 +8,7 @@ pub mod add_reward_pool;
 pub mod remove_reward_pool;
 pub mod claim_participation_reward;
 pub mod claim_referral_reward;
+pub mod claim_reward_for;
 pub mod participate;
 pub mod deposit;
 pub mod withdraw;
@@ -7,10 +7,12 @@ use valkyrie::mock_querier::{custom_deps, CustomDeps};
 use valkyrie::test_utils::expect_generic_err;
 
 use crate::executions::participate;
-use crate::states::{CampaignState, Actor};
-use valkyrie::test_constants::campaign::{campaign_env, PARTICIPATION_REWARD_AMOUNT, REFERRAL_REWARD_AMOUNTS, PARTICIPATION_REWARD_DENOM_NATIVE, DEPOSIT_AMOUNT, PARTICIPATION_REWARD_LOCK_PERIOD, REFERRAL_REWARD_LOCK_PERIOD};
-use valkyrie::test_constants::{default_sender, DEFAULT_SENDER, VALKYRIE_TOKEN};
+use crate::queries::get_referral_reward_limit_amount;
+use crate::states::{CampaignState, Actor, StakeSnapshot};
+use valkyrie::test_constants::campaign::{CAMPAIGN, campaign_env, campaign_env_height, PARTICIPATION_REWARD_AMOUNT, REFERRAL_REWARD_AMOUNTS, PARTICIPATION_REWARD_DENOM_NATIVE, DEPOSIT_AMOUNT, PARTICIPATION_REWARD_LOCK_PERIOD, REFERRAL_REWARD_LOCK_PERIOD};
+use valkyrie::test_constants::{default_sender, DEFAULT_SENDER, LP_STAKING, VALKYRIE_TOKEN};
 use valkyrie::campaign_manager::query_msgs::ReferralRewardLimitOptionResponse;
+use valkyrie::campaign_manager::enumerations::OverflowMode;
 use cw20::{Denom, Cw20ExecuteMsg};
 use valkyrie::governance::query_msgs::StakerStateResponse;
 
@@ -77,9 +79,12 @@ fn succeed_without_referrer() {
         referral_reward_amounts: vec![],
         cumulative_participation_reward_amount: PARTICIPATION_REWARD_AMOUNT,
         cumulative_referral_reward_amount: Uint128::zero(),
+        cumulative_overflow_amount: Uint128::zero(),
         participation_count: 1,
         referral_count: 0,
         last_participated_at: env.block.time,
+        stake_snapshots: vec![],
+        referral_reward_buckets: vec![],
     });
 }
 
@@ -127,9 +132,12 @@ fn succeed_with_referrer() {
         referral_reward_amounts: vec![],
         cumulative_participation_reward_amount: PARTICIPATION_REWARD_AMOUNT,
         cumulative_referral_reward_amount: Uint128::zero(),
+        cumulative_overflow_amount: Uint128::zero(),
         participation_count: 1,
         referral_count: 0,
         last_participated_at: env.block.time,
+        stake_snapshots: vec![],
+        referral_reward_buckets: vec![],
     });
 
     let referrer_participation = Actor::load(&deps.storage, &referrer).unwrap();
@@ -140,9 +148,12 @@ fn succeed_with_referrer() {
         referral_reward_amounts: vec![(REFERRAL_REWARD_AMOUNTS[0], env.block.height + REFERRAL_REWARD_LOCK_PERIOD)],
         cumulative_participation_reward_amount: PARTICIPATION_REWARD_AMOUNT,
         cumulative_referral_reward_amount: REFERRAL_REWARD_AMOUNTS[0],
+        cumulative_overflow_amount: Uint128::zero(),
         participation_count: 1,
         referral_count: 1,
         last_participated_at: referrer_env.block.time,
+        stake_snapshots: vec![],
+        referral_reward_buckets: vec![],
     });
 }
 
@@ -170,9 +181,12 @@ fn succeed_twice() {
         referral_reward_amounts: vec![],
         cumulative_participation_reward_amount: PARTICIPATION_REWARD_AMOUNT.checked_mul(Uint128::new(2)).unwrap(),
         cumulative_referral_reward_amount: Uint128::zero(),
+        cumulative_overflow_amount: Uint128::zero(),
         participation_count: 2,
         referral_count: 0,
         last_participated_at: env.block.time,
+        stake_snapshots: vec![],
+        referral_reward_buckets: vec![],
     });
 
     let campaign_state = CampaignState::load(&deps.storage).unwrap();
@@ -197,6 +211,28 @@ fn failed_inactive_campaign() {
     expect_generic_err(&result, "Inactive campaign");
 }
 
+#[test]
+fn failed_delisted_campaign() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::update_activation::will_success(&mut deps, true);
+    super::add_reward_pool::will_success(&mut deps, 1000, 1000);
+    super::deposit::will_success(&mut deps, "Participator", DEPOSIT_AMOUNT);
+
+    deps.querier.with_delisted_campaign(CAMPAIGN);
+
+    let result = exec(
+        &mut deps,
+        campaign_env(),
+        mock_info("Participator", &[]),
+        "Participator".to_string(),
+        None,
+    );
+
+    expect_generic_err(&result, "Inactive campaign");
+}
+
 #[test]
 fn failed_insufficient_balance() {
     let mut deps = custom_deps();
@@ -228,9 +264,11 @@ fn overflow_referral_reward() {
     super::add_reward_pool::will_success(&mut deps, 100, 100);
 
     deps.querier.with_referral_reward_limit_option(ReferralRewardLimitOptionResponse {
+        overflow_mode: OverflowMode::Reuse,
         overflow_amount_recipient: None,
         base_count: 1,
         percent_for_governance_staking: 10,
+        ..ReferralRewardLimitOptionResponse::default()
     });
 
     deps.querier.with_gov_staker_state(
@@ -239,6 +277,7 @@ fn overflow_referral_reward() {
             balance: Uint128::new(20),
             share: Uint128::new(20),
             votes: vec![],
+            ..StakerStateResponse::default()
         }
     );
 
@@ -266,6 +305,7 @@ fn overflow_referral_reward() {
             balance: Uint128::new(40),
             share: Uint128::new(40),
             votes: vec![],
+            ..StakerStateResponse::default()
         }
     );
 
@@ -289,13 +329,16 @@ fn overflow_referral_reward() {
             balance: Uint128::new(60),
             share: Uint128::new(60),
             votes: vec![],
+            ..StakerStateResponse::default()
         }
     );
 
     deps.querier.with_referral_reward_limit_option(ReferralRewardLimitOptionResponse {
+        overflow_mode: OverflowMode::Recipient,
         overflow_amount_recipient: Some("Recipient".to_string()),
         base_count: 1,
         percent_for_governance_staking: 10,
+        ..ReferralRewardLimitOptionResponse::default()
     });
 
     crate::tests::claim_referral_reward::will_success(
@@ -322,4 +365,304 @@ fn overflow_referral_reward() {
 
     let state = CampaignState::load(&deps.storage).unwrap();
     assert_eq!(state.balance(&Denom::Cw20(Addr::unchecked(VALKYRIE_TOKEN))).available(), Uint128::new(76));
+    assert_eq!(state.cumulative_overflow_amount, Uint128::new(7));
+    assert_eq!(referrer.cumulative_overflow_amount, Uint128::new(7));
+}
+
+#[test]
+fn burn_overflow_referral_reward() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::update_activation::will_success(&mut deps, true);
+    super::add_reward_pool::will_success(&mut deps, 100, 100);
+
+    deps.querier.with_referral_reward_limit_option(ReferralRewardLimitOptionResponse {
+        overflow_mode: OverflowMode::Burn,
+        overflow_amount_recipient: None,
+        base_count: 1,
+        percent_for_governance_staking: 10,
+        ..ReferralRewardLimitOptionResponse::default()
+    });
+
+    deps.querier.with_gov_staker_state(
+        "Referrer",
+        StakerStateResponse {
+            balance: Uint128::new(20),
+            share: Uint128::new(20),
+            votes: vec![],
+            ..StakerStateResponse::default()
+        }
+    );
+
+    will_success(&mut deps, "Referrer", None);
+    will_success(&mut deps, "Participator", Some(Referrer::Address("Referrer".to_string())));
+    will_success(&mut deps, "Participator2", Some(Referrer::Address("Participator".to_string())));
+    let (_, _, response) = will_success(&mut deps, "Participator", Some(Referrer::Address("Referrer".to_string())));
+
+    assert_eq!(response.messages, vec![
+        SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: VALKYRIE_TOKEN.to_string(),
+            funds: vec![],
+            msg: to_binary(&Cw20ExecuteMsg::Burn {
+                amount: Uint128::new(1),
+            }).unwrap(),
+        })),
+    ]);
+
+    let state = CampaignState::load(&deps.storage).unwrap();
+    assert_eq!(state.balance(&Denom::Cw20(Addr::unchecked(VALKYRIE_TOKEN))).available(), Uint128::new(82));
+    assert_eq!(state.cumulative_overflow_amount, Uint128::new(1));
+
+    let referrer = Actor::load(&deps.storage, &Addr::unchecked("Referrer")).unwrap();
+    assert_eq!(referrer.cumulative_overflow_amount, Uint128::new(1));
+}
+
+#[test]
+fn referral_reward_limit_with_lp_staking_over_window() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::update_activation::will_success(&mut deps, true);
+    super::add_reward_pool::will_success(&mut deps, 100, 100);
+
+    deps.querier.with_referral_reward_limit_option(ReferralRewardLimitOptionResponse {
+        overflow_mode: OverflowMode::Reuse,
+        overflow_amount_recipient: None,
+        base_count: 0,
+        percent_for_governance_staking: 10,
+        lp_staking: Some(LP_STAKING.to_string()),
+        percent_for_lp_staking: 10,
+        staking_window_period: 100,
+        limit_window_period: 0,
+    });
+
+    deps.querier.with_gov_staker_state(
+        "Referrer",
+        StakerStateResponse {
+            balance: Uint128::new(10),
+            share: Uint128::new(10),
+            votes: vec![],
+            ..StakerStateResponse::default()
+        }
+    );
+    deps.querier.with_lp_staker_bond_amount("Referrer", Uint128::new(20));
+
+    let (referrer_env, _, _) = will_success(&mut deps, "Referrer", None);
+
+    deps.querier.with_gov_staker_state(
+        "Referrer",
+        StakerStateResponse {
+            balance: Uint128::new(100),
+            share: Uint128::new(100),
+            votes: vec![],
+            ..StakerStateResponse::default()
+        }
+    );
+    deps.querier.with_lp_staker_bond_amount("Referrer", Uint128::new(100));
+
+    // without a snapshot before the window, the stake is not counted
+    super::deposit::will_success(&mut deps, "Participator", DEPOSIT_AMOUNT);
+    exec(
+        &mut deps,
+        campaign_env_height(referrer_env.block.height + 50),
+        mock_info("Participator", &[]),
+        "Participator".to_string(),
+        Some(Referrer::Address("Referrer".to_string())),
+    ).unwrap();
+
+    let referrer = Actor::load(&deps.storage, &Addr::unchecked("Referrer")).unwrap();
+    assert_eq!(referrer.cumulative_referral_reward_amount, Uint128::zero());
+    assert_eq!(referrer.cumulative_overflow_amount, Uint128::new(5));
+
+    // stake added inside the window is not counted yet
+    super::deposit::will_success(&mut deps, "Participator2", DEPOSIT_AMOUNT);
+    exec(
+        &mut deps,
+        campaign_env_height(referrer_env.block.height + 150),
+        mock_info("Participator2", &[]),
+        "Participator2".to_string(),
+        Some(Referrer::Address("Referrer".to_string())),
+    ).unwrap();
+
+    let referrer = Actor::load(&deps.storage, &Addr::unchecked("Referrer")).unwrap();
+    assert_eq!(referrer.cumulative_referral_reward_amount, Uint128::new(3));
+    assert_eq!(referrer.cumulative_overflow_amount, Uint128::new(7));
+
+    // once the window has passed, the raised stake counts in full
+    super::deposit::will_success(&mut deps, "Participator3", DEPOSIT_AMOUNT);
+    exec(
+        &mut deps,
+        campaign_env_height(referrer_env.block.height + 300),
+        mock_info("Participator3", &[]),
+        "Participator3".to_string(),
+        Some(Referrer::Address("Referrer".to_string())),
+    ).unwrap();
+
+    let referrer = Actor::load(&deps.storage, &Addr::unchecked("Referrer")).unwrap();
+    assert_eq!(referrer.cumulative_referral_reward_amount, Uint128::new(8));
+    assert_eq!(referrer.cumulative_overflow_amount, Uint128::new(7));
+    assert_eq!(referrer.stake_snapshots, vec![
+        StakeSnapshot {
+            height: referrer_env.block.height + 150,
+            governance_staking_amount: Uint128::new(100),
+            lp_staking_amount: Uint128::new(100),
+        },
+        StakeSnapshot {
+            height: referrer_env.block.height + 300,
+            governance_staking_amount: Uint128::new(100),
+            lp_staking_amount: Uint128::new(100),
+        },
+    ]);
+}
+
+#[test]
+fn referral_reward_limit_resets_after_window() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::update_activation::will_success(&mut deps, true);
+    super::add_reward_pool::will_success(&mut deps, 100, 100);
+
+    deps.querier.with_referral_reward_limit_option(ReferralRewardLimitOptionResponse {
+        overflow_mode: OverflowMode::Reuse,
+        overflow_amount_recipient: None,
+        base_count: 1,
+        percent_for_governance_staking: 0,
+        lp_staking: None,
+        percent_for_lp_staking: 0,
+        staking_window_period: 0,
+        limit_window_period: 1000,
+    });
+
+    let (referrer_env, _, _) = will_success(&mut deps, "Referrer", None);
+    let height = referrer_env.block.height;
+
+    let participate_at = |deps: &mut CustomDeps, participator: &str, height: u64| {
+        super::deposit::will_success(deps, participator, DEPOSIT_AMOUNT);
+        exec(
+            deps,
+            campaign_env_height(height),
+            mock_info(participator, &[]),
+            participator.to_string(),
+            Some(Referrer::Address("Referrer".to_string())),
+        ).unwrap();
+    };
+
+    participate_at(&mut deps, "Participator1", height);
+    participate_at(&mut deps, "Participator2", height + 10);
+    participate_at(&mut deps, "Participator3", height + 20); // reach limit. overflow amount = 5
+
+    let referrer = Actor::load(&deps.storage, &Addr::unchecked("Referrer")).unwrap();
+    assert_eq!(referrer.cumulative_referral_reward_amount, Uint128::new(10));
+    assert_eq!(referrer.cumulative_overflow_amount, Uint128::new(5));
+
+    let bucket_start = height - height % 100;
+    let limit = get_referral_reward_limit_amount(
+        deps.as_ref(),
+        campaign_env_height(height + 20),
+        "Referrer".to_string(),
+    ).unwrap();
+    assert_eq!(limit.limit_amount, Uint128::new(10));
+    assert_eq!(limit.window_usage_amount, Uint128::new(10));
+    assert_eq!(limit.window_reset_height, Some(bucket_start + 1100));
+
+    // rewards stay in the window until the last height of their bucket has left it
+    let limit = get_referral_reward_limit_amount(
+        deps.as_ref(),
+        campaign_env_height(bucket_start + 1099),
+        "Referrer".to_string(),
+    ).unwrap();
+    assert_eq!(limit.window_usage_amount, Uint128::new(10));
+
+    participate_at(&mut deps, "Participator4", bucket_start + 1100);
+
+    let referrer = Actor::load(&deps.storage, &Addr::unchecked("Referrer")).unwrap();
+    assert_eq!(referrer.cumulative_referral_reward_amount, Uint128::new(15));
+    assert_eq!(referrer.cumulative_overflow_amount, Uint128::new(5));
+    assert_eq!(referrer.referral_reward_buckets, vec![(bucket_start + 1100, Uint128::new(5))]);
+}
+
+#[test]
+fn referral_reward_limit_sees_only_sampled_stake() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::update_activation::will_success(&mut deps, true);
+    super::add_reward_pool::will_success(&mut deps, 100, 100);
+
+    deps.querier.with_referral_reward_limit_option(ReferralRewardLimitOptionResponse {
+        overflow_mode: OverflowMode::Reuse,
+        overflow_amount_recipient: None,
+        base_count: 0,
+        percent_for_governance_staking: 10,
+        lp_staking: None,
+        percent_for_lp_staking: 0,
+        staking_window_period: 100,
+        limit_window_period: 0,
+    });
+
+    let staked = StakerStateResponse {
+        balance: Uint128::new(100),
+        share: Uint128::new(100),
+        votes: vec![],
+        ..StakerStateResponse::default()
+    };
+    let unstaked = StakerStateResponse {
+        votes: vec![],
+        ..StakerStateResponse::default()
+    };
+
+    deps.querier.with_gov_staker_state("Referrer", staked.clone());
+    let (referrer_env, _, _) = will_success(&mut deps, "Referrer", None);
+    let height = referrer_env.block.height;
+
+    // unstaked and restaked between two samples, which is not seen
+    deps.querier.with_gov_staker_state("Referrer", unstaked.clone());
+    deps.querier.with_gov_staker_state("Referrer", staked.clone());
+
+    super::deposit::will_success(&mut deps, "Participator", DEPOSIT_AMOUNT);
+    exec(
+        &mut deps,
+        campaign_env_height(height + 150),
+        mock_info("Participator", &[]),
+        "Participator".to_string(),
+        Some(Referrer::Address("Referrer".to_string())),
+    ).unwrap();
+
+    let referrer = Actor::load(&deps.storage, &Addr::unchecked("Referrer")).unwrap();
+    assert_eq!(referrer.cumulative_referral_reward_amount, Uint128::new(5));
+    assert_eq!(referrer.cumulative_overflow_amount, Uint128::zero());
+
+    // unstake sampled by a referral reward
+    deps.querier.with_gov_staker_state("Referrer", unstaked);
+
+    super::deposit::will_success(&mut deps, "Participator2", DEPOSIT_AMOUNT);
+    exec(
+        &mut deps,
+        campaign_env_height(height + 160),
+        mock_info("Participator2", &[]),
+        "Participator2".to_string(),
+        Some(Referrer::Address("Referrer".to_string())),
+    ).unwrap();
+
+    let referrer = Actor::load(&deps.storage, &Addr::unchecked("Referrer")).unwrap();
+    assert_eq!(referrer.cumulative_referral_reward_amount, Uint128::new(5));
+    assert_eq!(referrer.cumulative_overflow_amount, Uint128::new(5));
+
+    // restaked, but the sampled unstake is still inside the window
+    deps.querier.with_gov_staker_state("Referrer", staked);
+
+    super::deposit::will_success(&mut deps, "Participator3", DEPOSIT_AMOUNT);
+    exec(
+        &mut deps,
+        campaign_env_height(height + 200),
+        mock_info("Participator3", &[]),
+        "Participator3".to_string(),
+        Some(Referrer::Address("Referrer".to_string())),
+    ).unwrap();
+
+    let referrer = Actor::load(&deps.storage, &Addr::unchecked("Referrer")).unwrap();
+    assert_eq!(referrer.cumulative_referral_reward_amount, Uint128::new(5));
+    assert_eq!(referrer.cumulative_overflow_amount, Uint128::new(10));
 }
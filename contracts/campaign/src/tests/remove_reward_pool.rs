@@ -238,6 +238,23 @@ fn succeed_free_balance() {
     );
 }
 
+#[test]
+fn succeed_delisted_campaign() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::update_activation::will_success(&mut deps, true);
+    super::add_reward_pool::will_success(&mut deps, 1000, 10000);
+
+    deps.querier.with_delisted_campaign(CAMPAIGN);
+
+    will_success(
+        &mut deps,
+        Denom::Token(VALKYRIE_TOKEN.to_string()),
+        Some(Uint128::new(10000)),
+    );
+}
+
 #[test]
 fn failed_invalid_permission() {
     let mut deps = custom_deps();
@@ -15,6 +15,7 @@ fn main() {
     export_schema(&schema_for!(InstantiateMsg), &out_dir);
     export_schema(&schema_for!(ReferralRewardLimitOptionMsg), &out_dir);
     export_schema(&schema_for!(ExecuteMsg), &out_dir);
+    export_schema(&schema_for!(Cw20HookMsg), &out_dir);
     export_schema(&schema_for!(MigrateMsg), &out_dir);
     export_schema(&schema_for!(CampaignInstantiateMsg), &out_dir);
 
@@ -23,4 +24,15 @@ fn main() {
     export_schema(&schema_for!(ReferralRewardLimitOptionResponse), &out_dir);
     export_schema(&schema_for!(CampaignResponse), &out_dir);
     export_schema(&schema_for!(CampaignsResponse), &out_dir);
+    export_schema(&schema_for!(PendingRewardsResponse), &out_dir);
+    export_schema(&schema_for!(TemplateResponse), &out_dir);
+    export_schema(&schema_for!(TemplatesResponse), &out_dir);
+    export_schema(&schema_for!(SwapRoutesResponse), &out_dir);
+    export_schema(&schema_for!(SimulateSwapResponse), &out_dir);
+    export_schema(&schema_for!(FeeSplitsResponse), &out_dir);
+    export_schema(&schema_for!(PriceResponse), &out_dir);
+    export_schema(&schema_for!(AddPoolFeeTiersResponse), &out_dir);
+    export_schema(&schema_for!(FeeQuoteResponse), &out_dir);
+    export_schema(&schema_for!(CampaignOverridesResponse), &out_dir);
+    export_schema(&schema_for!(EffectiveConfigResponse), &out_dir);
 }
\ No newline at end of file
@@ -1,12 +1,13 @@
-use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdError, to_binary};
+use cosmwasm_std::{Addr, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdError, to_binary, from_binary};
 use cosmwasm_std::entry_point;
 
-use valkyrie::campaign_manager::execute_msgs::{ExecuteMsg, InstantiateMsg, MigrateMsg};
+use valkyrie::campaign_manager::execute_msgs::{Cw20HookMsg, ExecuteMsg, InstantiateMsg, MigrateMsg};
 use valkyrie::campaign_manager::query_msgs::QueryMsg;
 use valkyrie::common::ContractResult;
 use valkyrie::errors::ContractError;
 
 use crate::{executions, migrations, queries};
+use cw20::Cw20ReceiveMsg;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -28,6 +29,7 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> ContractResult<Response> {
     match msg {
+        ExecuteMsg::Receive(msg) => receive_cw20(deps, env, info, msg),
         ExecuteMsg::UpdateConfig {
             governance,
             valkyrie_token,
@@ -41,6 +43,11 @@ pub fn execute(
             deactivate_period,
             key_denom,
             contract_admin,
+            creation_fee_denom,
+            creation_fee_amount,
+            swap_fee_max_spread,
+            swap_fee_keepers,
+            spot_price_fallback,
         } => executions::update_config(
             deps,
             env,
@@ -57,18 +64,33 @@ pub fn execute(
             deactivate_period,
             key_denom,
             contract_admin,
+            creation_fee_denom,
+            creation_fee_amount,
+            swap_fee_max_spread,
+            swap_fee_keepers,
+            spot_price_fallback,
         ),
         ExecuteMsg::UpdateReferralRewardLimitOption {
+            overflow_mode,
             overflow_amount_recipient,
             base_count,
             percent_for_governance_staking,
+            lp_staking,
+            percent_for_lp_staking,
+            staking_window_period,
+            limit_window_period,
         } => executions::update_referral_reward_limit_option(
             deps,
             env,
             info,
+            overflow_mode,
             overflow_amount_recipient,
             base_count,
             percent_for_governance_staking,
+            lp_staking,
+            percent_for_lp_staking,
+            staking_window_period,
+            limit_window_period,
         ),
         ExecuteMsg::SetReuseOverflowAmount {} => executions::set_reuse_overflow_amount(deps, env, info),
         ExecuteMsg::CreateCampaign {
@@ -78,17 +100,28 @@ pub fn execute(
             deposit_lock_period,
             qualifier,
             qualification_description,
-        } => executions::create_campaign(
-            deps,
-            env,
-            info,
-            config_msg,
-            deposit_denom,
-            deposit_amount,
-            deposit_lock_period,
-            qualifier,
-            qualification_description,
-        ),
+            tags,
+            logo_url,
+        } => {
+            let funds = info.funds.iter()
+                .map(|c| (cw20::Denom::Native(c.denom.to_string()), c.amount))
+                .collect();
+
+            executions::create_campaign(
+                deps,
+                env,
+                info.sender,
+                funds,
+                config_msg,
+                deposit_denom,
+                deposit_amount,
+                deposit_lock_period,
+                qualifier,
+                qualification_description,
+                tags,
+                logo_url,
+            )
+        },
         ExecuteMsg::SpendFee {
             amount,
         } => executions::spend_fee(deps, env, info, amount),
@@ -96,7 +129,141 @@ pub fn execute(
             denom,
             amount,
             route,
-        } => executions::swap_fee(deps, env, info, denom, amount, route),
+            minimum_receive,
+        } => executions::swap_fee(deps, env, info, denom, amount, route, minimum_receive),
+        ExecuteMsg::ClaimAll {
+            campaigns,
+        } => executions::claim_all(deps, env, info, campaigns),
+        ExecuteMsg::SetCampaignStatus {
+            address,
+            status,
+        } => executions::set_campaign_status(deps, env, info, address, status),
+        ExecuteMsg::MigrateCampaigns {
+            code_id,
+            campaigns,
+            start_after,
+            limit,
+            msg,
+        } => executions::migrate_campaigns(deps, env, info, code_id, campaigns, start_after, limit, msg),
+        ExecuteMsg::MigrateLegacyCampaigns {
+            limit,
+        } => executions::migrate_legacy_campaigns(deps, env, info, limit),
+        ExecuteMsg::CreateTemplate {
+            name,
+            template,
+        } => executions::create_template(deps, env, info, name, template),
+        ExecuteMsg::UpdateTemplate {
+            template_id,
+            name,
+            template,
+        } => executions::update_template(deps, env, info, template_id, name, template),
+        ExecuteMsg::RemoveTemplate {
+            template_id,
+        } => executions::remove_template(deps, env, info, template_id),
+        ExecuteMsg::AddSwapRoute {
+            route,
+        } => executions::add_swap_route(deps, env, info, route),
+        ExecuteMsg::RemoveSwapRoute {
+            route,
+        } => executions::remove_swap_route(deps, env, info, route),
+        ExecuteMsg::UpdateFeeSplits {
+            splits,
+            dust_recipient,
+        } => executions::update_fee_splits(deps, env, info, splits, dust_recipient),
+        ExecuteMsg::SetPriceSource {
+            base,
+            quote,
+            source,
+            max_staleness,
+        } => executions::set_price_source(deps, env, info, base, quote, source, max_staleness),
+        ExecuteMsg::RemovePriceSource {
+            base,
+            quote,
+        } => executions::remove_price_source(deps, env, info, base, quote),
+        ExecuteMsg::RecordPrice {
+            base,
+            quote,
+        } => executions::record_price(deps, env, info, base, quote),
+        ExecuteMsg::UpdateAddPoolFeeTiers {
+            tiers,
+        } => executions::update_add_pool_fee_tiers(deps, env, info, tiers),
+        ExecuteMsg::SetCampaignOverrides {
+            campaign,
+            overrides,
+        } => executions::set_campaign_overrides(deps, env, info, campaign, overrides),
+        ExecuteMsg::CreateCampaignFromTemplate {
+            template_id,
+            overrides,
+            tags,
+            logo_url,
+        } => {
+            let funds = info.funds.iter()
+                .map(|c| (cw20::Denom::Native(c.denom.to_string()), c.amount))
+                .collect();
+
+            executions::create_campaign_from_template(
+                deps,
+                env,
+                info.sender,
+                funds,
+                template_id,
+                overrides,
+                tags,
+                logo_url,
+            )
+        },
+    }
+}
+
+pub fn receive_cw20(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    cw20_msg: Cw20ReceiveMsg,
+) -> ContractResult<Response> {
+    match from_binary(&cw20_msg.msg)? {
+        Cw20HookMsg::CreateCampaign {
+            config_msg,
+            deposit_denom,
+            deposit_amount,
+            deposit_lock_period,
+            qualifier,
+            qualification_description,
+            tags,
+            logo_url,
+        } => {
+            executions::create_campaign(
+                deps,
+                env,
+                Addr::unchecked(cw20_msg.sender),
+                vec![(cw20::Denom::Cw20(info.sender), cw20_msg.amount)],
+                config_msg,
+                deposit_denom,
+                deposit_amount,
+                deposit_lock_period,
+                qualifier,
+                qualification_description,
+                tags,
+                logo_url,
+            )
+        },
+        Cw20HookMsg::CreateCampaignFromTemplate {
+            template_id,
+            overrides,
+            tags,
+            logo_url,
+        } => {
+            executions::create_campaign_from_template(
+                deps,
+                env,
+                Addr::unchecked(cw20_msg.sender),
+                vec![(cw20::Denom::Cw20(info.sender), cw20_msg.amount)],
+                template_id,
+                overrides,
+                tags,
+                logo_url,
+            )
+        },
     }
 }
 
@@ -104,13 +271,14 @@ pub fn execute(
 pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> ContractResult<Response> {
     match msg.id {
         crate::executions::REPLY_CREATE_CAMPAIGN => executions::created_campaign(deps, env, msg),
+        crate::executions::REPLY_MIGRATE_CAMPAIGN => executions::migrated_campaign(deps, env, msg),
         _ => Err(ContractError::Std(StdError::not_found("reply_id")))
     }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> ContractResult<Response> {
-    migrations::v1_0_6(deps, env, msg)
+    migrations::v1_0_7(deps, env, msg)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -120,8 +288,8 @@ pub fn query(
     msg: QueryMsg,
 ) -> ContractResult<Binary> {
     let result = match msg {
-        QueryMsg::Config {} => to_binary(
-            &queries::get_config(deps, env)?
+        QueryMsg::Config { campaign } => to_binary(
+            &queries::get_config(deps, env, campaign)?
         ),
         QueryMsg::ReferralRewardLimitOption {} => to_binary(
             &queries::get_referral_reward_limit_option(deps, env)?
@@ -130,11 +298,75 @@ pub fn query(
             &queries::get_campaign(deps, env, address)?
         ),
         QueryMsg::Campaigns {
+            filter,
+            start_after,
+            limit,
+            order_by,
+        } => to_binary(
+            &queries::query_campaign(deps, env, filter, start_after, limit, order_by)?
+        ),
+        QueryMsg::PendingRewards {
+            address,
+            campaigns,
+        } => to_binary(
+            &queries::get_pending_rewards(deps, env, address, campaigns)?
+        ),
+        QueryMsg::Template { template_id } => to_binary(
+            &queries::get_template(deps, env, template_id)?
+        ),
+        QueryMsg::Templates {
             start_after,
             limit,
             order_by,
         } => to_binary(
-            &queries::query_campaign(deps, env, start_after, limit, order_by)?
+            &queries::query_templates(deps, env, start_after, limit, order_by)?
+        ),
+        QueryMsg::SwapRoutes {
+            offer,
+            ask,
+        } => to_binary(
+            &queries::get_swap_routes(deps, env, offer, ask)?
+        ),
+        QueryMsg::SimulateSwap {
+            offer,
+            ask,
+            amount,
+        } => to_binary(
+            &queries::simulate_swap(deps, env, offer, ask, amount)?
+        ),
+        QueryMsg::FeeSplits {} => to_binary(
+            &queries::get_fee_splits(deps, env)?
+        ),
+        QueryMsg::Price {
+            base,
+            quote,
+        } => to_binary(
+            &queries::get_price(deps, env, base, quote)?
+        ),
+        QueryMsg::RegisteredPrice {
+            base,
+            quote,
+        } => to_binary(
+            &queries::get_registered_price(deps, env, base, quote)?
+        ),
+        QueryMsg::AddPoolFeeTiers {} => to_binary(
+            &queries::get_add_pool_fee_tiers(deps, env)?
+        ),
+        QueryMsg::FeeQuote {
+            campaign,
+            addresses,
+        } => to_binary(
+            &queries::get_fee_quote(deps, env, campaign, addresses)?
+        ),
+        QueryMsg::CampaignOverrides {
+            campaign,
+        } => to_binary(
+            &queries::get_campaign_overrides(deps, env, campaign)?
+        ),
+        QueryMsg::EffectiveConfig {
+            campaign,
+        } => to_binary(
+            &queries::get_effective_config(deps, env, campaign)?
         ),
     }?;
 
@@ -1,16 +1,28 @@
-use cosmwasm_std::{Binary, Decimal, DepsMut, Env, MessageInfo, Reply, ReplyOn, Response, StdError, SubMsg, to_binary, Uint128, coin};
+use cosmwasm_std::{Addr, Binary, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo, QuerierWrapper, Reply, ReplyOn, Response, StdError, StdResult, Storage, SubMsg, to_binary, Uint128, coin};
 
-use valkyrie::campaign_manager::execute_msgs::{CampaignInstantiateMsg, InstantiateMsg};
-use valkyrie::common::{ContractResult, Denom};
+use valkyrie::campaign::execute_msgs::{CampaignConfigMsg, ExecuteMsg as CampaignExecuteMsg};
+use valkyrie::campaign_manager::enumerations::{CampaignStatus, FeeSplitRecipient as FeeSplitMsgRecipient, OverflowMode};
+use valkyrie::campaign_manager::execute_msgs::{CampaignInstantiateMsg, CampaignOverridesMsg, CampaignTemplateMsg, FeeSplitMsg, FeeTierMsg, InstantiateMsg, PriceSourceMsg};
+use valkyrie::common::{ContractResult, Denom, OrderBy};
 use valkyrie::errors::ContractError;
 use valkyrie::message_factories;
 use valkyrie::utils::{find, make_response};
 
+use crate::migrations;
 use crate::states::*;
 use valkyrie::cw20::{query_cw20_balance, query_balance};
 use cw20::Cw20ExecuteMsg;
 use terraswap::asset::AssetInfo;
-use terraswap::router::{ExecuteMsg as TerraswapExecuteMsg, SwapOperation};
+use terraswap::router::{ExecuteMsg as TerraswapExecuteMsg, QueryMsg as TerraswapQueryMsg, SimulateSwapOperationsResponse, SwapOperation};
+
+pub const MAX_TAG_COUNT: usize = 5;
+pub const MIN_TAG_LENGTH: usize = 2;
+pub const MAX_TAG_LENGTH: usize = 32;
+pub const MIN_URL_LENGTH: usize = 12;
+pub const MAX_URL_LENGTH: usize = 256;
+pub const MAX_FEE_SPLIT_COUNT: usize = 10;
+pub const PRICE_UNIT: u128 = 1_000_000;
+pub const MAX_FEE_TIER_COUNT: usize = 10;
 
 pub fn instantiate(
     deps: DepsMut,
@@ -34,13 +46,38 @@ pub fn instantiate(
         deactivate_period: msg.deactivate_period,
         key_denom: msg.key_denom.to_cw20(deps.api),
         contract_admin: deps.api.addr_validate(msg.contract_admin.as_str())?,
+        creation_fee_denom: msg.creation_fee_denom.to_cw20(deps.api),
+        creation_fee_amount: msg.creation_fee_amount,
+        swap_fee_max_spread: validate_max_spread(msg.swap_fee_max_spread)?,
+        swap_fee_keepers: msg.swap_fee_keepers.iter()
+            .map(|k| deps.api.addr_validate(k))
+            .collect::<StdResult<Vec<Addr>>>()?,
+        spot_price_fallback: false,
     }.save(deps.storage)?;
 
+    let overflow_amount_recipient = msg.referral_reward_limit_option.overflow_amount_recipient
+        .map(|r| deps.api.addr_validate(r.as_str()))
+        .transpose()?;
+    let lp_staking = msg.referral_reward_limit_option.lp_staking
+        .map(|s| deps.api.addr_validate(s.as_str()))
+        .transpose()?;
+    if lp_staking.is_none() && msg.referral_reward_limit_option.percent_for_lp_staking != 0 {
+        return Err(ContractError::Std(StdError::generic_err("LP staking contract is required")));
+    }
+
     ReferralRewardLimitOption {
-        overflow_amount_recipient: msg.referral_reward_limit_option.overflow_amount_recipient
-            .map(|r| deps.api.addr_validate(r.as_str()).unwrap()),
+        overflow_mode: if overflow_amount_recipient.is_some() {
+            OverflowMode::Recipient
+        } else {
+            OverflowMode::Reuse
+        },
+        overflow_amount_recipient,
         base_count: msg.referral_reward_limit_option.base_count,
         percent_for_governance_staking: msg.referral_reward_limit_option.percent_for_governance_staking,
+        lp_staking,
+        percent_for_lp_staking: msg.referral_reward_limit_option.percent_for_lp_staking,
+        staking_window_period: msg.referral_reward_limit_option.staking_window_period,
+        limit_window_period: msg.referral_reward_limit_option.limit_window_period,
     }.save(deps.storage)?;
 
     Ok(response)
@@ -62,6 +99,11 @@ pub fn update_config(
     deactivate_period: Option<u64>,
     key_denom: Option<Denom>,
     contract_admin: Option<String>,
+    creation_fee_denom: Option<Denom>,
+    creation_fee_amount: Option<Uint128>,
+    swap_fee_max_spread: Option<Decimal>,
+    swap_fee_keepers: Option<Vec<String>>,
+    spot_price_fallback: Option<bool>,
 ) -> ContractResult<Response> {
     // Validate
     let mut config = Config::load(deps.storage)?;
@@ -159,6 +201,53 @@ pub fn update_config(
         response = response.add_attribute("is_updated_key_denom", "true");
     }
 
+    if let Some(creation_fee_denom) = creation_fee_denom.as_ref() {
+        if !config.is_governance(&info.sender) {
+            return Err(ContractError::Unauthorized {});
+        }
+
+        config.creation_fee_denom = creation_fee_denom.to_cw20(deps.api);
+        response = response.add_attribute("is_updated_creation_fee_denom", "true");
+    }
+
+    if let Some(creation_fee_amount) = creation_fee_amount.as_ref() {
+        if !config.is_governance(&info.sender) {
+            return Err(ContractError::Unauthorized {});
+        }
+
+        config.creation_fee_amount = *creation_fee_amount;
+        response = response.add_attribute("is_updated_creation_fee_amount", "true");
+    }
+
+    if let Some(swap_fee_max_spread) = swap_fee_max_spread {
+        if !config.is_governance(&info.sender) {
+            return Err(ContractError::Unauthorized {});
+        }
+
+        config.swap_fee_max_spread = validate_max_spread(swap_fee_max_spread)?;
+        response = response.add_attribute("is_updated_swap_fee_max_spread", "true");
+    }
+
+    if let Some(swap_fee_keepers) = swap_fee_keepers.as_ref() {
+        if !config.is_governance(&info.sender) {
+            return Err(ContractError::Unauthorized {});
+        }
+
+        config.swap_fee_keepers = swap_fee_keepers.iter()
+            .map(|k| deps.api.addr_validate(k))
+            .collect::<StdResult<Vec<Addr>>>()?;
+        response = response.add_attribute("is_updated_swap_fee_keepers", "true");
+    }
+
+    if let Some(spot_price_fallback) = spot_price_fallback {
+        if !config.is_governance(&info.sender) {
+            return Err(ContractError::Unauthorized {});
+        }
+
+        config.spot_price_fallback = spot_price_fallback;
+        response = response.add_attribute("is_updated_spot_price_fallback", "true");
+    }
+
     if let Some(governance) = governance.as_ref() {
         if !config.is_governance(&info.sender) {
             return Err(ContractError::Unauthorized {});
@@ -182,9 +271,14 @@ pub fn update_referral_reward_limit_option(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
+    overflow_mode: Option<OverflowMode>,
     overflow_amount_recipient: Option<String>,
     base_count: Option<u8>,
     percent_for_governance_staking: Option<u16>,
+    lp_staking: Option<String>,
+    percent_for_lp_staking: Option<u16>,
+    staking_window_period: Option<u64>,
+    limit_window_period: Option<u64>,
 ) -> ContractResult<Response> {
     // Validate
     let config = Config::load(deps.storage)?;
@@ -199,9 +293,20 @@ pub fn update_referral_reward_limit_option(
 
     if let Some(overflow_amount_recipient) = overflow_amount_recipient.as_ref() {
         limit_option.overflow_amount_recipient = Some(deps.api.addr_validate(overflow_amount_recipient.as_str())?);
+        // setting a recipient keeps sending overflow to it unless another mode is given
+        limit_option.overflow_mode = OverflowMode::Recipient;
         response = response.add_attribute("is_updated_overflow_amount_recipient", "true");
     }
 
+    if let Some(overflow_mode) = overflow_mode {
+        if overflow_mode == OverflowMode::Recipient && limit_option.overflow_amount_recipient.is_none() {
+            return Err(ContractError::Std(StdError::generic_err("Overflow amount recipient is required")));
+        }
+
+        limit_option.overflow_mode = overflow_mode;
+        response = response.add_attribute("overflow_mode", limit_option.overflow_mode.to_string());
+    }
+
     if let Some(base_count) = base_count.as_ref() {
         limit_option.base_count = *base_count;
         response = response.add_attribute("is_updated_base_count", "true");
@@ -212,6 +317,30 @@ pub fn update_referral_reward_limit_option(
         response = response.add_attribute("is_updated_percent_for_governance_staking", "true");
     }
 
+    if let Some(lp_staking) = lp_staking.as_ref() {
+        limit_option.lp_staking = Some(deps.api.addr_validate(lp_staking.as_str())?);
+        response = response.add_attribute("is_updated_lp_staking", "true");
+    }
+
+    if let Some(percent_for_lp_staking) = percent_for_lp_staking.as_ref() {
+        limit_option.percent_for_lp_staking = *percent_for_lp_staking;
+        response = response.add_attribute("is_updated_percent_for_lp_staking", "true");
+    }
+
+    if let Some(staking_window_period) = staking_window_period.as_ref() {
+        limit_option.staking_window_period = *staking_window_period;
+        response = response.add_attribute("is_updated_staking_window_period", "true");
+    }
+
+    if let Some(limit_window_period) = limit_window_period.as_ref() {
+        limit_option.limit_window_period = *limit_window_period;
+        response = response.add_attribute("is_updated_limit_window_period", "true");
+    }
+
+    if limit_option.lp_staking.is_none() && limit_option.percent_for_lp_staking != 0 {
+        return Err(ContractError::Std(StdError::generic_err("LP staking contract is required")));
+    }
+
     limit_option.save(deps.storage)?;
 
     Ok(response)
@@ -233,8 +362,10 @@ pub fn set_reuse_overflow_amount(
 
     let mut limit_option = ReferralRewardLimitOption::load(deps.storage)?;
 
+    limit_option.overflow_mode = OverflowMode::Reuse;
     limit_option.overflow_amount_recipient = None;
     response = response.add_attribute("is_updated_overflow_amount_recipient", "true");
+    response = response.add_attribute("overflow_mode", limit_option.overflow_mode.to_string());
 
     limit_option.save(deps.storage)?;
 
@@ -246,33 +377,47 @@ pub const REPLY_CREATE_CAMPAIGN: u64 = 1;
 pub fn create_campaign(
     deps: DepsMut,
     env: Env,
-    info: MessageInfo,
+    sender: Addr,
+    funds: Vec<(cw20::Denom, Uint128)>,
     config_msg: Binary,
     deposit_denom: Option<Denom>,
     deposit_amount: Option<Uint128>,
     deposit_lock_period: Option<u64>,
     qualifier: Option<String>,
     qualification_description: Option<String>,
+    tags: Option<Vec<String>>,
+    logo_url: Option<String>,
 ) -> ContractResult<Response> {
     // Validate
     let config = Config::load(deps.storage)?;
 
+    validate_creation_fee(&config, &funds)?;
+
+    let tags = tags.unwrap_or_default();
+    validate_tags(&tags)?;
+    if let Some(logo_url) = logo_url.as_ref() {
+        validate_url(logo_url)?;
+    }
+
     // Execute
     let mut response = make_response("create_campaign");
 
     CreateCampaignContext {
         code_id: config.code_id,
-        creator: deps.api.addr_validate(info.sender.as_str())?,
+        creator: sender.clone(),
+        tags,
+        logo_url,
     }.save(deps.storage)?;
 
+    // manager is the wasm admin of campaigns, so they can be migrated by migrate_campaigns
     let create_campaign_msg = message_factories::wasm_instantiate(
         config.code_id,
-        Some(config.contract_admin.clone()),
+        Some(env.contract.address.clone()),
         to_binary(&CampaignInstantiateMsg {
             governance: config.governance.to_string(),
             campaign_manager: env.contract.address.to_string(),
-            admin: info.sender.to_string(),
-            creator: info.sender.to_string(),
+            admin: sender.to_string(),
+            creator: sender.to_string(),
             config_msg,
             deposit_denom,
             deposit_amount: deposit_amount.unwrap_or_default(),
@@ -291,8 +436,24 @@ pub fn create_campaign(
     });
 
     response = response.add_attribute("campaign_code_id", config.code_id.to_string());
-    response = response.add_attribute("campaign_creator", info.sender.to_string());
-    response = response.add_attribute("campaign_admin", info.sender.to_string());
+    response = response.add_attribute("campaign_creator", sender.to_string());
+    response = response.add_attribute("campaign_admin", sender.to_string());
+
+    if !config.creation_fee_amount.is_zero() {
+        response = response.add_attribute(
+            "creation_fee",
+            format!(
+                "{}{}",
+                config.creation_fee_amount,
+                Denom::from_cw20(config.creation_fee_denom.clone()),
+            ),
+        );
+
+        // fee paid in valkyrie token is split right away, other denoms wait for swap_fee
+        if config.creation_fee_denom == cw20::Denom::Cw20(config.valkyrie_token.clone()) {
+            response = response.add_messages(make_spend_fee_msgs(deps.storage, &config, config.creation_fee_amount)?);
+        }
+    }
 
     Ok(response)
 }
@@ -328,6 +489,9 @@ pub fn created_campaign(
         address: deps.api.addr_validate(contract_address)?,
         creator: context.creator,
         created_height: env.block.height,
+        tags: context.tags,
+        logo_url: context.logo_url,
+        status: CampaignStatus::Unverified,
     }.save(deps.storage)?;
 
     CreateCampaignContext::clear(deps.storage);
@@ -337,6 +501,245 @@ pub fn created_campaign(
     Ok(response)
 }
 
+fn validate_creation_fee(config: &Config, funds: &[(cw20::Denom, Uint128)]) -> StdResult<()> {
+    if config.creation_fee_amount.is_zero() {
+        return if funds.is_empty() {
+            Ok(())
+        } else {
+            Err(StdError::generic_err("Creation fee is not required"))
+        };
+    }
+
+    match funds.len() {
+        0 => Err(StdError::generic_err("Missing creation fee")),
+        1 => {
+            let (denom, amount) = &funds[0];
+            if *denom != config.creation_fee_denom {
+                Err(StdError::generic_err("Invalid creation fee denom"))
+            } else if *amount != config.creation_fee_amount {
+                Err(StdError::generic_err("Invalid creation fee amount"))
+            } else {
+                Ok(())
+            }
+        }
+        _ => Err(StdError::generic_err("Too many funds")),
+    }
+}
+
+fn validate_tags(tags: &[String]) -> StdResult<()> {
+    if tags.len() > MAX_TAG_COUNT {
+        return Err(StdError::generic_err(format!("Too many tags (max {})", MAX_TAG_COUNT)));
+    }
+
+    for (index, tag) in tags.iter().enumerate() {
+        if tag.len() < MIN_TAG_LENGTH {
+            return Err(StdError::generic_err("Tag too short"));
+        }
+
+        if tag.len() > MAX_TAG_LENGTH {
+            return Err(StdError::generic_err("Tag too long"));
+        }
+
+        if !tag.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-') {
+            return Err(StdError::generic_err("Tag must consist of lowercase letters, digits and '-'"));
+        }
+
+        if tags[..index].contains(tag) {
+            return Err(StdError::generic_err(format!("Duplicated tag ({})", tag)));
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_url(url: &str) -> StdResult<()> {
+    if url.len() < MIN_URL_LENGTH {
+        Err(StdError::generic_err("Url too short"))
+    } else if url.len() > MAX_URL_LENGTH {
+        Err(StdError::generic_err("Url too long"))
+    } else {
+        Ok(())
+    }
+}
+
+pub const MAX_TEMPLATE_NAME_LENGTH: usize = 64;
+
+pub fn create_template(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    name: String,
+    template: CampaignTemplateMsg,
+) -> ContractResult<Response> {
+    // Validate
+    validate_template_name(&name)?;
+
+    // Execute
+    let mut response = make_response("create_template");
+
+    let template = CampaignTemplate {
+        id: get_template_id(deps.storage)?,
+        name,
+        owner: info.sender,
+        template,
+    };
+    template.save(deps.storage)?;
+
+    response = response.add_attribute("template_id", template.id.to_string());
+    response = response.add_attribute("template_owner", template.owner.to_string());
+
+    Ok(response)
+}
+
+pub fn update_template(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    template_id: u64,
+    name: Option<String>,
+    template: Option<CampaignTemplateMsg>,
+) -> ContractResult<Response> {
+    // Validate
+    let mut campaign_template = load_template_for_update(deps.as_ref(), &info.sender, template_id)?;
+
+    // Execute
+    let mut response = make_response("update_template");
+    response = response.add_attribute("template_id", template_id.to_string());
+
+    if let Some(name) = name {
+        validate_template_name(&name)?;
+
+        campaign_template.name = name;
+        response = response.add_attribute("is_updated_name", "true");
+    }
+
+    if let Some(template) = template {
+        campaign_template.template = template;
+        response = response.add_attribute("is_updated_template", "true");
+    }
+
+    campaign_template.save(deps.storage)?;
+
+    Ok(response)
+}
+
+pub fn remove_template(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    template_id: u64,
+) -> ContractResult<Response> {
+    // Validate
+    let campaign_template = load_template_for_update(deps.as_ref(), &info.sender, template_id)?;
+
+    // Execute
+    let mut response = make_response("remove_template");
+
+    campaign_template.remove(deps.storage);
+
+    response = response.add_attribute("template_id", template_id.to_string());
+
+    Ok(response)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_campaign_from_template(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    funds: Vec<(cw20::Denom, Uint128)>,
+    template_id: u64,
+    overrides: Option<CampaignTemplateMsg>,
+    tags: Option<Vec<String>>,
+    logo_url: Option<String>,
+) -> ContractResult<Response> {
+    // Validate
+    let campaign_template = CampaignTemplate::may_load(deps.storage, template_id)?
+        .ok_or(ContractError::NotFound {})?;
+
+    let template = merge_template(campaign_template.template, overrides.unwrap_or_default());
+    let config_msg = to_binary(&CampaignConfigMsg {
+        title: template.title.ok_or_else(|| missing_template_field("title"))?,
+        description: template.description.ok_or_else(|| missing_template_field("description"))?,
+        url: template.url.ok_or_else(|| missing_template_field("url"))?,
+        parameter_key: template.parameter_key.ok_or_else(|| missing_template_field("parameter_key"))?,
+        participation_reward_denom: template.participation_reward_denom
+            .ok_or_else(|| missing_template_field("participation_reward_denom"))?,
+        participation_reward_amount: template.participation_reward_amount
+            .ok_or_else(|| missing_template_field("participation_reward_amount"))?,
+        participation_reward_lock_period: template.participation_reward_lock_period
+            .ok_or_else(|| missing_template_field("participation_reward_lock_period"))?,
+        referral_reward_amounts: template.referral_reward_amounts
+            .ok_or_else(|| missing_template_field("referral_reward_amounts"))?,
+        referral_reward_lock_period: template.referral_reward_lock_period
+            .ok_or_else(|| missing_template_field("referral_reward_lock_period"))?,
+    })?;
+
+    // Execute
+    let response = create_campaign(
+        deps,
+        env,
+        sender,
+        funds,
+        config_msg,
+        template.deposit_denom,
+        template.deposit_amount,
+        template.deposit_lock_period,
+        template.qualifier,
+        template.qualification_description,
+        tags,
+        logo_url,
+    )?;
+
+    Ok(response.add_attribute("template_id", template_id.to_string()))
+}
+
+fn load_template_for_update(deps: Deps, sender: &Addr, template_id: u64) -> ContractResult<CampaignTemplate> {
+    let campaign_template = CampaignTemplate::may_load(deps.storage, template_id)?
+        .ok_or(ContractError::NotFound {})?;
+
+    let config = Config::load(deps.storage)?;
+    if !campaign_template.is_owner(sender) && !config.is_governance(sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    Ok(campaign_template)
+}
+
+fn validate_template_name(name: &str) -> StdResult<()> {
+    if name.is_empty() {
+        Err(StdError::generic_err("Template name must not be empty"))
+    } else if name.len() > MAX_TEMPLATE_NAME_LENGTH {
+        Err(StdError::generic_err("Template name too long"))
+    } else {
+        Ok(())
+    }
+}
+
+fn merge_template(template: CampaignTemplateMsg, overrides: CampaignTemplateMsg) -> CampaignTemplateMsg {
+    CampaignTemplateMsg {
+        title: overrides.title.or(template.title),
+        description: overrides.description.or(template.description),
+        url: overrides.url.or(template.url),
+        parameter_key: overrides.parameter_key.or(template.parameter_key),
+        participation_reward_denom: overrides.participation_reward_denom.or(template.participation_reward_denom),
+        participation_reward_amount: overrides.participation_reward_amount.or(template.participation_reward_amount),
+        participation_reward_lock_period: overrides.participation_reward_lock_period
+            .or(template.participation_reward_lock_period),
+        referral_reward_amounts: overrides.referral_reward_amounts.or(template.referral_reward_amounts),
+        referral_reward_lock_period: overrides.referral_reward_lock_period.or(template.referral_reward_lock_period),
+        deposit_denom: overrides.deposit_denom.or(template.deposit_denom),
+        deposit_amount: overrides.deposit_amount.or(template.deposit_amount),
+        deposit_lock_period: overrides.deposit_lock_period.or(template.deposit_lock_period),
+        qualifier: overrides.qualifier.or(template.qualifier),
+        qualification_description: overrides.qualification_description.or(template.qualification_description),
+    }
+}
+
+fn missing_template_field(field: &str) -> StdError {
+    StdError::generic_err(format!("Missing template field ({})", field))
+}
+
 pub fn spend_fee(
     deps: DepsMut,
     env: Env,
@@ -357,61 +760,402 @@ pub fn spend_fee(
         )?
     };
 
-    let burn_amount = amount * config.fee_burn_ratio;
-    let distribute_amount = amount.checked_sub(burn_amount)?;
+    response = response.add_messages(make_spend_fee_msgs(deps.storage, &config, amount)?);
 
-    response = response.add_message(message_factories::wasm_execute(
-        &config.valkyrie_token,
-        &Cw20ExecuteMsg::Transfer {
-            recipient: config.fee_recipient.to_string(),
-            amount: distribute_amount,
-        },
-    ));
+    Ok(response)
+}
 
-    response = response.add_message(message_factories::wasm_execute(
-        &config.valkyrie_token,
-        &Cw20ExecuteMsg::Burn {
-            amount: burn_amount,
-        },
-    ));
+fn make_spend_fee_msgs(
+    storage: &dyn Storage,
+    config: &Config,
+    amount: Uint128,
+) -> StdResult<Vec<CosmosMsg>> {
+    let fee_splits = FeeSplits::load(storage)?;
+
+    if fee_splits.splits.is_empty() {
+        let burn_amount = amount * config.fee_burn_ratio;
+        let distribute_amount = amount.checked_sub(burn_amount)?;
+
+        return Ok(vec![
+            message_factories::wasm_execute(
+                &config.valkyrie_token,
+                &Cw20ExecuteMsg::Transfer {
+                    recipient: config.fee_recipient.to_string(),
+                    amount: distribute_amount,
+                },
+            ),
+            message_factories::wasm_execute(
+                &config.valkyrie_token,
+                &Cw20ExecuteMsg::Burn {
+                    amount: burn_amount,
+                },
+            ),
+        ]);
+    }
+
+    let mut messages = vec![];
+    let mut distributed_amount = Uint128::zero();
+
+    for split in fee_splits.splits.iter() {
+        let split_amount = amount * split.weight;
+        if split_amount.is_zero() {
+            continue;
+        }
+        distributed_amount = distributed_amount.checked_add(split_amount)?;
+
+        let msg = match (&split.recipient, &split.msg) {
+            (FeeSplitRecipient::Burn, _) => Cw20ExecuteMsg::Burn {
+                amount: split_amount,
+            },
+            (FeeSplitRecipient::Address(recipient), Some(msg)) => Cw20ExecuteMsg::Send {
+                contract: recipient.to_string(),
+                amount: split_amount,
+                msg: msg.clone(),
+            },
+            (FeeSplitRecipient::Address(recipient), None) => Cw20ExecuteMsg::Transfer {
+                recipient: recipient.to_string(),
+                amount: split_amount,
+            },
+        };
+        messages.push(message_factories::wasm_execute(&config.valkyrie_token, &msg));
+    }
+
+    let dust_amount = amount.checked_sub(distributed_amount)?;
+    if !dust_amount.is_zero() {
+        messages.push(message_factories::wasm_execute(
+            &config.valkyrie_token,
+            &Cw20ExecuteMsg::Transfer {
+                recipient: fee_splits.dust_recipient(config).to_string(),
+                amount: dust_amount,
+            },
+        ));
+    }
+
+    Ok(messages)
+}
+
+pub fn update_fee_splits(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    splits: Vec<FeeSplitMsg>,
+    dust_recipient: Option<String>,
+) -> ContractResult<Response> {
+    // Validate
+    let config = Config::load(deps.storage)?;
+    if !config.is_governance(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if splits.len() > MAX_FEE_SPLIT_COUNT {
+        return Err(ContractError::Std(StdError::generic_err("Too many fee splits")));
+    }
+
+    let mut fee_splits = vec![];
+    let mut total_weight = Decimal::zero();
+    for split in splits.into_iter() {
+        if split.weight.is_zero() || split.weight > Decimal::one() {
+            return Err(ContractError::Std(StdError::generic_err("Fee split weight must be in (0, 1]")));
+        }
+        total_weight = total_weight + split.weight;
+
+        let recipient = match split.recipient {
+            FeeSplitMsgRecipient::Burn => {
+                if split.msg.is_some() {
+                    return Err(ContractError::Std(StdError::generic_err("Burn fee split can not have a msg")));
+                }
+                FeeSplitRecipient::Burn
+            }
+            FeeSplitMsgRecipient::Address(address) => {
+                FeeSplitRecipient::Address(deps.api.addr_validate(address.as_str())?)
+            }
+        };
+
+        fee_splits.push(FeeSplit {
+            recipient,
+            weight: split.weight,
+            msg: split.msg,
+        });
+    }
+
+    // empty splits fall back to fee_burn_ratio and fee_recipient
+    if !fee_splits.is_empty() && total_weight != Decimal::one() {
+        return Err(ContractError::Std(StdError::generic_err("Sum of fee split weights must be 1")));
+    }
+
+    let dust_recipient = dust_recipient
+        .map(|address| deps.api.addr_validate(address.as_str()))
+        .transpose()?;
+
+    // Execute
+    let mut response = make_response("update_fee_splits");
+
+    let fee_splits = FeeSplits {
+        splits: fee_splits,
+        dust_recipient,
+    };
+    fee_splits.save(deps.storage)?;
+
+    response = response.add_attribute("split_count", fee_splits.splits.len().to_string());
+    response = response.add_attribute("dust_recipient", fee_splits.dust_recipient(&config).to_string());
 
     Ok(response)
 }
 
-pub fn swap_fee(
+pub fn set_campaign_status(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address: String,
+    status: CampaignStatus,
+) -> ContractResult<Response> {
+    // Validate
+    let config = Config::load(deps.storage)?;
+    if !config.is_governance(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let address = deps.api.addr_validate(&address)?;
+    let mut campaign = Campaign::may_load(deps.storage, &address)?
+        .ok_or(ContractError::NotFound {})?;
+
+    // Execute
+    let mut response = make_response("set_campaign_status");
+
+    campaign.status = status;
+    campaign.save(deps.storage)?;
+
+    response = response.add_attribute("campaign", campaign.address.to_string());
+    response = response.add_attribute("status", campaign.status.to_string());
+
+    Ok(response)
+}
+
+pub const MAX_CLAIM_CAMPAIGNS: usize = 30;
+
+pub fn claim_all(
     deps: DepsMut,
     env: Env,
+    info: MessageInfo,
+    campaigns: Vec<String>,
+) -> ContractResult<Response> {
+    // Validate
+    let campaigns = validate_campaigns(deps.as_ref(), campaigns, MAX_CLAIM_CAMPAIGNS)?;
+
+    // Execute
+    let mut response = make_response("claim_all");
+
+    for campaign in campaigns.iter() {
+        let actor = load_campaign_actor(&deps.querier, &campaign.address, &info.sender)?;
+
+        let participation_reward_amount = calc_unlocked_reward_amount(
+            &actor.participation_reward_amounts,
+            env.block.height,
+        );
+        let referral_reward_amount = calc_unlocked_reward_amount(
+            &actor.referral_reward_amounts,
+            env.block.height,
+        );
+
+        if participation_reward_amount.is_zero() && referral_reward_amount.is_zero() {
+            continue;
+        }
+
+        response = response.add_message(message_factories::wasm_execute(
+            &campaign.address,
+            &CampaignExecuteMsg::ClaimRewardFor {
+                actor: info.sender.to_string(),
+            },
+        ));
+        response = response.add_attribute("claimed_campaign", campaign.address.to_string());
+    }
+
+    if response.messages.is_empty() {
+        return Err(ContractError::Std(StdError::generic_err("Not exist claimable reward")));
+    }
+
+    Ok(response)
+}
+
+pub fn validate_campaigns(deps: Deps, campaigns: Vec<String>, max_campaigns: usize) -> StdResult<Vec<Campaign>> {
+    if campaigns.is_empty() {
+        return Err(StdError::generic_err("campaigns must not be empty"));
+    }
+
+    if campaigns.len() > max_campaigns {
+        return Err(StdError::generic_err(format!(
+            "Too many campaigns (max {})", max_campaigns,
+        )));
+    }
+
+    let mut result: Vec<Campaign> = vec![];
+    for campaign in campaigns.iter() {
+        let address = deps.api.addr_validate(campaign)?;
+        if result.iter().any(|c| c.address == address) {
+            continue;
+        }
+
+        let campaign = Campaign::may_load(deps.storage, &address)?
+            .ok_or_else(|| StdError::generic_err(format!("{} is not a registered campaign", address)))?;
+
+        result.push(campaign);
+    }
+
+    Ok(result)
+}
+
+pub const REPLY_MIGRATE_CAMPAIGN: u64 = 2;
+pub const MAX_MIGRATE_CAMPAIGNS: usize = 10;
+
+#[allow(clippy::too_many_arguments)]
+pub fn migrate_campaigns(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    code_id: u64,
+    campaigns: Option<Vec<String>>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+    msg: Binary,
+) -> ContractResult<Response> {
+    // Validate
+    let config = Config::load(deps.storage)?;
+    if !config.is_governance(&info.sender) && !config.is_contract_admin(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let campaigns = match campaigns {
+        Some(campaigns) => validate_campaigns(deps.as_ref(), campaigns, MAX_MIGRATE_CAMPAIGNS)?
+            .into_iter()
+            .map(|c| c.address)
+            .collect::<Vec<Addr>>(),
+        None => {
+            let limit = limit.unwrap_or(MAX_MIGRATE_CAMPAIGNS as u32).min(MAX_MIGRATE_CAMPAIGNS as u32);
+            Campaign::query(deps.storage, None, start_after, Some(limit), Some(OrderBy::Asc))?
+                .campaigns
+                .into_iter()
+                .map(|c| Addr::unchecked(c.address))
+                .collect::<Vec<Addr>>()
+        }
+    };
+
+    if campaigns.is_empty() {
+        return Err(ContractError::Std(StdError::generic_err("Not exist campaign to migrate")));
+    }
+
+    // Execute
+    let mut response = make_response("migrate_campaigns");
+
+    for campaign in campaigns.iter() {
+        response = response.add_submessage(SubMsg {
+            id: REPLY_MIGRATE_CAMPAIGN,
+            msg: message_factories::wasm_migrate(campaign, code_id, msg.clone()),
+            gas_limit: None,
+            reply_on: ReplyOn::Always,
+        });
+    }
+
+    response = response.add_attribute("code_id", code_id.to_string());
+    response = response.add_attribute("campaign_count", campaigns.len().to_string());
+    response = response.add_attribute("last_campaign", campaigns.last().unwrap().to_string());
+
+    MigrateCampaignsContext {
+        code_id,
+        campaigns,
+    }.save(deps.storage)?;
+
+    Ok(response)
+}
+
+pub fn migrated_campaign(
+    deps: DepsMut,
+    _env: Env,
+    msg: Reply,
+) -> ContractResult<Response> {
+    // Validate
+    let mut context = MigrateCampaignsContext::load(deps.storage)?;
+    if context.campaigns.is_empty() {
+        return Err(ContractError::Std(StdError::generic_err("Invalid migrate campaigns context")));
+    }
+
+    // Execute
+    let mut response = make_response("migrated_campaign");
+
+    // replies arrive in the order the submessages were dispatched
+    let campaign_address = context.campaigns.remove(0);
+    response = response.add_attribute("campaign", campaign_address.to_string());
+
+    match msg.result.into_result() {
+        Ok(_) => {
+            let mut campaign = Campaign::load(deps.storage, &campaign_address)?;
+            campaign.code_id = context.code_id;
+            campaign.save(deps.storage)?;
+
+            response = response.add_attribute("result", "success");
+        }
+        Err(err) => {
+            response = response.add_attribute("result", "failure");
+            response = response.add_attribute("error", err);
+        }
+    }
+
+    if context.campaigns.is_empty() {
+        MigrateCampaignsContext::clear(deps.storage);
+    } else {
+        context.save(deps.storage)?;
+    }
+
+    Ok(response)
+}
+
+pub fn migrate_legacy_campaigns(
+    deps: DepsMut,
+    _env: Env,
     _info: MessageInfo,
+    limit: Option<u32>,
+) -> ContractResult<Response> {
+    // Execute
+    let mut response = make_response("migrate_legacy_campaigns");
+
+    let (migrated_count, is_completed) = migrations::migrate_legacy_campaigns(deps.storage, limit)?;
+    response = response.add_attribute("migrated_count", migrated_count.to_string());
+    response = response.add_attribute("is_completed", is_completed.to_string());
+
+    Ok(response)
+}
+
+pub fn swap_fee(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
     denom: Denom,
     amount: Option<Uint128>,
     route: Option<Vec<Denom>>,
+    minimum_receive: Option<Uint128>,
 ) -> ContractResult<Response> {
     // Validate
     let config = Config::load(deps.storage)?;
-    let token_denom = Denom::Token(config.valkyrie_token.to_string());
-    let route = route.unwrap_or_else(|| vec![denom.clone(), token_denom.clone()]);
-
-    if route.len() < 2 || *route.first().unwrap() != denom || *route.last().unwrap() != token_denom {
-        return Err(ContractError::Std(StdError::generic_err(
-            format!(
-                "route must start with '{}' and end with '{}'",
-                denom.to_string(), token_denom.to_string(),
-            )
-        )));
+    if !config.is_governance(&info.sender)
+        && !config.is_contract_admin(&info.sender)
+        && !config.is_swap_fee_keeper(&info.sender) {
+        return Err(ContractError::Unauthorized {});
     }
 
-    // Execute
-    let mut response = make_response("swap_fee");
-
-    let operations: Vec<SwapOperation> = route.windows(2).map(|pair| {
-        pair_to_terraswap_operation(pair)
-    }).collect();
+    let token_denom = Denom::Token(config.valkyrie_token.to_string());
+    if denom == token_denom {
+        return Err(ContractError::Std(StdError::generic_err("Can not swap valkyrie token to itself")));
+    }
 
-    let terraswap_msg = TerraswapExecuteMsg::ExecuteSwapOperations {
-        operations,
-        minimum_receive: None,
-        to: None,
-    };
+    if let Some(route) = route.as_ref() {
+        if route.len() < 2 || *route.first().unwrap() != denom || *route.last().unwrap() != token_denom {
+            return Err(ContractError::Std(StdError::generic_err(
+                format!(
+                    "route must start with '{}' and end with '{}'",
+                    denom, token_denom,
+                )
+            )));
+        }
+    }
 
     let balance = query_balance(
         &deps.querier,
@@ -432,6 +1176,43 @@ pub fn swap_fee(
         return Err(ContractError::InvalidZeroAmount {});
     }
 
+    // Execute
+    let mut response = make_response("swap_fee");
+
+    let (route, minimum_receive) = match route {
+        Some(route) => {
+            let minimum_receive = match minimum_receive {
+                Some(minimum_receive) => minimum_receive,
+                None => {
+                    let simulated_amount = simulate_route(&deps.querier, &config, &route, amount)?;
+                    simulated_amount.checked_sub(simulated_amount * config.swap_fee_max_spread)?
+                }
+            };
+
+            (route, minimum_receive)
+        }
+        None => {
+            let (route, simulated_amount) = simulate_best_route(
+                deps.as_ref(),
+                &config,
+                &denom,
+                &token_denom,
+                amount,
+            )?;
+            let minimum_receive = minimum_receive.unwrap_or(
+                simulated_amount.checked_sub(simulated_amount * config.swap_fee_max_spread)?
+            );
+
+            (route, minimum_receive)
+        }
+    };
+
+    let terraswap_msg = TerraswapExecuteMsg::ExecuteSwapOperations {
+        operations: route_to_terraswap_operations(&route),
+        minimum_receive: Some(minimum_receive),
+        to: None,
+    };
+
     let swap_msg = match denom {
         Denom::Native(denom) => {
             message_factories::wasm_execute_with_funds(
@@ -453,10 +1234,153 @@ pub fn swap_fee(
     };
 
     response = response.add_message(swap_msg);
+    response = response.add_attribute(
+        "route",
+        route.iter().map(|d| d.to_string()).collect::<Vec<String>>().join(">"),
+    );
+    response = response.add_attribute("minimum_receive", minimum_receive.to_string());
 
     Ok(response)
 }
 
+pub fn add_swap_route(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    route: Vec<Denom>,
+) -> ContractResult<Response> {
+    // Validate
+    let config = Config::load(deps.storage)?;
+    if !config.is_governance(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if route.len() < 2 {
+        return Err(ContractError::Std(StdError::generic_err("route must have at least 2 denoms")));
+    }
+
+    if route.windows(2).any(|pair| pair[0] == pair[1]) {
+        return Err(ContractError::Std(StdError::generic_err("route must not swap to the same denom")));
+    }
+
+    let offer = route.first().unwrap();
+    let ask = route.last().unwrap();
+    let mut routes = load_swap_routes(deps.storage, offer, ask)?;
+    if routes.contains(&route) {
+        return Err(ContractError::Std(StdError::generic_err("Already registered route")));
+    }
+
+    // Execute
+    let mut response = make_response("add_swap_route");
+
+    routes.push(route.clone());
+    save_swap_routes(deps.storage, offer, ask, &routes)?;
+
+    response = response.add_attribute(
+        "route",
+        route.iter().map(|d| d.to_string()).collect::<Vec<String>>().join(">"),
+    );
+
+    Ok(response)
+}
+
+pub fn remove_swap_route(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    route: Vec<Denom>,
+) -> ContractResult<Response> {
+    // Validate
+    let config = Config::load(deps.storage)?;
+    if !config.is_governance(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let (offer, ask) = match (route.first(), route.last()) {
+        (Some(offer), Some(ask)) => (offer, ask),
+        _ => return Err(ContractError::Std(StdError::generic_err("Not registered route"))),
+    };
+
+    let mut routes = load_swap_routes(deps.storage, offer, ask)?;
+    let index = routes.iter().position(|r| *r == route)
+        .ok_or_else(|| StdError::generic_err("Not registered route"))?;
+
+    // Execute
+    let mut response = make_response("remove_swap_route");
+
+    routes.remove(index);
+    save_swap_routes(deps.storage, offer, ask, &routes)?;
+
+    response = response.add_attribute(
+        "route",
+        route.iter().map(|d| d.to_string()).collect::<Vec<String>>().join(">"),
+    );
+
+    Ok(response)
+}
+
+/// Simulates every registered route from `offer` to `ask` (or the direct pair if none is registered)
+/// and returns the one with the largest return amount.
+pub fn simulate_best_route(
+    deps: Deps,
+    config: &Config,
+    offer: &Denom,
+    ask: &Denom,
+    amount: Uint128,
+) -> StdResult<(Vec<Denom>, Uint128)> {
+    if offer == ask {
+        return Ok((vec![offer.clone()], amount));
+    }
+
+    let mut routes = load_swap_routes(deps.storage, offer, ask)?;
+    if routes.is_empty() {
+        routes.push(vec![offer.clone(), ask.clone()]);
+    }
+
+    let mut best: Option<(Vec<Denom>, Uint128)> = None;
+    for route in routes.into_iter() {
+        // a route without liquidity should not block the others
+        if let Ok(simulated_amount) = simulate_route(&deps.querier, config, &route, amount) {
+            if best.as_ref().map_or(true, |(_, best_amount)| simulated_amount > *best_amount) {
+                best = Some((route, simulated_amount));
+            }
+        }
+    }
+
+    best.ok_or_else(|| StdError::generic_err(format!("No available swap route ({} > {})", offer, ask)))
+}
+
+fn simulate_route(
+    querier: &QuerierWrapper,
+    config: &Config,
+    route: &[Denom],
+    amount: Uint128,
+) -> StdResult<Uint128> {
+    let response: SimulateSwapOperationsResponse = querier.query_wasm_smart(
+        &config.terraswap_router,
+        &TerraswapQueryMsg::SimulateSwapOperations {
+            offer_amount: amount,
+            operations: route_to_terraswap_operations(route),
+        },
+    )?;
+
+    Ok(response.amount)
+}
+
+fn route_to_terraswap_operations(route: &[Denom]) -> Vec<SwapOperation> {
+    route.windows(2).map(|pair| {
+        pair_to_terraswap_operation(pair)
+    }).collect()
+}
+
+fn validate_max_spread(max_spread: Decimal) -> StdResult<Decimal> {
+    if max_spread > Decimal::one() {
+        Err(StdError::generic_err("max_spread must be less than or equal to 1"))
+    } else {
+        Ok(max_spread)
+    }
+}
+
 fn pair_to_terraswap_operation(pair: &[Denom]) -> SwapOperation {
     let left = pair[0].clone();
     let right = pair[1].clone();
@@ -486,3 +1410,202 @@ fn denom_to_asset_info(denom: Denom) -> AssetInfo {
         },
     }
 }
+
+pub fn set_price_source(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    base: Denom,
+    quote: Denom,
+    source: PriceSourceMsg,
+    max_staleness: u64,
+) -> ContractResult<Response> {
+    // Validate
+    let config = Config::load(deps.storage)?;
+    if !config.is_governance(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if base == quote {
+        return Err(ContractError::Std(StdError::generic_err("base and quote must be different")));
+    }
+
+    match source {
+        PriceSourceMsg::Fixed { price } => if price.is_zero() {
+            return Err(ContractError::Std(StdError::generic_err("price must be greater than zero")));
+        },
+        PriceSourceMsg::Twap { window } => if window == 0 {
+            return Err(ContractError::Std(StdError::generic_err("window must be greater than zero")));
+        },
+    }
+
+    // Execute
+    let mut response = make_response("set_price_source");
+
+    let price_source = PriceSource::new(source, max_staleness, env.block.height);
+    price_source.save(deps.storage, &base, &quote)?;
+
+    response = response.add_attribute("base", base.to_string());
+    response = response.add_attribute("quote", quote.to_string());
+
+    Ok(response)
+}
+
+pub fn remove_price_source(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    base: Denom,
+    quote: Denom,
+) -> ContractResult<Response> {
+    // Validate
+    let config = Config::load(deps.storage)?;
+    if !config.is_governance(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if PriceSource::may_load(deps.storage, &base, &quote)?.is_none() {
+        return Err(ContractError::NotFound {});
+    }
+
+    // Execute
+    let mut response = make_response("remove_price_source");
+
+    PriceSource::remove(deps.storage, &base, &quote);
+
+    response = response.add_attribute("base", base.to_string());
+    response = response.add_attribute("quote", quote.to_string());
+
+    Ok(response)
+}
+
+pub fn record_price(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    base: Denom,
+    quote: Denom,
+) -> ContractResult<Response> {
+    // Validate
+    let config = Config::load(deps.storage)?;
+    if !config.is_governance(&info.sender)
+        && !config.is_contract_admin(&info.sender)
+        && !config.is_swap_fee_keeper(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut price_source = PriceSource::load(deps.storage, &base, &quote)?;
+
+    // Execute
+    let mut response = make_response("record_price");
+
+    let (_, amount) = simulate_best_route(
+        deps.as_ref(),
+        &config,
+        &base,
+        &quote,
+        Uint128::new(PRICE_UNIT),
+    )?;
+    let price = Decimal::from_ratio(amount, PRICE_UNIT);
+
+    price_source.record(env.block.height, price)?;
+    price_source.save(deps.storage, &base, &quote)?;
+
+    response = response.add_attribute("base", base.to_string());
+    response = response.add_attribute("quote", quote.to_string());
+    response = response.add_attribute("price", price.to_string());
+
+    Ok(response)
+}
+
+pub fn update_add_pool_fee_tiers(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    tiers: Vec<FeeTierMsg>,
+) -> ContractResult<Response> {
+    // Validate
+    let config = Config::load(deps.storage)?;
+    if !config.is_governance(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if tiers.len() > MAX_FEE_TIER_COUNT {
+        return Err(ContractError::Std(StdError::generic_err("Too many fee tiers")));
+    }
+
+    let mut prev_tier: Option<&FeeTierMsg> = None;
+    for tier in tiers.iter() {
+        if tier.fee_rate > config.add_pool_fee_rate {
+            return Err(ContractError::Std(StdError::generic_err("Tier fee rate must be less than or equal to add_pool_fee_rate")));
+        }
+
+        if let Some(prev_tier) = prev_tier {
+            if tier.min_staking_amount <= prev_tier.min_staking_amount {
+                return Err(ContractError::Std(StdError::generic_err("Tiers must be sorted by min_staking_amount in ascending order")));
+            }
+            if tier.fee_rate > prev_tier.fee_rate {
+                return Err(ContractError::Std(StdError::generic_err("Higher tier must not have higher fee rate")));
+            }
+        }
+        prev_tier = Some(tier);
+    }
+
+    // Execute
+    let mut response = make_response("update_add_pool_fee_tiers");
+
+    save_add_pool_fee_tiers(deps.storage, &tiers)?;
+
+    response = response.add_attribute("tier_count", tiers.len().to_string());
+
+    Ok(response)
+}
+
+pub fn set_campaign_overrides(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    campaign: String,
+    overrides: CampaignOverridesMsg,
+) -> ContractResult<Response> {
+    // Validate
+    let config = Config::load(deps.storage)?;
+    if !config.is_governance(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let campaign = deps.api.addr_validate(campaign.as_str())?;
+    if Campaign::may_load(deps.storage, &campaign)?.is_none() {
+        return Err(ContractError::NotFound {});
+    }
+
+    if let Some(overflow_amount_recipient) = overrides.overflow_amount_recipient.as_ref() {
+        deps.api.addr_validate(overflow_amount_recipient)?;
+    }
+    if let Some(lp_staking) = overrides.lp_staking.as_ref() {
+        deps.api.addr_validate(lp_staking)?;
+    }
+
+    // Execute
+    let mut response = make_response("set_campaign_overrides");
+
+    save_campaign_overrides(deps.storage, &campaign, &overrides)?;
+
+    // overrides are merged with the global option, so the result is validated as a whole
+    let limit_option = crate::queries::get_effective_config(deps.as_ref(), env, campaign.to_string())?
+        .referral_reward_limit_option;
+    if limit_option.overflow_mode == OverflowMode::Recipient && limit_option.overflow_amount_recipient.is_none() {
+        return Err(ContractError::Std(StdError::generic_err("Overflow amount recipient is required")));
+    }
+    if limit_option.lp_staking.is_none() && limit_option.percent_for_lp_staking != 0 {
+        return Err(ContractError::Std(StdError::generic_err("LP staking contract is required")));
+    }
+
+    response = response.add_attribute("campaign", campaign.to_string());
+    response = response.add_attribute(
+        "is_cleared",
+        (overrides == CampaignOverridesMsg::default()).to_string(),
+    );
+
+    Ok(response)
+}
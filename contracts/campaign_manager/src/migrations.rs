@@ -1,16 +1,18 @@
-use cosmwasm_std::{Addr, Decimal, DepsMut, Env, Response};
+use cosmwasm_std::{Addr, Decimal, DepsMut, Env, Response, StdError, StdResult, Storage, Uint128};
 use cw20::Denom;
-use cw_storage_plus::Item;
+use cw_storage_plus::{Item, Map};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use valkyrie::campaign_manager::enumerations::OverflowMode;
 use valkyrie::campaign_manager::execute_msgs::MigrateMsg;
-use valkyrie::common::ContractResult;
+use valkyrie::common::{ContractResult, OrderBy};
+use valkyrie::pagination::addr_range_option;
 use valkyrie::utils::make_response;
 
-use crate::states::Config;
+use crate::states::{Campaign, Config, ReferralRewardLimitOption};
 
-pub fn v1_0_6(
+pub fn v1_0_7(
     deps: DepsMut,
     _env: Env,
     msg: MigrateMsg,
@@ -19,7 +21,7 @@ pub fn v1_0_6(
 
     Config {
         governance: legacy_config.governance,
-        valkyrie_token: legacy_config.valkyrie_token,
+        valkyrie_token: legacy_config.valkyrie_token.clone(),
         terraswap_router: legacy_config.terraswap_router,
         code_id: legacy_config.code_id,
         add_pool_fee_rate: legacy_config.add_pool_fee_rate,
@@ -30,9 +32,74 @@ pub fn v1_0_6(
         deactivate_period: legacy_config.deactivate_period,
         key_denom: legacy_config.key_denom,
         contract_admin: deps.api.addr_validate(msg.contract_admin.as_str())?,
+        creation_fee_denom: Denom::Cw20(legacy_config.valkyrie_token),
+        creation_fee_amount: Uint128::zero(),
+        swap_fee_max_spread: Decimal::percent(1),
+        swap_fee_keepers: vec![],
+        spot_price_fallback: false,
     }.save(deps.storage)?;
 
-    Ok(make_response("migrate_v1_0_6"))
+    let legacy_limit_option = REFERRAL_REWARD_LIMIT_OPTION_LEGACY.load(deps.storage)?;
+    ReferralRewardLimitOption {
+        overflow_mode: if legacy_limit_option.overflow_amount_recipient.is_some() {
+            OverflowMode::Recipient
+        } else {
+            OverflowMode::Reuse
+        },
+        overflow_amount_recipient: legacy_limit_option.overflow_amount_recipient,
+        base_count: legacy_limit_option.base_count,
+        percent_for_governance_staking: legacy_limit_option.percent_for_governance_staking,
+        lp_staking: None,
+        percent_for_lp_staking: 0,
+        staking_window_period: 0,
+        limit_window_period: 0,
+    }.save(deps.storage)?;
+
+    // campaigns are indexed in pages, the rest by MigrateLegacyCampaigns
+    LEGACY_CAMPAIGN_CURSOR.save(deps.storage, &None)?;
+    let (migrated_count, is_completed) = migrate_legacy_campaigns(deps.storage, None)?;
+
+    Ok(make_response("migrate_v1_0_7")
+        .add_attribute("migrated_count", migrated_count.to_string())
+        .add_attribute("is_completed", is_completed.to_string()))
+}
+
+// Legacy campaigns load as `Campaign` with default tags and status,
+// so saving them again is enough to build the indexes.
+pub fn migrate_legacy_campaigns(
+    storage: &mut dyn Storage,
+    limit: Option<u32>,
+) -> StdResult<(usize, bool)> {
+    let start_after = LEGACY_CAMPAIGN_CURSOR.may_load(storage)?
+        .ok_or_else(|| StdError::generic_err("Legacy campaigns are already migrated"))?;
+
+    let range_option = addr_range_option(
+        start_after.map(|v| v.to_string()),
+        limit,
+        Some(OrderBy::Asc),
+    );
+
+    let campaigns = CAMPAIGN_LEGACY
+        .range(storage, range_option.min, range_option.max, range_option.order_by)
+        .take(range_option.limit)
+        .map(|item| {
+            let (_, campaign) = item?;
+            Ok(campaign)
+        })
+        .collect::<StdResult<Vec<Campaign>>>()?;
+
+    for campaign in campaigns.iter() {
+        campaign.save(storage)?;
+    }
+
+    let is_completed = campaigns.len() < range_option.limit;
+    if is_completed {
+        LEGACY_CAMPAIGN_CURSOR.remove(storage);
+    } else {
+        LEGACY_CAMPAIGN_CURSOR.save(storage, &campaigns.last().map(|c| c.address.clone()))?;
+    }
+
+    Ok((campaigns.len(), is_completed))
 }
 
 const CONFIG_LEGACY: Item<LegacyConfig> = Item::new("config");
@@ -50,4 +117,17 @@ pub struct LegacyConfig {
     pub fee_recipient: Addr,
     pub deactivate_period: u64,
     pub key_denom: Denom,
-}
\ No newline at end of file
+    pub contract_admin: Addr,
+}
+
+const REFERRAL_REWARD_LIMIT_OPTION_LEGACY: Item<LegacyReferralRewardLimitOption> = Item::new("referral_reward_limit_option");
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LegacyReferralRewardLimitOption {
+    pub overflow_amount_recipient: Option<Addr>,
+    pub base_count: u8,
+    pub percent_for_governance_staking: u16,
+}
+
+const CAMPAIGN_LEGACY: Map<&Addr, Campaign> = Map::new("campaign");
+const LEGACY_CAMPAIGN_CURSOR: Item<Option<Addr>> = Item::new("legacy_campaign_cursor");
@@ -1,11 +1,22 @@
-use cosmwasm_std::{Deps, Env};
+use cosmwasm_std::{Addr, Decimal, Deps, Env, StdError, Uint128};
 
-use valkyrie::campaign_manager::query_msgs::{CampaignResponse, CampaignsResponse, ConfigResponse, ReferralRewardLimitOptionResponse};
+use valkyrie::campaign_manager::enumerations::{CampaignFilter, OverflowMode};
+use valkyrie::campaign_manager::execute_msgs::PriceSourceMsg;
+use valkyrie::campaign_manager::query_msgs::{AddPoolFeeTiersResponse, FeeQuoteResponse, CampaignOverridesResponse, CampaignResponse, CampaignsResponse, ConfigResponse, EffectiveConfigResponse, FeeSplitsResponse, PendingRewardsResponse, PriceResponse, ReferralRewardLimitOptionResponse, SimulateSwapResponse, SwapRoutesResponse, TemplateResponse, TemplatesResponse};
 use valkyrie::common::{ContractResult, Denom, OrderBy};
+use valkyrie::errors::ContractError;
 
+use crate::executions::{simulate_best_route, validate_campaigns, MAX_CLAIM_CAMPAIGNS};
 use crate::states::*;
 
-pub fn get_config(deps: Deps, _env: Env) -> ContractResult<ConfigResponse> {
+pub fn get_config(deps: Deps, _env: Env, campaign: Option<String>) -> ContractResult<ConfigResponse> {
+    if let Some(campaign) = campaign {
+        let campaign = Campaign::may_load(deps.storage, &deps.api.addr_validate(&campaign)?)?;
+        if campaign.map_or(false, |c| c.is_delisted()) {
+            return Err(ContractError::Std(StdError::generic_err("Delisted campaign")));
+        }
+    }
+
     let config = Config::load(deps.storage)?;
 
     Ok(ConfigResponse {
@@ -21,6 +32,11 @@ pub fn get_config(deps: Deps, _env: Env) -> ContractResult<ConfigResponse> {
         deactivate_period: config.deactivate_period,
         key_denom: Denom::from_cw20(config.key_denom),
         contract_admin: config.contract_admin.to_string(),
+        creation_fee_denom: Denom::from_cw20(config.creation_fee_denom),
+        creation_fee_amount: config.creation_fee_amount,
+        swap_fee_max_spread: config.swap_fee_max_spread,
+        swap_fee_keepers: config.swap_fee_keepers.iter().map(|k| k.to_string()).collect(),
+        spot_price_fallback: config.spot_price_fallback,
     })
 }
 
@@ -31,9 +47,14 @@ pub fn get_referral_reward_limit_option(
     let option = ReferralRewardLimitOption::load(deps.storage)?;
 
     Ok(ReferralRewardLimitOptionResponse {
+        overflow_mode: option.overflow_mode,
         overflow_amount_recipient: option.overflow_amount_recipient.map(|r| r.to_string()),
         base_count: option.base_count,
         percent_for_governance_staking: option.percent_for_governance_staking,
+        lp_staking: option.lp_staking.map(|s| s.to_string()),
+        percent_for_lp_staking: option.percent_for_lp_staking,
+        staking_window_period: option.staking_window_period,
+        limit_window_period: option.limit_window_period,
     })
 }
 
@@ -43,23 +64,20 @@ pub fn get_campaign(deps: Deps, _env: Env, address: String) -> ContractResult<Ca
         &deps.api.addr_validate(address.as_str())?,
     )?;
 
-    Ok(CampaignResponse {
-        code_id: campaign.code_id,
-        address: campaign.address.to_string(),
-        creator: campaign.creator.to_string(),
-        created_height: campaign.created_height,
-    })
+    Ok(campaign.to_response())
 }
 
 pub fn query_campaign(
     deps: Deps,
     _env: Env,
+    filter: Option<CampaignFilter>,
     start_after: Option<String>,
     limit: Option<u32>,
     order_by: Option<OrderBy>,
 ) -> ContractResult<CampaignsResponse> {
     let campaigns = Campaign::query(
         deps.storage,
+        filter,
         start_after,
         limit,
         order_by,
@@ -67,3 +85,233 @@ pub fn query_campaign(
 
     Ok(campaigns)
 }
+
+pub fn get_template(deps: Deps, _env: Env, template_id: u64) -> ContractResult<TemplateResponse> {
+    let template = CampaignTemplate::load(deps.storage, template_id)?;
+
+    Ok(template.to_response())
+}
+
+pub fn query_templates(
+    deps: Deps,
+    _env: Env,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+    order_by: Option<OrderBy>,
+) -> ContractResult<TemplatesResponse> {
+    let templates = CampaignTemplate::query(
+        deps.storage,
+        start_after,
+        limit,
+        order_by,
+    )?;
+
+    Ok(templates)
+}
+
+pub fn get_swap_routes(
+    deps: Deps,
+    _env: Env,
+    offer: Denom,
+    ask: Denom,
+) -> ContractResult<SwapRoutesResponse> {
+    Ok(SwapRoutesResponse {
+        routes: load_swap_routes(deps.storage, &offer, &ask)?,
+    })
+}
+
+pub fn simulate_swap(
+    deps: Deps,
+    _env: Env,
+    offer: Denom,
+    ask: Denom,
+    amount: Uint128,
+) -> ContractResult<SimulateSwapResponse> {
+    let config = Config::load(deps.storage)?;
+    let (route, amount) = simulate_best_route(deps, &config, &offer, &ask, amount)?;
+
+    Ok(SimulateSwapResponse {
+        route,
+        amount,
+    })
+}
+
+pub fn get_fee_splits(deps: Deps, _env: Env) -> ContractResult<FeeSplitsResponse> {
+    let config = Config::load(deps.storage)?;
+
+    Ok(FeeSplits::load(deps.storage)?.to_response(&config))
+}
+
+pub fn get_price(deps: Deps, env: Env, base: Denom, quote: Denom) -> ContractResult<PriceResponse> {
+    if base == quote {
+        return Ok(PriceResponse {
+            price: Decimal::one(),
+            source: PriceSourceMsg::Fixed {
+                price: Decimal::one(),
+            },
+            updated_height: env.block.height,
+        });
+    }
+
+    Ok(PriceSource::load(deps.storage, &base, &quote)?.to_response(env.block.height)?)
+}
+
+pub fn get_registered_price(deps: Deps, env: Env, base: Denom, quote: Denom) -> ContractResult<Option<PriceResponse>> {
+    if base != quote && PriceSource::may_load(deps.storage, &base, &quote)?.is_none() {
+        return Ok(None);
+    }
+
+    Ok(Some(get_price(deps, env, base, quote)?))
+}
+
+pub fn get_add_pool_fee_tiers(deps: Deps, _env: Env) -> ContractResult<AddPoolFeeTiersResponse> {
+    Ok(AddPoolFeeTiersResponse {
+        tiers: load_add_pool_fee_tiers(deps.storage)?,
+    })
+}
+
+pub fn get_fee_quote(
+    deps: Deps,
+    _env: Env,
+    campaign: Option<String>,
+    addresses: Vec<String>,
+) -> ContractResult<FeeQuoteResponse> {
+    let config = Config::load(deps.storage)?;
+    let tiers = load_add_pool_fee_tiers(deps.storage)?;
+
+    let add_pool_fee_rate = match campaign {
+        Some(campaign) => load_campaign_overrides(deps.storage, &deps.api.addr_validate(&campaign)?)?
+            .add_pool_fee_rate
+            .unwrap_or(config.add_pool_fee_rate),
+        None => config.add_pool_fee_rate,
+    };
+
+    let mut staker: Option<(Addr, Uint128)> = None;
+    for address in addresses.iter() {
+        let address = deps.api.addr_validate(address)?;
+        let staking_amount = load_gov_staking_amount(&deps.querier, &config.governance, &address)?;
+
+        if staker.as_ref().map_or(true, |(_, max_amount)| staking_amount > *max_amount) {
+            staker = Some((address, staking_amount));
+        }
+    }
+
+    let staking_amount = staker.as_ref().map(|(_, amount)| *amount).unwrap_or_default();
+    let tier = find_fee_tier(&tiers, staking_amount);
+
+    Ok(FeeQuoteResponse {
+        address: staker.map(|(address, _)| address.to_string()),
+        staking_amount,
+        tier: tier.map(|tier| tier as u32),
+        // tiers only discount the base fee rate
+        fee_rate: tier.map(|tier| tiers[tier].fee_rate.min(add_pool_fee_rate))
+            .unwrap_or(add_pool_fee_rate),
+    })
+}
+
+pub fn get_campaign_overrides(deps: Deps, _env: Env, campaign: String) -> ContractResult<CampaignOverridesResponse> {
+    let campaign = deps.api.addr_validate(&campaign)?;
+
+    Ok(CampaignOverridesResponse {
+        overrides: load_campaign_overrides(deps.storage, &campaign)?,
+        campaign: campaign.to_string(),
+    })
+}
+
+pub fn get_effective_config(deps: Deps, env: Env, campaign: String) -> ContractResult<EffectiveConfigResponse> {
+    let campaign = deps.api.addr_validate(&campaign)?;
+    let delisted = Campaign::may_load(deps.storage, &campaign)?
+        .map_or(false, |c| c.is_delisted());
+    let overrides = load_campaign_overrides(deps.storage, &campaign)?;
+
+    let mut config = get_config(deps, env.clone(), None)?;
+    if let Some(add_pool_fee_rate) = overrides.add_pool_fee_rate {
+        config.add_pool_fee_rate = add_pool_fee_rate;
+    }
+    if let Some(add_pool_min_referral_reward_rate) = overrides.add_pool_min_referral_reward_rate {
+        config.add_pool_min_referral_reward_rate = add_pool_min_referral_reward_rate;
+    }
+    if let Some(remove_pool_fee_rate) = overrides.remove_pool_fee_rate {
+        config.remove_pool_fee_rate = remove_pool_fee_rate;
+    }
+    if let Some(deactivate_period) = overrides.deactivate_period {
+        config.deactivate_period = deactivate_period;
+    }
+
+    let mut referral_reward_limit_option = get_referral_reward_limit_option(deps, env)?;
+    if let Some(overflow_amount_recipient) = overrides.overflow_amount_recipient {
+        referral_reward_limit_option.overflow_amount_recipient = Some(overflow_amount_recipient);
+        // same as the global option, a recipient sends overflow to it unless another mode is given
+        referral_reward_limit_option.overflow_mode = OverflowMode::Recipient;
+    }
+    if let Some(overflow_mode) = overrides.overflow_mode {
+        referral_reward_limit_option.overflow_mode = overflow_mode;
+    }
+    if let Some(base_count) = overrides.base_count {
+        referral_reward_limit_option.base_count = base_count;
+    }
+    if let Some(percent_for_governance_staking) = overrides.percent_for_governance_staking {
+        referral_reward_limit_option.percent_for_governance_staking = percent_for_governance_staking;
+    }
+    if let Some(lp_staking) = overrides.lp_staking {
+        referral_reward_limit_option.lp_staking = Some(lp_staking);
+    }
+    if let Some(percent_for_lp_staking) = overrides.percent_for_lp_staking {
+        referral_reward_limit_option.percent_for_lp_staking = percent_for_lp_staking;
+    }
+    if let Some(staking_window_period) = overrides.staking_window_period {
+        referral_reward_limit_option.staking_window_period = staking_window_period;
+    }
+    if let Some(limit_window_period) = overrides.limit_window_period {
+        referral_reward_limit_option.limit_window_period = limit_window_period;
+    }
+
+    Ok(EffectiveConfigResponse {
+        config,
+        referral_reward_limit_option,
+        delisted,
+    })
+}
+
+pub fn get_pending_rewards(
+    deps: Deps,
+    env: Env,
+    address: String,
+    campaigns: Vec<String>,
+) -> ContractResult<PendingRewardsResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let campaigns = validate_campaigns(deps, campaigns, MAX_CLAIM_CAMPAIGNS)?;
+
+    let mut rewards: Vec<(Denom, Uint128)> = vec![];
+    for campaign in campaigns.iter() {
+        let actor = load_campaign_actor(&deps.querier, &campaign.address, &address)?;
+        let reward_config = load_campaign_reward_config(&deps.querier, &campaign.address)?;
+
+        add_reward(
+            &mut rewards,
+            reward_config.participation_reward_denom,
+            calc_unlocked_reward_amount(&actor.participation_reward_amounts, env.block.height),
+        );
+        add_reward(
+            &mut rewards,
+            Denom::Token(reward_config.referral_reward_token),
+            calc_unlocked_reward_amount(&actor.referral_reward_amounts, env.block.height),
+        );
+    }
+
+    Ok(PendingRewardsResponse {
+        address: address.to_string(),
+        rewards,
+    })
+}
+
+fn add_reward(rewards: &mut Vec<(Denom, Uint128)>, denom: Denom, amount: Uint128) {
+    if amount.is_zero() {
+        return;
+    }
+
+    match rewards.iter_mut().find(|(d, _)| *d == denom) {
+        Some((_, total)) => *total += amount,
+        None => rewards.push((denom, amount)),
+    }
+}
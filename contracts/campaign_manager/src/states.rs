@@ -1,12 +1,16 @@
-use cosmwasm_std::{Addr, Decimal, StdResult, Storage};
+use cosmwasm_std::{Addr, Binary, Decimal, QuerierWrapper, StdError, StdResult, Storage, Uint128};
 use cw20::Denom;
-use cw_storage_plus::{Item, Map};
+use cw_storage_plus::{Bound, Item, Map, PrimaryKey};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use valkyrie::campaign_manager::query_msgs::{CampaignResponse, CampaignsResponse};
+use valkyrie::campaign::query_msgs::{ActorResponse, QueryMsg as CampaignQueryMsg, RewardConfigResponse};
+use valkyrie::campaign_manager::enumerations::{CampaignFilter, CampaignStatus, FeeSplitRecipient as FeeSplitMsgRecipient, OverflowMode};
+use valkyrie::campaign_manager::execute_msgs::{CampaignOverridesMsg, CampaignTemplateMsg, FeeSplitMsg, FeeTierMsg, PriceSourceMsg};
+use valkyrie::campaign_manager::query_msgs::{CampaignResponse, CampaignsResponse, FeeSplitsResponse, PriceResponse, TemplateResponse, TemplatesResponse};
 use valkyrie::common::OrderBy;
-use valkyrie::pagination::addr_range_option;
+use valkyrie::governance::query_msgs::{QueryMsg as GovQueryMsg, StakerStateResponse};
+use valkyrie::pagination::{addr_range_option, DEFAULT_LIMIT, MAX_LIMIT};
 
 const CONFIG: Item<Config> = Item::new("config");
 
@@ -24,6 +28,11 @@ pub struct Config {
     pub deactivate_period: u64,
     pub key_denom: Denom,
     pub contract_admin: Addr,
+    pub creation_fee_denom: Denom,
+    pub creation_fee_amount: Uint128,
+    pub swap_fee_max_spread: Decimal,
+    pub swap_fee_keepers: Vec<Addr>,
+    pub spot_price_fallback: bool,
 }
 
 impl Config {
@@ -42,6 +51,10 @@ impl Config {
     pub fn is_contract_admin(&self, address: &Addr) -> bool {
         self.contract_admin == *address
     }
+
+    pub fn is_swap_fee_keeper(&self, address: &Addr) -> bool {
+        self.swap_fee_keepers.contains(address)
+    }
 }
 
 
@@ -49,9 +62,14 @@ const REFERRAL_REWARD_LIMIT_OPTION: Item<ReferralRewardLimitOption> = Item::new(
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct ReferralRewardLimitOption {
+    pub overflow_mode: OverflowMode,
     pub overflow_amount_recipient: Option<Addr>,
     pub base_count: u8,
     pub percent_for_governance_staking: u16,
+    pub lp_staking: Option<Addr>,
+    pub percent_for_lp_staking: u16,
+    pub staking_window_period: u64,
+    pub limit_window_period: u64,
 }
 
 impl ReferralRewardLimitOption {
@@ -71,6 +89,8 @@ const CREATE_CAMPAIGN_CONTEXT: Item<CreateCampaignContext> = Item::new("create_c
 pub struct CreateCampaignContext {
     pub code_id: u64,
     pub creator: Addr,
+    pub tags: Vec<String>,
+    pub logo_url: Option<String>,
 }
 
 impl CreateCampaignContext {
@@ -92,8 +112,39 @@ impl CreateCampaignContext {
     }
 }
 
+const MIGRATE_CAMPAIGNS_CONTEXT: Item<MigrateCampaignsContext> = Item::new("migrate_campaigns_context");
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MigrateCampaignsContext {
+    pub code_id: u64,
+    pub campaigns: Vec<Addr>,
+}
+
+impl MigrateCampaignsContext {
+    pub fn save(&self, storage: &mut dyn Storage) -> StdResult<()> {
+        MIGRATE_CAMPAIGNS_CONTEXT.save(storage, self)
+    }
+
+    pub fn load(storage: &dyn Storage) -> StdResult<MigrateCampaignsContext> {
+        MIGRATE_CAMPAIGNS_CONTEXT.load(storage)
+    }
+
+    #[cfg(test)]
+    pub fn may_load(storage: &dyn Storage) -> StdResult<Option<MigrateCampaignsContext>> {
+        MIGRATE_CAMPAIGNS_CONTEXT.may_load(storage)
+    }
+
+    pub fn clear(storage: &mut dyn Storage) {
+        MIGRATE_CAMPAIGNS_CONTEXT.remove(storage)
+    }
+}
 
 const CAMPAIGN: Map<&Addr, Campaign> = Map::new("campaign");
+const CAMPAIGN_CREATOR_INDEX: Map<(&[u8], &[u8]), bool> = Map::new("campaign_creator_index");
+const CAMPAIGN_TAG_INDEX: Map<(&[u8], &[u8]), bool> = Map::new("campaign_tag_index");
+const CAMPAIGN_CODE_ID_INDEX: Map<(&[u8], &[u8]), bool> = Map::new("campaign_code_id_index");
+const CAMPAIGN_HEIGHT_INDEX: Map<(&[u8], &[u8]), bool> = Map::new("campaign_height_index");
+const CAMPAIGN_STATUS_INDEX: Map<(&[u8], &[u8]), bool> = Map::new("campaign_status_index");
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct Campaign {
@@ -101,42 +152,569 @@ pub struct Campaign {
     pub address: Addr,
     pub creator: Addr,
     pub created_height: u64,
+    // default for campaigns saved before v1.0.7
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub logo_url: Option<String>,
+    #[serde(default = "default_campaign_status")]
+    pub status: CampaignStatus,
+}
+
+fn default_campaign_status() -> CampaignStatus {
+    CampaignStatus::Unverified
 }
 
 impl Campaign {
     pub fn save(&self, storage: &mut dyn Storage) -> StdResult<()> {
+        if let Some(prev) = CAMPAIGN.may_load(storage, &self.address)? {
+            prev.remove_index(storage);
+        }
+
+        self.save_index(storage)?;
         CAMPAIGN.save(storage, &self.address, self)
     }
 
+    fn save_index(&self, storage: &mut dyn Storage) -> StdResult<()> {
+        let address = self.address.as_bytes();
+
+        CAMPAIGN_CREATOR_INDEX.save(storage, (self.creator.as_bytes(), address), &true)?;
+        CAMPAIGN_CODE_ID_INDEX.save(storage, (&self.code_id.to_be_bytes(), address), &true)?;
+        CAMPAIGN_HEIGHT_INDEX.save(storage, (&self.created_height.to_be_bytes(), address), &true)?;
+        CAMPAIGN_STATUS_INDEX.save(storage, (self.status.to_string().as_bytes(), address), &true)?;
+        for tag in self.tags.iter() {
+            CAMPAIGN_TAG_INDEX.save(storage, (tag.as_bytes(), address), &true)?;
+        }
+
+        Ok(())
+    }
+
+    fn remove_index(&self, storage: &mut dyn Storage) {
+        let address = self.address.as_bytes();
+
+        CAMPAIGN_CREATOR_INDEX.remove(storage, (self.creator.as_bytes(), address));
+        CAMPAIGN_CODE_ID_INDEX.remove(storage, (&self.code_id.to_be_bytes(), address));
+        CAMPAIGN_HEIGHT_INDEX.remove(storage, (&self.created_height.to_be_bytes(), address));
+        CAMPAIGN_STATUS_INDEX.remove(storage, (self.status.to_string().as_bytes(), address));
+        for tag in self.tags.iter() {
+            CAMPAIGN_TAG_INDEX.remove(storage, (tag.as_bytes(), address));
+        }
+    }
+
     pub fn load(storage: &dyn Storage, address: &Addr) -> StdResult<Campaign> {
         CAMPAIGN.load(storage, address)
     }
 
+    pub fn may_load(storage: &dyn Storage, address: &Addr) -> StdResult<Option<Campaign>> {
+        CAMPAIGN.may_load(storage, address)
+    }
+
     pub fn query(
         storage: &dyn Storage,
+        filter: Option<CampaignFilter>,
         start_after: Option<String>,
         limit: Option<u32>,
         order_by: Option<OrderBy>,
     ) -> StdResult<CampaignsResponse> {
+        let campaigns = match filter {
+            None => {
+                let range_option = addr_range_option(start_after, limit, order_by);
+
+                CAMPAIGN
+                    .range(storage, range_option.min, range_option.max, range_option.order_by)
+                    .take(range_option.limit)
+                    .map(|item| {
+                        let (_, campaign) = item?;
+                        Ok(campaign.to_response())
+                    })
+                    .collect::<StdResult<Vec<CampaignResponse>>>()?
+            }
+            Some(CampaignFilter::Creator(creator)) => Campaign::query_by_index(
+                storage, CAMPAIGN_CREATOR_INDEX, creator.as_bytes(), start_after, limit, order_by,
+            )?,
+            Some(CampaignFilter::Tag(tag)) => Campaign::query_by_index(
+                storage, CAMPAIGN_TAG_INDEX, tag.as_bytes(), start_after, limit, order_by,
+            )?,
+            Some(CampaignFilter::CodeId(code_id)) => Campaign::query_by_index(
+                storage, CAMPAIGN_CODE_ID_INDEX, &code_id.to_be_bytes(), start_after, limit, order_by,
+            )?,
+            Some(CampaignFilter::CreatedHeight { min, max }) => Campaign::query_by_height(
+                storage, min, max, start_after, limit, order_by,
+            )?,
+            Some(CampaignFilter::Status(status)) => Campaign::query_by_index(
+                storage, CAMPAIGN_STATUS_INDEX, status.to_string().as_bytes(), start_after, limit, order_by,
+            )?,
+        };
+
+        Ok(CampaignsResponse {
+            campaigns,
+        })
+    }
+
+    fn query_by_index(
+        storage: &dyn Storage,
+        index: Map<(&[u8], &[u8]), bool>,
+        prefix: &[u8],
+        start_after: Option<String>,
+        limit: Option<u32>,
+        order_by: Option<OrderBy>,
+    ) -> StdResult<Vec<CampaignResponse>> {
         let range_option = addr_range_option(start_after, limit, order_by);
 
-        let campaigns = CAMPAIGN
+        index.prefix(prefix)
             .range(storage, range_option.min, range_option.max, range_option.order_by)
             .take(range_option.limit)
             .map(|item| {
-                let (_, campaign) = item?;
-
-                Ok(CampaignResponse {
-                    code_id: campaign.code_id,
-                    address: campaign.address.to_string(),
-                    creator: campaign.creator.to_string(),
-                    created_height: campaign.created_height,
-                })
+                let (k, _) = item?;
+                let address = String::from_utf8(k)?;
+                Ok(CAMPAIGN.load(storage, &Addr::unchecked(address))?.to_response())
             })
-            .collect::<StdResult<Vec<CampaignResponse>>>()?;
+            .collect()
+    }
 
-        Ok(CampaignsResponse {
-            campaigns,
+    fn query_by_height(
+        storage: &dyn Storage,
+        min: Option<u64>,
+        max: Option<u64>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        order_by: Option<OrderBy>,
+    ) -> StdResult<Vec<CampaignResponse>> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let mut min = min.map(|v| Bound::inclusive(height_index_key(v, b"")));
+        let mut max = max.and_then(|v| v.checked_add(1))
+            .map(|v| Bound::exclusive(height_index_key(v, b"")));
+
+        let start_after = start_after
+            .map(|v| CAMPAIGN.load(storage, &Addr::unchecked(v)))
+            .transpose()?
+            .map(|v| Bound::exclusive(height_index_key(v.created_height, v.address.as_bytes())));
+
+        let order_by = match order_by {
+            Some(OrderBy::Asc) => {
+                min = start_after.or(min);
+                OrderBy::Asc
+            }
+            _ => {
+                max = start_after.or(max);
+                OrderBy::Desc
+            }
+        };
+
+        CAMPAIGN_HEIGHT_INDEX
+            .range(storage, min, max, order_by.into())
+            .take(limit)
+            .map(|item| {
+                let (k, _) = item?;
+                // skip length prefix (2 bytes) and height (8 bytes)
+                let address = String::from_utf8(k[10..].to_vec())?;
+                Ok(CAMPAIGN.load(storage, &Addr::unchecked(address))?.to_response())
+            })
+            .collect()
+    }
+
+    pub fn is_delisted(&self) -> bool {
+        self.status == CampaignStatus::Delisted
+    }
+
+    pub fn to_response(&self) -> CampaignResponse {
+        CampaignResponse {
+            code_id: self.code_id,
+            address: self.address.to_string(),
+            creator: self.creator.to_string(),
+            created_height: self.created_height,
+            tags: self.tags.clone(),
+            logo_url: self.logo_url.clone(),
+            status: self.status.clone(),
+        }
+    }
+}
+
+fn height_index_key(height: u64, address: &[u8]) -> Vec<u8> {
+    (height.to_be_bytes().as_ref(), address).joined_key()
+}
+
+
+const TEMPLATE_COUNT: Item<u64> = Item::new("template_count");
+const TEMPLATES: Map<&[u8], CampaignTemplate> = Map::new("template");
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CampaignTemplate {
+    pub id: u64,
+    pub name: String,
+    pub owner: Addr,
+    pub template: CampaignTemplateMsg,
+}
+
+impl CampaignTemplate {
+    pub fn save(&self, storage: &mut dyn Storage) -> StdResult<()> {
+        TEMPLATES.save(storage, &self.id.to_be_bytes(), self)
+    }
+
+    pub fn load(storage: &dyn Storage, template_id: u64) -> StdResult<CampaignTemplate> {
+        TEMPLATES.load(storage, &template_id.to_be_bytes())
+    }
+
+    pub fn may_load(storage: &dyn Storage, template_id: u64) -> StdResult<Option<CampaignTemplate>> {
+        TEMPLATES.may_load(storage, &template_id.to_be_bytes())
+    }
+
+    pub fn remove(&self, storage: &mut dyn Storage) {
+        TEMPLATES.remove(storage, &self.id.to_be_bytes())
+    }
+
+    pub fn query(
+        storage: &dyn Storage,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+        order_by: Option<OrderBy>,
+    ) -> StdResult<TemplatesResponse> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start_after = start_after.map(|v| Bound::exclusive(v.to_be_bytes()));
+        let (min, max, order_by) = match order_by {
+            Some(OrderBy::Asc) => (start_after, None, OrderBy::Asc),
+            _ => (None, start_after, OrderBy::Desc),
+        };
+
+        let templates = TEMPLATES
+            .range(storage, min, max, order_by.into())
+            .take(limit)
+            .map(|item| {
+                let (_, template) = item?;
+                Ok(template.to_response())
+            })
+            .collect::<StdResult<Vec<TemplateResponse>>>()?;
+
+        Ok(TemplatesResponse {
+            templates,
         })
     }
-}
\ No newline at end of file
+
+    pub fn is_owner(&self, address: &Addr) -> bool {
+        self.owner == *address
+    }
+
+    pub fn to_response(&self) -> TemplateResponse {
+        TemplateResponse {
+            id: self.id,
+            name: self.name.clone(),
+            owner: self.owner.to_string(),
+            template: self.template.clone(),
+        }
+    }
+}
+
+pub fn get_template_id(storage: &mut dyn Storage) -> StdResult<u64> {
+    let template_id = TEMPLATE_COUNT.may_load(storage)?.unwrap_or_default() + 1;
+    TEMPLATE_COUNT.save(storage, &template_id)?;
+
+    Ok(template_id)
+}
+
+pub type SwapRoute = Vec<valkyrie::common::Denom>;
+
+const SWAP_ROUTES: Map<(&[u8], &[u8]), Vec<SwapRoute>> = Map::new("swap_routes");
+
+pub fn load_swap_routes(
+    storage: &dyn Storage,
+    offer: &valkyrie::common::Denom,
+    ask: &valkyrie::common::Denom,
+) -> StdResult<Vec<SwapRoute>> {
+    Ok(SWAP_ROUTES
+        .may_load(storage, (offer.to_string().as_bytes(), ask.to_string().as_bytes()))?
+        .unwrap_or_default())
+}
+
+pub fn save_swap_routes(
+    storage: &mut dyn Storage,
+    offer: &valkyrie::common::Denom,
+    ask: &valkyrie::common::Denom,
+    routes: &[SwapRoute],
+) -> StdResult<()> {
+    let key = (offer.to_string(), ask.to_string());
+
+    if routes.is_empty() {
+        SWAP_ROUTES.remove(storage, (key.0.as_bytes(), key.1.as_bytes()));
+        Ok(())
+    } else {
+        SWAP_ROUTES.save(storage, (key.0.as_bytes(), key.1.as_bytes()), &routes.to_vec())
+    }
+}
+
+const FEE_SPLITS: Item<FeeSplits> = Item::new("fee_splits");
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FeeSplit {
+    pub recipient: FeeSplitRecipient,
+    pub weight: Decimal,
+    pub msg: Option<Binary>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Default)]
+pub struct FeeSplits {
+    pub splits: Vec<FeeSplit>,
+    pub dust_recipient: Option<Addr>,
+}
+
+impl FeeSplits {
+    pub fn save(&self, storage: &mut dyn Storage) -> StdResult<()> {
+        FEE_SPLITS.save(storage, self)
+    }
+
+    pub fn load(storage: &dyn Storage) -> StdResult<FeeSplits> {
+        Ok(FEE_SPLITS.may_load(storage)?.unwrap_or_default())
+    }
+
+    pub fn dust_recipient(&self, config: &Config) -> Addr {
+        self.dust_recipient.clone().unwrap_or_else(|| config.fee_recipient.clone())
+    }
+
+    pub fn to_response(&self, config: &Config) -> FeeSplitsResponse {
+        FeeSplitsResponse {
+            splits: self.splits.iter().map(|split| FeeSplitMsg {
+                recipient: match &split.recipient {
+                    FeeSplitRecipient::Burn => FeeSplitMsgRecipient::Burn,
+                    FeeSplitRecipient::Address(address) => FeeSplitMsgRecipient::Address(address.to_string()),
+                },
+                weight: split.weight,
+                msg: split.msg.clone(),
+            }).collect(),
+            dust_recipient: self.dust_recipient(config).to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FeeSplitRecipient {
+    Burn,
+    Address(Addr),
+}
+
+const PRICE_SOURCES: Map<(&[u8], &[u8]), PriceSource> = Map::new("price_sources");
+
+pub const PRICE_PRECISION: u128 = 1_000_000_000_000;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PriceObservation {
+    pub height: u64,
+    pub cumulative_price: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PriceSource {
+    pub source: PriceSourceMsg,
+    pub max_staleness: u64,
+    pub last_price: Decimal,
+    pub updated_height: u64,
+    pub cumulative_price: Uint128,
+    pub observations: Vec<PriceObservation>,
+}
+
+impl PriceSource {
+    pub fn new(source: PriceSourceMsg, max_staleness: u64, height: u64) -> PriceSource {
+        match source {
+            PriceSourceMsg::Fixed { price } => PriceSource {
+                source,
+                max_staleness,
+                last_price: price,
+                updated_height: height,
+                cumulative_price: Uint128::zero(),
+                observations: vec![],
+            },
+            PriceSourceMsg::Twap { .. } => PriceSource {
+                source,
+                max_staleness,
+                last_price: Decimal::zero(),
+                updated_height: 0,
+                cumulative_price: Uint128::zero(),
+                observations: vec![],
+            },
+        }
+    }
+
+    pub fn save(
+        &self,
+        storage: &mut dyn Storage,
+        base: &valkyrie::common::Denom,
+        quote: &valkyrie::common::Denom,
+    ) -> StdResult<()> {
+        PRICE_SOURCES.save(storage, (base.to_string().as_bytes(), quote.to_string().as_bytes()), self)
+    }
+
+    pub fn may_load(
+        storage: &dyn Storage,
+        base: &valkyrie::common::Denom,
+        quote: &valkyrie::common::Denom,
+    ) -> StdResult<Option<PriceSource>> {
+        PRICE_SOURCES.may_load(storage, (base.to_string().as_bytes(), quote.to_string().as_bytes()))
+    }
+
+    pub fn load(
+        storage: &dyn Storage,
+        base: &valkyrie::common::Denom,
+        quote: &valkyrie::common::Denom,
+    ) -> StdResult<PriceSource> {
+        PriceSource::may_load(storage, base, quote)?
+            .ok_or_else(|| StdError::generic_err(format!("Price source not registered ({} > {})", base, quote)))
+    }
+
+    pub fn remove(
+        storage: &mut dyn Storage,
+        base: &valkyrie::common::Denom,
+        quote: &valkyrie::common::Denom,
+    ) {
+        PRICE_SOURCES.remove(storage, (base.to_string().as_bytes(), quote.to_string().as_bytes()))
+    }
+
+    pub fn record(&mut self, height: u64, price: Decimal) -> StdResult<()> {
+        let window = match self.source {
+            PriceSourceMsg::Twap { window } => window,
+            PriceSourceMsg::Fixed { .. } => return Err(StdError::generic_err("Fixed price can not be recorded")),
+        };
+
+        if !self.observations.is_empty() {
+            if height <= self.updated_height {
+                return Err(StdError::generic_err("Price already recorded at this height"));
+            }
+            self.cumulative_price = self.cumulative_at(height)?;
+        }
+
+        self.last_price = price;
+        self.updated_height = height;
+        self.observations.push(PriceObservation {
+            height,
+            cumulative_price: self.cumulative_price,
+        });
+
+        // keep the latest observation older than the window as the anchor of the average
+        let anchor = self.observations.iter()
+            .rposition(|o| o.height + window <= height)
+            .unwrap_or(0);
+        self.observations.drain(..anchor);
+
+        Ok(())
+    }
+
+    pub fn price(&self, height: u64) -> StdResult<Decimal> {
+        if let PriceSourceMsg::Twap { .. } = self.source {
+            if self.observations.is_empty() {
+                return Err(StdError::generic_err("Price not recorded"));
+            }
+        }
+
+        if height > self.updated_height + self.max_staleness {
+            return Err(StdError::generic_err("Stale price"));
+        }
+
+        match self.source {
+            PriceSourceMsg::Fixed { .. } => Ok(self.last_price),
+            PriceSourceMsg::Twap { window } => {
+                let anchor = self.observations.iter().rev()
+                    .find(|o| o.height + window <= height)
+                    .ok_or_else(|| StdError::generic_err("Not enough price history"))?;
+
+                let blocks = height - anchor.height;
+                if blocks == 0 {
+                    return Ok(self.last_price);
+                }
+
+                let average = self.cumulative_at(height)?
+                    .checked_sub(anchor.cumulative_price)?
+                    .checked_div(Uint128::from(blocks))?;
+
+                Ok(Decimal::from_ratio(average, PRICE_PRECISION))
+            }
+        }
+    }
+
+    fn cumulative_at(&self, height: u64) -> StdResult<Uint128> {
+        let elapsed = Uint128::from(height.saturating_sub(self.updated_height));
+        let scaled_price = self.last_price * Uint128::from(PRICE_PRECISION);
+
+        Ok(self.cumulative_price.checked_add(scaled_price.checked_mul(elapsed)?)?)
+    }
+
+    pub fn to_response(&self, height: u64) -> StdResult<PriceResponse> {
+        Ok(PriceResponse {
+            price: self.price(height)?,
+            source: self.source.clone(),
+            updated_height: self.updated_height,
+        })
+    }
+}
+
+const ADD_POOL_FEE_TIERS: Item<Vec<FeeTierMsg>> = Item::new("add_pool_fee_tiers");
+
+pub fn load_add_pool_fee_tiers(storage: &dyn Storage) -> StdResult<Vec<FeeTierMsg>> {
+    Ok(ADD_POOL_FEE_TIERS.may_load(storage)?.unwrap_or_default())
+}
+
+pub fn save_add_pool_fee_tiers(storage: &mut dyn Storage, tiers: &[FeeTierMsg]) -> StdResult<()> {
+    ADD_POOL_FEE_TIERS.save(storage, &tiers.to_vec())
+}
+
+/// Returns the index of the highest tier reached by the staking amount.
+pub fn find_fee_tier(tiers: &[FeeTierMsg], staking_amount: Uint128) -> Option<usize> {
+    tiers.iter().rposition(|tier| tier.min_staking_amount <= staking_amount)
+}
+
+pub fn load_gov_staking_amount(
+    querier: &QuerierWrapper,
+    governance: &Addr,
+    address: &Addr,
+) -> StdResult<Uint128> {
+    let staker_state: StakerStateResponse = querier.query_wasm_smart(
+        governance,
+        &GovQueryMsg::StakerState {
+            address: address.to_string(),
+        },
+    )?;
+
+    Ok(staker_state.balance)
+}
+
+const CAMPAIGN_OVERRIDES: Map<&Addr, CampaignOverridesMsg> = Map::new("campaign_overrides");
+
+pub fn load_campaign_overrides(storage: &dyn Storage, campaign: &Addr) -> StdResult<CampaignOverridesMsg> {
+    Ok(CAMPAIGN_OVERRIDES.may_load(storage, campaign)?.unwrap_or_default())
+}
+
+pub fn save_campaign_overrides(
+    storage: &mut dyn Storage,
+    campaign: &Addr,
+    overrides: &CampaignOverridesMsg,
+) -> StdResult<()> {
+    if *overrides == CampaignOverridesMsg::default() {
+        CAMPAIGN_OVERRIDES.remove(storage, campaign);
+        Ok(())
+    } else {
+        CAMPAIGN_OVERRIDES.save(storage, campaign, overrides)
+    }
+}
+
+pub fn load_campaign_actor(
+    querier: &QuerierWrapper,
+    campaign: &Addr,
+    actor: &Addr,
+) -> StdResult<ActorResponse> {
+    querier.query_wasm_smart(
+        campaign,
+        &CampaignQueryMsg::Actor {
+            address: actor.to_string(),
+        },
+    )
+}
+
+pub fn load_campaign_reward_config(
+    querier: &QuerierWrapper,
+    campaign: &Addr,
+) -> StdResult<RewardConfigResponse> {
+    querier.query_wasm_smart(campaign, &CampaignQueryMsg::RewardConfig {})
+}
+
+pub fn calc_unlocked_reward_amount(amounts: &[(Uint128, u64)], height: u64) -> Uint128 {
+    amounts.iter()
+        .filter(|(_, unlock_height)| *unlock_height <= height)
+        .fold(Uint128::zero(), |total, (amount, _)| total + *amount)
+}
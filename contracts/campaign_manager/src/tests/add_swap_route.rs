@@ -0,0 +1,104 @@
+use cosmwasm_std::{Env, MessageInfo, Response, Uint128};
+
+use valkyrie::common::{ContractResult, Denom};
+use valkyrie::mock_querier::{custom_deps, CustomDeps};
+use valkyrie::test_constants::{default_sender, VALKYRIE_TOKEN};
+use valkyrie::test_constants::campaign_manager::campaign_manager_env;
+use valkyrie::test_constants::governance::governance_sender;
+use valkyrie::test_utils::{expect_generic_err, expect_unauthorized_err};
+
+use crate::executions::add_swap_route;
+use crate::queries::{get_swap_routes, simulate_swap};
+
+pub fn exec(
+    deps: &mut CustomDeps,
+    env: Env,
+    info: MessageInfo,
+    route: Vec<Denom>,
+) -> ContractResult<Response> {
+    add_swap_route(deps.as_mut(), env, info, route)
+}
+
+pub fn will_success(deps: &mut CustomDeps, route: Vec<Denom>) -> (Env, MessageInfo, Response) {
+    let env = campaign_manager_env();
+    let info = governance_sender();
+
+    let response = exec(deps, env.clone(), info.clone(), route).unwrap();
+
+    (env, info, response)
+}
+
+fn default_route() -> Vec<Denom> {
+    vec![
+        Denom::Native("ukrw".to_string()),
+        Denom::Native("uusd".to_string()),
+        Denom::Token(VALKYRIE_TOKEN.to_string()),
+    ]
+}
+
+#[test]
+fn succeed() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    will_success(&mut deps, default_route());
+
+    let routes = get_swap_routes(
+        deps.as_ref(),
+        campaign_manager_env(),
+        Denom::Native("ukrw".to_string()),
+        Denom::Token(VALKYRIE_TOKEN.to_string()),
+    ).unwrap();
+    assert_eq!(routes.routes, vec![default_route()]);
+
+    deps.querier.with_terraswap_price("ukrw".to_string(), "uusd".to_string(), 0.5f64);
+    deps.querier.with_terraswap_price("uusd".to_string(), VALKYRIE_TOKEN.to_string(), 2f64);
+
+    let simulated = simulate_swap(
+        deps.as_ref(),
+        campaign_manager_env(),
+        Denom::Native("ukrw".to_string()),
+        Denom::Token(VALKYRIE_TOKEN.to_string()),
+        Uint128::new(1000),
+    ).unwrap();
+    assert_eq!(simulated.route, default_route());
+    assert_eq!(simulated.amount, Uint128::new(1000));
+}
+
+#[test]
+fn failed_invalid_permission() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    let result = exec(&mut deps, campaign_manager_env(), default_sender(), default_route());
+    expect_unauthorized_err(&result);
+}
+
+#[test]
+fn failed_invalid_route() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        governance_sender(),
+        vec![Denom::Native("ukrw".to_string())],
+    );
+    expect_generic_err(&result, "route must have at least 2 denoms");
+
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        governance_sender(),
+        vec![Denom::Native("ukrw".to_string()), Denom::Native("ukrw".to_string())],
+    );
+    expect_generic_err(&result, "route must not swap to the same denom");
+
+    will_success(&mut deps, default_route());
+    let result = exec(&mut deps, campaign_manager_env(), governance_sender(), default_route());
+    expect_generic_err(&result, "Already registered route");
+}
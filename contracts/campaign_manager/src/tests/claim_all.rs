@@ -0,0 +1,188 @@
+use cosmwasm_std::{Addr, Env, MessageInfo, Response, Uint128, SubMsg, CosmosMsg, WasmMsg, to_binary};
+use cosmwasm_std::testing::mock_info;
+
+use valkyrie::campaign::execute_msgs::ExecuteMsg as CampaignExecuteMsg;
+use valkyrie::campaign::query_msgs::{ActorResponse, RewardConfigResponse};
+use valkyrie::campaign_manager::query_msgs::PendingRewardsResponse;
+use valkyrie::campaign_manager::enumerations::CampaignStatus;
+use valkyrie::common::{ContractResult, Denom};
+use valkyrie::mock_querier::{custom_deps, CustomDeps};
+use valkyrie::test_constants::campaign_manager::campaign_manager_env;
+use valkyrie::test_constants::VALKYRIE_TOKEN;
+use valkyrie::test_utils::expect_generic_err;
+
+use crate::executions::claim_all;
+use crate::queries::get_pending_rewards;
+use crate::states::Campaign;
+
+const ACTOR: &str = "Actor";
+
+pub fn exec(
+    deps: &mut CustomDeps,
+    env: Env,
+    info: MessageInfo,
+    campaigns: Vec<String>,
+) -> ContractResult<Response> {
+    claim_all(deps.as_mut(), env, info, campaigns)
+}
+
+pub fn will_success(deps: &mut CustomDeps, campaigns: Vec<String>) -> (Env, MessageInfo, Response) {
+    let env = campaign_manager_env();
+    let info = mock_info(ACTOR, &[]);
+
+    let response = exec(deps, env.clone(), info.clone(), campaigns).unwrap();
+
+    (env, info, response)
+}
+
+fn register_campaign(
+    deps: &mut CustomDeps,
+    address: &str,
+    participation_reward_amounts: Vec<(Uint128, u64)>,
+    referral_reward_amounts: Vec<(Uint128, u64)>,
+) {
+    Campaign {
+        code_id: 1,
+        address: Addr::unchecked(address),
+        creator: Addr::unchecked("Creator"),
+        created_height: 0,
+        tags: vec![],
+        logo_url: None,
+        status: CampaignStatus::Unverified,
+    }.save(deps.as_mut().storage).unwrap();
+
+    deps.querier.with_campaign_reward_config(address.to_string(), RewardConfigResponse {
+        participation_reward_denom: Denom::Native("uusd".to_string()),
+        participation_reward_amount: Uint128::new(5),
+        participation_reward_lock_period: 0,
+        referral_reward_token: VALKYRIE_TOKEN.to_string(),
+        referral_reward_amounts: vec![Uint128::new(5)],
+        referral_reward_lock_period: 0,
+    });
+
+    let mut actor = ActorResponse::new(ACTOR.to_string(), None);
+    actor.participation_reward_amounts = participation_reward_amounts;
+    actor.referral_reward_amounts = referral_reward_amounts;
+    deps.querier.with_campaign_actor(address.to_string(), actor);
+}
+
+fn default_campaigns(deps: &mut CustomDeps) {
+    let height = campaign_manager_env().block.height;
+
+    register_campaign(deps, "Campaign1", vec![(Uint128::new(5), height)], vec![]);
+    register_campaign(deps, "Campaign2", vec![(Uint128::new(5), height + 1)], vec![]);
+    register_campaign(
+        deps,
+        "Campaign3",
+        vec![(Uint128::new(3), height)],
+        vec![(Uint128::new(7), height - 1), (Uint128::new(2), height + 1)],
+    );
+}
+
+#[test]
+fn succeed() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    default_campaigns(&mut deps);
+
+    let (_, _, response) = will_success(&mut deps, vec![
+        "Campaign1".to_string(),
+        "Campaign2".to_string(),
+        "Campaign3".to_string(),
+        "Campaign1".to_string(),
+    ]);
+    assert_eq!(response.messages, vec![
+        SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "Campaign1".to_string(),
+            funds: vec![],
+            msg: to_binary(&CampaignExecuteMsg::ClaimRewardFor {
+                actor: ACTOR.to_string(),
+            }).unwrap(),
+        })),
+        SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "Campaign3".to_string(),
+            funds: vec![],
+            msg: to_binary(&CampaignExecuteMsg::ClaimRewardFor {
+                actor: ACTOR.to_string(),
+            }).unwrap(),
+        })),
+    ]);
+}
+
+#[test]
+fn succeed_pending_rewards() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    default_campaigns(&mut deps);
+
+    let response = get_pending_rewards(
+        deps.as_ref(),
+        campaign_manager_env(),
+        ACTOR.to_string(),
+        vec!["Campaign1".to_string(), "Campaign2".to_string(), "Campaign3".to_string()],
+    ).unwrap();
+    assert_eq!(response, PendingRewardsResponse {
+        address: ACTOR.to_string(),
+        rewards: vec![
+            (Denom::Native("uusd".to_string()), Uint128::new(8)),
+            (Denom::Token(VALKYRIE_TOKEN.to_string()), Uint128::new(7)),
+        ],
+    });
+}
+
+#[test]
+fn failed_no_reward() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    default_campaigns(&mut deps);
+
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        mock_info(ACTOR, &[]),
+        vec!["Campaign2".to_string()],
+    );
+    expect_generic_err(&result, "Not exist claimable reward");
+}
+
+#[test]
+fn failed_not_registered_campaign() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    default_campaigns(&mut deps);
+
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        mock_info(ACTOR, &[]),
+        vec!["Campaign1".to_string(), "Campaign4".to_string()],
+    );
+    expect_generic_err(&result, "Campaign4 is not a registered campaign");
+}
+
+#[test]
+fn failed_invalid_campaigns() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        mock_info(ACTOR, &[]),
+        vec![],
+    );
+    expect_generic_err(&result, "campaigns must not be empty");
+
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        mock_info(ACTOR, &[]),
+        (0..31).map(|i| format!("Campaign{}", i)).collect(),
+    );
+    expect_generic_err(&result, "Too many campaigns (max 30)");
+}
@@ -1,13 +1,17 @@
-use cosmwasm_std::{Addr, Binary, CosmosMsg, Env, MessageInfo, ReplyOn, Response, SubMsg, to_binary, Uint128, WasmMsg};
+use cosmwasm_std::{Addr, Binary, coin, CosmosMsg, Decimal, Env, MessageInfo, ReplyOn, Response, SubMsg, to_binary, Uint128, WasmMsg};
+
+use cosmwasm_std::testing::mock_info;
+use cw20::Cw20ExecuteMsg;
 
 use valkyrie::campaign::execute_msgs::CampaignConfigMsg;
 use valkyrie::campaign_manager::execute_msgs::CampaignInstantiateMsg;
 use valkyrie::common::{ContractResult, Denom};
 use valkyrie::mock_querier::{custom_deps, CustomDeps};
-use valkyrie::test_constants::{DEFAULT_SENDER, default_sender, VALKYRIE_TOKEN};
+use valkyrie::test_constants::{DEFAULT_SENDER, VALKYRIE_TOKEN};
 use valkyrie::test_constants::campaign::{CAMPAIGN_DESCRIPTION, CAMPAIGN_PARAMETER_KEY, CAMPAIGN_TITLE, CAMPAIGN_URL, PARTICIPATION_REWARD_AMOUNT, PARTICIPATION_REWARD_DENOM_NATIVE, REFERRAL_REWARD_AMOUNTS, DEPOSIT_DENOM_NATIVE, DEPOSIT_AMOUNT, DEPOSIT_LOCK_PERIOD, PARTICIPATION_REWARD_LOCK_PERIOD, REFERRAL_REWARD_LOCK_PERIOD};
-use valkyrie::test_constants::campaign_manager::{CAMPAIGN_CODE_ID, CAMPAIGN_MANAGER, campaign_manager_env};
+use valkyrie::test_constants::campaign_manager::{CAMPAIGN_CODE_ID, CAMPAIGN_MANAGER, campaign_manager_env, CREATION_FEE_AMOUNT, CREATION_FEE_DENOM_NATIVE, FEE_RECIPIENT, FEE_BURN_RATIO_PERCENT};
 use valkyrie::test_constants::governance::GOVERNANCE;
+use valkyrie::test_utils::expect_generic_err;
 
 use crate::executions::{create_campaign, REPLY_CREATE_CAMPAIGN};
 use crate::states::CreateCampaignContext;
@@ -15,71 +19,98 @@ use crate::states::CreateCampaignContext;
 pub fn exec(
     deps: &mut CustomDeps,
     env: Env,
-    info: MessageInfo,
+    sender: &str,
+    funds: Vec<(cw20::Denom, Uint128)>,
     config_msg: Binary,
     deposit_denom: Option<Denom>,
     deposit_amount: Option<Uint128>,
     deposit_lock_period: Option<u64>,
     qualifier: Option<String>,
     qualification_description: Option<String>,
+    tags: Option<Vec<String>>,
+    logo_url: Option<String>,
 ) -> ContractResult<Response> {
     create_campaign(
         deps.as_mut(),
         env,
-        info,
+        Addr::unchecked(sender),
+        funds,
         config_msg,
         deposit_denom,
         deposit_amount,
         deposit_lock_period,
         qualifier,
         qualification_description,
+        tags,
+        logo_url,
     )
 }
 
 pub fn default(deps: &mut CustomDeps) -> (Env, MessageInfo, Response) {
-    let env = campaign_manager_env();
-    let info = default_sender();
+    will_success(deps, None, None)
+}
 
-    let campaign_config_msg = CampaignConfigMsg {
-        title: CAMPAIGN_TITLE.to_string(),
-        description: CAMPAIGN_DESCRIPTION.to_string(),
-        url: CAMPAIGN_URL.to_string(),
-        parameter_key: CAMPAIGN_PARAMETER_KEY.to_string(),
-        participation_reward_denom: Denom::Native(PARTICIPATION_REWARD_DENOM_NATIVE.to_string()),
-        participation_reward_amount: PARTICIPATION_REWARD_AMOUNT,
-        participation_reward_lock_period: PARTICIPATION_REWARD_LOCK_PERIOD,
-        referral_reward_amounts: REFERRAL_REWARD_AMOUNTS.to_vec(),
-        referral_reward_lock_period: REFERRAL_REWARD_LOCK_PERIOD,
-    };
+pub fn will_success(
+    deps: &mut CustomDeps,
+    tags: Option<Vec<String>>,
+    logo_url: Option<String>,
+) -> (Env, MessageInfo, Response) {
+    let env = campaign_manager_env();
+    let info = mock_info(DEFAULT_SENDER, &[coin(CREATION_FEE_AMOUNT.u128(), CREATION_FEE_DENOM_NATIVE)]);
 
     let response = exec(
         deps,
         env.clone(),
-        info.clone(),
-        to_binary(&campaign_config_msg).unwrap(),
+        DEFAULT_SENDER,
+        creation_fee(),
+        default_config_msg(),
         Some(Denom::Native(DEPOSIT_DENOM_NATIVE.to_string())),
         Some(DEPOSIT_AMOUNT),
         Some(DEPOSIT_LOCK_PERIOD),
         None,
         None,
+        tags,
+        logo_url,
     ).unwrap();
 
     (env, info, response)
 }
 
+pub fn creation_fee() -> Vec<(cw20::Denom, Uint128)> {
+    vec![(cw20::Denom::Native(CREATION_FEE_DENOM_NATIVE.to_string()), CREATION_FEE_AMOUNT)]
+}
+
+pub fn default_config_msg() -> Binary {
+    to_binary(&CampaignConfigMsg {
+        title: CAMPAIGN_TITLE.to_string(),
+        description: CAMPAIGN_DESCRIPTION.to_string(),
+        url: CAMPAIGN_URL.to_string(),
+        parameter_key: CAMPAIGN_PARAMETER_KEY.to_string(),
+        participation_reward_denom: Denom::Native(PARTICIPATION_REWARD_DENOM_NATIVE.to_string()),
+        participation_reward_amount: PARTICIPATION_REWARD_AMOUNT,
+        participation_reward_lock_period: PARTICIPATION_REWARD_LOCK_PERIOD,
+        referral_reward_amounts: REFERRAL_REWARD_AMOUNTS.to_vec(),
+        referral_reward_lock_period: REFERRAL_REWARD_LOCK_PERIOD,
+    }).unwrap()
+}
+
 #[test]
 fn succeed() {
     let mut deps = custom_deps();
 
     super::instantiate::default(&mut deps);
 
-    let (_, _, response) = default(&mut deps);
+    let (_, _, response) = will_success(
+        &mut deps,
+        Some(vec!["defi".to_string(), "nft".to_string()]),
+        Some("https://campaign.url/logo.png".to_string()),
+    );
 
     assert_eq!(response.messages, vec![
         SubMsg {
             id: REPLY_CREATE_CAMPAIGN,
             msg: CosmosMsg::Wasm(WasmMsg::Instantiate {
-                admin: Some(GOVERNANCE.to_string()),
+                admin: Some(CAMPAIGN_MANAGER.to_string()),
                 code_id: CAMPAIGN_CODE_ID,
                 msg: to_binary(&CampaignInstantiateMsg {
                     governance: GOVERNANCE.to_string(),
@@ -116,5 +147,187 @@ fn succeed() {
     assert_eq!(context, CreateCampaignContext {
         code_id: CAMPAIGN_CODE_ID,
         creator: Addr::unchecked(DEFAULT_SENDER),
+        tags: vec!["defi".to_string(), "nft".to_string()],
+        logo_url: Some("https://campaign.url/logo.png".to_string()),
     });
 }
+
+#[test]
+fn failed_invalid_tags() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    let cases: Vec<(Vec<&str>, &str)> = vec![
+        (vec!["a", "b", "c", "d", "e", "f"], "Too many tags (max 5)"),
+        (vec!["a"], "Tag too short"),
+        (vec!["abcdefghijklmnopqrstuvwxyz0123456"], "Tag too long"),
+        (vec!["DeFi"], "Tag must consist of lowercase letters, digits and '-'"),
+        (vec!["defi", "nft", "defi"], "Duplicated tag (defi)"),
+    ];
+
+    for (tags, message) in cases.into_iter() {
+        let result = exec(
+            &mut deps,
+            campaign_manager_env(),
+            DEFAULT_SENDER,
+            creation_fee(),
+            default_config_msg(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(tags.iter().map(|t| t.to_string()).collect()),
+            None,
+        );
+        expect_generic_err(&result, message);
+    }
+}
+
+#[test]
+fn failed_invalid_logo_url() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        DEFAULT_SENDER,
+        creation_fee(),
+        default_config_msg(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("http://a".to_string()),
+    );
+    expect_generic_err(&result, "Url too short");
+
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        DEFAULT_SENDER,
+        creation_fee(),
+        default_config_msg(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(format!("https://{}", "a".repeat(256))),
+    );
+    expect_generic_err(&result, "Url too long");
+}
+
+#[test]
+fn succeed_creation_fee_in_valkyrie_token() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::update_config::will_success(
+        &mut deps,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(Denom::Token(VALKYRIE_TOKEN.to_string())),
+        Some(Uint128::new(1000)),
+        None,
+        None,
+        None,
+    );
+
+    let response = exec(
+        &mut deps,
+        campaign_manager_env(),
+        DEFAULT_SENDER,
+        vec![(cw20::Denom::Cw20(Addr::unchecked(VALKYRIE_TOKEN)), Uint128::new(1000))],
+        default_config_msg(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ).unwrap();
+
+    let burn_amount = Uint128::new(1000) * Decimal::percent(FEE_BURN_RATIO_PERCENT);
+    assert_eq!(response.messages[1..], vec![
+        SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: VALKYRIE_TOKEN.to_string(),
+            funds: vec![],
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: FEE_RECIPIENT.to_string(),
+                amount: Uint128::new(1000).checked_sub(burn_amount).unwrap(),
+            }).unwrap(),
+        })),
+        SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: VALKYRIE_TOKEN.to_string(),
+            funds: vec![],
+            msg: to_binary(&Cw20ExecuteMsg::Burn {
+                amount: burn_amount,
+            }).unwrap(),
+        })),
+    ]);
+
+    let context = CreateCampaignContext::load(&deps.storage).unwrap();
+    assert_eq!(context.creator, Addr::unchecked(DEFAULT_SENDER));
+}
+
+#[test]
+fn failed_invalid_creation_fee() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    let cases: Vec<(Vec<(cw20::Denom, Uint128)>, &str)> = vec![
+        (vec![], "Missing creation fee"),
+        (
+            vec![(cw20::Denom::Native("ukrw".to_string()), CREATION_FEE_AMOUNT)],
+            "Invalid creation fee denom",
+        ),
+        (
+            vec![(cw20::Denom::Native(CREATION_FEE_DENOM_NATIVE.to_string()), Uint128::new(1))],
+            "Invalid creation fee amount",
+        ),
+        (
+            vec![
+                (cw20::Denom::Native(CREATION_FEE_DENOM_NATIVE.to_string()), CREATION_FEE_AMOUNT),
+                (cw20::Denom::Native("ukrw".to_string()), CREATION_FEE_AMOUNT),
+            ],
+            "Too many funds",
+        ),
+    ];
+
+    for (funds, message) in cases.into_iter() {
+        let result = exec(
+            &mut deps,
+            campaign_manager_env(),
+            DEFAULT_SENDER,
+            funds,
+            default_config_msg(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        expect_generic_err(&result, message);
+    }
+}
@@ -0,0 +1,104 @@
+use cosmwasm_std::{Addr, CosmosMsg, Env, from_binary, Response, Uint128, WasmMsg};
+
+use valkyrie::campaign::execute_msgs::CampaignConfigMsg;
+use valkyrie::campaign_manager::execute_msgs::{CampaignInstantiateMsg, CampaignTemplateMsg};
+use valkyrie::common::ContractResult;
+use valkyrie::errors::ContractError;
+use valkyrie::mock_querier::{custom_deps, CustomDeps};
+use valkyrie::test_constants::DEFAULT_SENDER;
+use valkyrie::test_constants::campaign::CAMPAIGN_DESCRIPTION;
+use valkyrie::test_constants::campaign_manager::campaign_manager_env;
+use valkyrie::test_utils::expect_generic_err;
+
+use crate::executions::create_campaign_from_template;
+use crate::states::CreateCampaignContext;
+
+pub fn exec(
+    deps: &mut CustomDeps,
+    env: Env,
+    template_id: u64,
+    overrides: Option<CampaignTemplateMsg>,
+) -> ContractResult<Response> {
+    create_campaign_from_template(
+        deps.as_mut(),
+        env,
+        Addr::unchecked(DEFAULT_SENDER),
+        super::create_campaign::creation_fee(),
+        template_id,
+        overrides,
+        Some(vec!["defi".to_string()]),
+        None,
+    )
+}
+
+#[test]
+fn succeed() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::create_template::default(&mut deps);
+
+    let response = exec(&mut deps, campaign_manager_env(), 1, None).unwrap();
+
+    // same as create_campaign with the config the template holds
+    let mut other_deps = custom_deps();
+    super::instantiate::default(&mut other_deps);
+    let (_, _, expected) = super::create_campaign::will_success(&mut other_deps, Some(vec!["defi".to_string()]), None);
+    assert_eq!(response.messages, expected.messages);
+    assert!(response.attributes.iter().any(|a| a.key == "template_id" && a.value == "1"));
+
+    let context = CreateCampaignContext::load(&deps.storage).unwrap();
+    assert_eq!(context.tags, vec!["defi".to_string()]);
+}
+
+#[test]
+fn succeed_with_overrides() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::create_template::will_success(&mut deps, "Partial", CampaignTemplateMsg {
+        title: None,
+        deposit_amount: None,
+        ..super::create_template::default_template()
+    });
+
+    let response = exec(&mut deps, campaign_manager_env(), 1, Some(CampaignTemplateMsg {
+        title: Some("Overridden".to_string()),
+        deposit_amount: Some(Uint128::new(7)),
+        ..CampaignTemplateMsg::default()
+    })).unwrap();
+
+    let msg = match &response.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Instantiate { msg, .. }) => from_binary::<CampaignInstantiateMsg>(msg).unwrap(),
+        _ => panic!("unexpected message"),
+    };
+    assert_eq!(msg.deposit_amount, Uint128::new(7));
+
+    let config_msg: CampaignConfigMsg = from_binary(&msg.config_msg).unwrap();
+    assert_eq!(config_msg.title, "Overridden".to_string());
+    assert_eq!(config_msg.description, CAMPAIGN_DESCRIPTION.to_string());
+}
+
+#[test]
+fn failed_missing_field() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::create_template::will_success(&mut deps, "Partial", CampaignTemplateMsg {
+        title: None,
+        ..super::create_template::default_template()
+    });
+
+    let result = exec(&mut deps, campaign_manager_env(), 1, None);
+    expect_generic_err(&result, "Missing template field (title)");
+}
+
+#[test]
+fn failed_not_found() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    let result = exec(&mut deps, campaign_manager_env(), 1, None);
+    assert_eq!(result.unwrap_err(), ContractError::NotFound {});
+}
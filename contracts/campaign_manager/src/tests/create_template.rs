@@ -0,0 +1,116 @@
+use cosmwasm_std::{Addr, Env, MessageInfo, Response};
+
+use valkyrie::campaign_manager::execute_msgs::CampaignTemplateMsg;
+use valkyrie::common::{ContractResult, Denom, OrderBy};
+use valkyrie::mock_querier::{custom_deps, CustomDeps};
+use valkyrie::test_constants::{DEFAULT_SENDER, default_sender};
+use valkyrie::test_constants::campaign::{CAMPAIGN_DESCRIPTION, CAMPAIGN_PARAMETER_KEY, CAMPAIGN_TITLE, CAMPAIGN_URL, DEPOSIT_AMOUNT, DEPOSIT_DENOM_NATIVE, DEPOSIT_LOCK_PERIOD, PARTICIPATION_REWARD_AMOUNT, PARTICIPATION_REWARD_DENOM_NATIVE, PARTICIPATION_REWARD_LOCK_PERIOD, REFERRAL_REWARD_AMOUNTS, REFERRAL_REWARD_LOCK_PERIOD};
+use valkyrie::test_constants::campaign_manager::campaign_manager_env;
+use valkyrie::test_utils::expect_generic_err;
+
+use crate::executions::create_template;
+use crate::states::CampaignTemplate;
+
+pub fn exec(
+    deps: &mut CustomDeps,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+    template: CampaignTemplateMsg,
+) -> ContractResult<Response> {
+    create_template(deps.as_mut(), env, info, name, template)
+}
+
+pub fn will_success(
+    deps: &mut CustomDeps,
+    name: &str,
+    template: CampaignTemplateMsg,
+) -> (Env, MessageInfo, Response) {
+    let env = campaign_manager_env();
+    let info = default_sender();
+
+    let response = exec(deps, env.clone(), info.clone(), name.to_string(), template).unwrap();
+
+    (env, info, response)
+}
+
+pub fn default(deps: &mut CustomDeps) -> (Env, MessageInfo, Response) {
+    will_success(deps, "Default", default_template())
+}
+
+pub fn default_template() -> CampaignTemplateMsg {
+    CampaignTemplateMsg {
+        title: Some(CAMPAIGN_TITLE.to_string()),
+        description: Some(CAMPAIGN_DESCRIPTION.to_string()),
+        url: Some(CAMPAIGN_URL.to_string()),
+        parameter_key: Some(CAMPAIGN_PARAMETER_KEY.to_string()),
+        participation_reward_denom: Some(Denom::Native(PARTICIPATION_REWARD_DENOM_NATIVE.to_string())),
+        participation_reward_amount: Some(PARTICIPATION_REWARD_AMOUNT),
+        participation_reward_lock_period: Some(PARTICIPATION_REWARD_LOCK_PERIOD),
+        referral_reward_amounts: Some(REFERRAL_REWARD_AMOUNTS.to_vec()),
+        referral_reward_lock_period: Some(REFERRAL_REWARD_LOCK_PERIOD),
+        deposit_denom: Some(Denom::Native(DEPOSIT_DENOM_NATIVE.to_string())),
+        deposit_amount: Some(DEPOSIT_AMOUNT),
+        deposit_lock_period: Some(DEPOSIT_LOCK_PERIOD),
+        qualifier: None,
+        qualification_description: None,
+    }
+}
+
+#[test]
+fn succeed() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    default(&mut deps);
+    will_success(&mut deps, "Partial", CampaignTemplateMsg {
+        title: Some("Partial".to_string()),
+        ..CampaignTemplateMsg::default()
+    });
+
+    let template = CampaignTemplate::load(&deps.storage, 1).unwrap();
+    assert_eq!(template, CampaignTemplate {
+        id: 1,
+        name: "Default".to_string(),
+        owner: Addr::unchecked(DEFAULT_SENDER),
+        template: default_template(),
+    });
+
+    let templates = CampaignTemplate::query(&deps.storage, None, None, Some(OrderBy::Asc)).unwrap();
+    assert_eq!(
+        templates.templates.iter().map(|t| t.id).collect::<Vec<u64>>(),
+        vec![1, 2],
+    );
+
+    let templates = CampaignTemplate::query(&deps.storage, Some(2), None, None).unwrap();
+    assert_eq!(
+        templates.templates.iter().map(|t| t.id).collect::<Vec<u64>>(),
+        vec![1],
+    );
+}
+
+#[test]
+fn failed_invalid_name() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        default_sender(),
+        String::new(),
+        default_template(),
+    );
+    expect_generic_err(&result, "Template name must not be empty");
+
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        default_sender(),
+        "a".repeat(65),
+        default_template(),
+    );
+    expect_generic_err(&result, "Template name too long");
+}
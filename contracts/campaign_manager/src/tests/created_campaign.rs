@@ -1,5 +1,6 @@
 use cosmwasm_std::{Addr, ContractResult as CwContractResult, Env, Event, Reply, Response, SubMsgExecutionResponse};
 
+use valkyrie::campaign_manager::enumerations::CampaignStatus;
 use valkyrie::common::ContractResult;
 use valkyrie::mock_querier::{custom_deps, CustomDeps};
 use valkyrie::test_constants::campaign_manager::campaign_manager_env;
@@ -18,6 +19,24 @@ pub fn exec(
     })
 }
 
+pub fn will_success(deps: &mut CustomDeps, campaign_address: &str) -> (Env, Response) {
+    let env = campaign_manager_env();
+
+    let response = exec(
+        deps,
+        env.clone(),
+        CwContractResult::Ok(SubMsgExecutionResponse {
+            events: vec![
+                Event::new("instantiate_contract")
+                    .add_attribute("contract_address", campaign_address.to_string()),
+            ],
+            data: None,
+        }),
+    ).unwrap();
+
+    (env, response)
+}
+
 #[test]
 fn succeed_success_reply() {
     let mut deps = custom_deps();
@@ -51,5 +70,8 @@ fn succeed_success_reply() {
         address: campaign_address,
         creator: context.creator,
         created_height: env.block.height,
+        tags: context.tags,
+        logo_url: context.logo_url,
+        status: CampaignStatus::Unverified,
     });
 }
\ No newline at end of file
@@ -1,6 +1,7 @@
-use cosmwasm_std::{Addr, Decimal, Env, MessageInfo, Response};
+use cosmwasm_std::{Addr, Decimal, Env, MessageInfo, Response, Uint128};
 
 use valkyrie::campaign_manager::execute_msgs::{InstantiateMsg, ReferralRewardLimitOptionMsg};
+use valkyrie::campaign_manager::enumerations::OverflowMode;
 use valkyrie::common::{ContractResult, Denom};
 use valkyrie::mock_querier::{custom_deps, CustomDeps};
 use valkyrie::test_constants::campaign_manager::*;
@@ -28,7 +29,15 @@ pub fn exec(
     overflow_amount_recipient: Option<String>,
     base_count: u8,
     percent_for_governance_staking: u16,
+    lp_staking: Option<String>,
+    percent_for_lp_staking: u16,
+    staking_window_period: u64,
+    limit_window_period: u64,
     contract_admin: String,
+    creation_fee_denom: Denom,
+    creation_fee_amount: Uint128,
+    swap_fee_max_spread: Decimal,
+    swap_fee_keepers: Vec<String>,
 ) -> ContractResult<Response> {
     let msg = InstantiateMsg {
         governance,
@@ -46,8 +55,16 @@ pub fn exec(
             overflow_amount_recipient,
             base_count,
             percent_for_governance_staking,
+            lp_staking,
+            percent_for_lp_staking,
+            staking_window_period,
+            limit_window_period,
         },
         contract_admin,
+        creation_fee_denom,
+        creation_fee_amount,
+        swap_fee_max_spread,
+        swap_fee_keepers,
     };
 
     instantiate(
@@ -80,7 +97,15 @@ pub fn default(deps: &mut CustomDeps) -> (Env, MessageInfo, Response) {
         None,
         REFERRAL_REWARD_LIMIT_BASE_COUNT,
         REFERRAL_REWARD_LIMIT_STAKING_PERCENT,
+        None,
+        0,
+        0,
+        0,
         GOVERNANCE.to_string(),
+        Denom::Native(CREATION_FEE_DENOM_NATIVE.to_string()),
+        CREATION_FEE_AMOUNT,
+        Decimal::percent(SWAP_FEE_MAX_SPREAD_PERCENT),
+        vec![SWAP_FEE_KEEPER.to_string()],
     ).unwrap();
 
     (env, info, response)
@@ -106,12 +131,22 @@ fn succeed() {
         deactivate_period: CAMPAIGN_DEACTIVATE_PERIOD,
         key_denom: cw20::Denom::Native(KEY_DENOM_NATIVE.to_string()),
         contract_admin: Addr::unchecked(GOVERNANCE),
+        creation_fee_denom: cw20::Denom::Native(CREATION_FEE_DENOM_NATIVE.to_string()),
+        creation_fee_amount: CREATION_FEE_AMOUNT,
+        swap_fee_max_spread: Decimal::percent(SWAP_FEE_MAX_SPREAD_PERCENT),
+        swap_fee_keepers: vec![Addr::unchecked(SWAP_FEE_KEEPER)],
+        spot_price_fallback: false,
     });
 
     let referral_reward_limit_option = ReferralRewardLimitOption::load(&deps.storage).unwrap();
     assert_eq!(referral_reward_limit_option, ReferralRewardLimitOption {
+        overflow_mode: OverflowMode::Reuse,
         overflow_amount_recipient: None,
         base_count: REFERRAL_REWARD_LIMIT_BASE_COUNT,
         percent_for_governance_staking: REFERRAL_REWARD_LIMIT_STAKING_PERCENT,
+        lp_staking: None,
+        percent_for_lp_staking: 0,
+        staking_window_period: 0,
+        limit_window_period: 0,
     });
 }
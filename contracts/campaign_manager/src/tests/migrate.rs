@@ -0,0 +1,154 @@
+use cosmwasm_std::{Addr, Decimal, Env, Response, Uint128};
+use cw_storage_plus::{Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use valkyrie::campaign_manager::execute_msgs::MigrateMsg;
+use valkyrie::campaign_manager::enumerations::{CampaignFilter, CampaignStatus, OverflowMode};
+use valkyrie::common::ContractResult;
+use valkyrie::mock_querier::{custom_deps, CustomDeps};
+use valkyrie::test_constants::{TERRASWAP_ROUTER, VALKYRIE_TOKEN};
+use valkyrie::test_constants::campaign_manager::*;
+use valkyrie::test_constants::governance::GOVERNANCE;
+
+use crate::migrations::{LegacyConfig, LegacyReferralRewardLimitOption, migrate_legacy_campaigns, v1_0_7};
+use crate::states::{Campaign, Config, ReferralRewardLimitOption};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+struct LegacyCampaign {
+    code_id: u64,
+    address: Addr,
+    creator: Addr,
+    created_height: u64,
+}
+
+fn save_legacy_states(deps: &mut CustomDeps) {
+    let legacy_config: Item<LegacyConfig> = Item::new("config");
+    legacy_config.save(&mut deps.storage, &LegacyConfig {
+        governance: Addr::unchecked(GOVERNANCE),
+        valkyrie_token: Addr::unchecked(VALKYRIE_TOKEN),
+        terraswap_router: Addr::unchecked(TERRASWAP_ROUTER),
+        code_id: CAMPAIGN_CODE_ID,
+        add_pool_fee_rate: Decimal::percent(ADD_POOL_FEE_RATE_PERCENT),
+        add_pool_min_referral_reward_rate: Decimal::percent(ADD_POOL_MIN_REFERRAL_REWARD_RATE_PERCENT),
+        remove_pool_fee_rate: Decimal::percent(REMOVE_POOL_FEE_RATE_PERCENT),
+        fee_burn_ratio: Decimal::percent(FEE_BURN_RATIO_PERCENT),
+        fee_recipient: Addr::unchecked(FEE_RECIPIENT),
+        deactivate_period: CAMPAIGN_DEACTIVATE_PERIOD,
+        key_denom: cw20::Denom::Native(KEY_DENOM_NATIVE.to_string()),
+        contract_admin: Addr::unchecked("LegacyAdmin"),
+    }).unwrap();
+
+    let legacy_limit_option: Item<LegacyReferralRewardLimitOption> = Item::new("referral_reward_limit_option");
+    legacy_limit_option.save(&mut deps.storage, &LegacyReferralRewardLimitOption {
+        overflow_amount_recipient: None,
+        base_count: REFERRAL_REWARD_LIMIT_BASE_COUNT,
+        percent_for_governance_staking: REFERRAL_REWARD_LIMIT_STAKING_PERCENT,
+    }).unwrap();
+}
+
+pub fn exec(deps: &mut CustomDeps, env: Env, contract_admin: &str) -> ContractResult<Response> {
+    v1_0_7(deps.as_mut(), env, MigrateMsg {
+        contract_admin: contract_admin.to_string(),
+    })
+}
+
+#[test]
+fn succeed() {
+    let mut deps = custom_deps();
+
+    save_legacy_states(&mut deps);
+
+    let legacy_campaigns: Map<&Addr, LegacyCampaign> = Map::new("campaign");
+    let campaign_address = Addr::unchecked("Campaign1");
+    legacy_campaigns.save(&mut deps.storage, &campaign_address, &LegacyCampaign {
+        code_id: 1,
+        address: campaign_address.clone(),
+        creator: Addr::unchecked("Creator1"),
+        created_height: 100,
+    }).unwrap();
+
+    exec(&mut deps, campaign_manager_env(), GOVERNANCE).unwrap();
+
+    let campaign = Campaign::load(&deps.storage, &campaign_address).unwrap();
+    assert_eq!(campaign, Campaign {
+        code_id: 1,
+        address: campaign_address,
+        creator: Addr::unchecked("Creator1"),
+        created_height: 100,
+        tags: vec![],
+        logo_url: None,
+        status: CampaignStatus::Unverified,
+    });
+
+    let campaigns = Campaign::query(
+        &deps.storage,
+        Some(CampaignFilter::Creator("Creator1".to_string())),
+        None,
+        None,
+        None,
+    ).unwrap();
+    assert_eq!(campaigns.campaigns, vec![campaign.to_response()]);
+
+    let config = Config::load(&deps.storage).unwrap();
+    assert_eq!(config.contract_admin, Addr::unchecked(GOVERNANCE));
+    assert_eq!(config.creation_fee_denom, cw20::Denom::Cw20(Addr::unchecked(VALKYRIE_TOKEN)));
+    assert_eq!(config.creation_fee_amount, Uint128::zero());
+    assert_eq!(config.swap_fee_max_spread, Decimal::percent(1));
+    assert!(config.swap_fee_keepers.is_empty());
+    assert!(!config.spot_price_fallback);
+
+    let limit_option = ReferralRewardLimitOption::load(&deps.storage).unwrap();
+    assert_eq!(limit_option, ReferralRewardLimitOption {
+        overflow_mode: OverflowMode::Reuse,
+        overflow_amount_recipient: None,
+        base_count: REFERRAL_REWARD_LIMIT_BASE_COUNT,
+        percent_for_governance_staking: REFERRAL_REWARD_LIMIT_STAKING_PERCENT,
+        lp_staking: None,
+        percent_for_lp_staking: 0,
+        staking_window_period: 0,
+        limit_window_period: 0,
+    });
+}
+
+#[test]
+fn succeed_paginated_campaigns() {
+    let mut deps = custom_deps();
+    save_legacy_states(&mut deps);
+
+    let legacy_campaigns: Map<&Addr, LegacyCampaign> = Map::new("campaign");
+    for i in 0..15 {
+        let campaign_address = Addr::unchecked(format!("Campaign{:02}", i));
+        legacy_campaigns.save(&mut deps.storage, &campaign_address, &LegacyCampaign {
+            code_id: 1,
+            address: campaign_address.clone(),
+            creator: Addr::unchecked("Creator1"),
+            created_height: 100,
+        }).unwrap();
+    }
+
+    let response = exec(&mut deps, campaign_manager_env(), GOVERNANCE).unwrap();
+    assert!(response.attributes.iter().any(|a| a.key == "is_completed" && a.value == "false"));
+
+    let campaigns = Campaign::query(
+        &deps.storage,
+        Some(CampaignFilter::Creator("Creator1".to_string())),
+        None,
+        Some(30),
+        None,
+    ).unwrap();
+    assert_eq!(campaigns.campaigns.len(), 10);
+
+    assert_eq!(migrate_legacy_campaigns(&mut deps.storage, None).unwrap(), (5, true));
+
+    let campaigns = Campaign::query(
+        &deps.storage,
+        Some(CampaignFilter::Creator("Creator1".to_string())),
+        None,
+        Some(30),
+        None,
+    ).unwrap();
+    assert_eq!(campaigns.campaigns.len(), 15);
+
+    assert!(migrate_legacy_campaigns(&mut deps.storage, None).is_err());
+}
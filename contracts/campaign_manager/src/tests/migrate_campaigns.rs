@@ -0,0 +1,222 @@
+use cosmwasm_std::{Addr, Binary, Env, MessageInfo, ReplyOn, Response, SubMsg, to_binary};
+
+use valkyrie::campaign::execute_msgs::MigrateMsg as CampaignMigrateMsg;
+use valkyrie::campaign_manager::enumerations::CampaignStatus;
+use valkyrie::common::ContractResult;
+use valkyrie::message_factories;
+use valkyrie::mock_querier::{custom_deps, CustomDeps};
+use valkyrie::test_constants::campaign_manager::campaign_manager_env;
+use valkyrie::test_constants::default_sender;
+use valkyrie::test_constants::governance::governance_sender;
+use valkyrie::test_utils::{expect_generic_err, expect_unauthorized_err};
+
+use crate::executions::{migrate_campaigns, MAX_MIGRATE_CAMPAIGNS, REPLY_MIGRATE_CAMPAIGN};
+use crate::states::{Campaign, MigrateCampaignsContext};
+
+pub const NEW_CODE_ID: u64 = 2;
+
+pub fn exec(
+    deps: &mut CustomDeps,
+    env: Env,
+    info: MessageInfo,
+    code_id: u64,
+    campaigns: Option<Vec<String>>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> ContractResult<Response> {
+    migrate_campaigns(
+        deps.as_mut(),
+        env,
+        info,
+        code_id,
+        campaigns,
+        start_after,
+        limit,
+        migrate_msg(),
+    )
+}
+
+pub fn will_success(
+    deps: &mut CustomDeps,
+    campaigns: Option<Vec<String>>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> (Env, MessageInfo, Response) {
+    let env = campaign_manager_env();
+    let info = governance_sender();
+
+    let response = exec(
+        deps,
+        env.clone(),
+        info.clone(),
+        NEW_CODE_ID,
+        campaigns,
+        start_after,
+        limit,
+    ).unwrap();
+
+    (env, info, response)
+}
+
+pub fn register_campaign(deps: &mut CustomDeps, address: &str) {
+    Campaign {
+        code_id: 1,
+        address: Addr::unchecked(address),
+        creator: Addr::unchecked("Creator"),
+        created_height: 0,
+        tags: vec![],
+        logo_url: None,
+        status: CampaignStatus::Unverified,
+    }.save(deps.as_mut().storage).unwrap();
+}
+
+fn migrate_msg() -> Binary {
+    to_binary(&CampaignMigrateMsg {}).unwrap()
+}
+
+fn migrate_submsg(campaign: &str) -> SubMsg {
+    SubMsg {
+        id: REPLY_MIGRATE_CAMPAIGN,
+        msg: message_factories::wasm_migrate(&Addr::unchecked(campaign), NEW_CODE_ID, migrate_msg()),
+        gas_limit: None,
+        reply_on: ReplyOn::Always,
+    }
+}
+
+#[test]
+fn succeed() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    register_campaign(&mut deps, "Campaign1");
+    register_campaign(&mut deps, "Campaign2");
+    register_campaign(&mut deps, "Campaign3");
+
+    let (_, _, response) = will_success(
+        &mut deps,
+        Some(vec!["Campaign3".to_string(), "Campaign1".to_string(), "Campaign3".to_string()]),
+        None,
+        None,
+    );
+    assert_eq!(response.messages, vec![
+        migrate_submsg("Campaign3"),
+        migrate_submsg("Campaign1"),
+    ]);
+
+    let context = MigrateCampaignsContext::may_load(&deps.storage).unwrap();
+    assert_eq!(context, Some(MigrateCampaignsContext {
+        code_id: NEW_CODE_ID,
+        campaigns: vec![Addr::unchecked("Campaign3"), Addr::unchecked("Campaign1")],
+    }));
+}
+
+#[test]
+fn succeed_paginate() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    register_campaign(&mut deps, "Campaign1");
+    register_campaign(&mut deps, "Campaign2");
+    register_campaign(&mut deps, "Campaign3");
+
+    let (_, _, response) = will_success(&mut deps, None, None, Some(2));
+    assert_eq!(response.messages, vec![
+        migrate_submsg("Campaign1"),
+        migrate_submsg("Campaign2"),
+    ]);
+
+    let (_, _, response) = will_success(&mut deps, None, Some("Campaign2".to_string()), Some(2));
+    assert_eq!(response.messages, vec![
+        migrate_submsg("Campaign3"),
+    ]);
+
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        governance_sender(),
+        NEW_CODE_ID,
+        None,
+        Some("Campaign3".to_string()),
+        None,
+    );
+    expect_generic_err(&result, "Not exist campaign to migrate");
+}
+
+#[test]
+fn succeed_default_limit() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    for i in 1..=(MAX_MIGRATE_CAMPAIGNS + 1) {
+        register_campaign(&mut deps, &format!("Campaign{:02}", i));
+    }
+
+    let (_, _, response) = will_success(&mut deps, None, None, None);
+    assert_eq!(response.messages.len(), MAX_MIGRATE_CAMPAIGNS);
+
+    let (_, _, response) = will_success(&mut deps, None, None, Some(MAX_MIGRATE_CAMPAIGNS as u32 + 1));
+    assert_eq!(response.messages.len(), MAX_MIGRATE_CAMPAIGNS);
+}
+
+#[test]
+fn failed_too_many_campaigns() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    let campaigns: Vec<String> = (1..=(MAX_MIGRATE_CAMPAIGNS + 1))
+        .map(|i| format!("Campaign{:02}", i))
+        .collect();
+    for campaign in campaigns.iter() {
+        register_campaign(&mut deps, campaign);
+    }
+
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        governance_sender(),
+        NEW_CODE_ID,
+        Some(campaigns),
+        None,
+        None,
+    );
+    expect_generic_err(&result, &format!("Too many campaigns (max {})", MAX_MIGRATE_CAMPAIGNS));
+}
+
+#[test]
+fn failed_invalid_permission() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    register_campaign(&mut deps, "Campaign1");
+
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        default_sender(),
+        NEW_CODE_ID,
+        None,
+        None,
+        None,
+    );
+    expect_unauthorized_err(&result);
+}
+
+#[test]
+fn failed_not_registered_campaign() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    register_campaign(&mut deps, "Campaign1");
+
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        governance_sender(),
+        NEW_CODE_ID,
+        Some(vec!["Campaign1".to_string(), "Unknown".to_string()]),
+        None,
+        None,
+    );
+    expect_generic_err(&result, "Unknown is not a registered campaign");
+}
@@ -0,0 +1,77 @@
+use cosmwasm_std::{Addr, ContractResult as CwContractResult, Env, Reply, Response, SubMsgExecutionResponse};
+
+use valkyrie::common::ContractResult;
+use valkyrie::mock_querier::{custom_deps, CustomDeps};
+use valkyrie::test_constants::campaign_manager::campaign_manager_env;
+
+use crate::executions::{migrated_campaign, REPLY_MIGRATE_CAMPAIGN};
+use crate::states::{Campaign, MigrateCampaignsContext};
+
+use super::migrate_campaigns::{NEW_CODE_ID, register_campaign};
+
+pub fn exec(
+    deps: &mut CustomDeps,
+    env: Env,
+    result: CwContractResult<SubMsgExecutionResponse>,
+) -> ContractResult<Response> {
+    migrated_campaign(deps.as_mut(), env, Reply {
+        id: REPLY_MIGRATE_CAMPAIGN,
+        result,
+    })
+}
+
+#[test]
+fn succeed() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    register_campaign(&mut deps, "Campaign1");
+    register_campaign(&mut deps, "Campaign2");
+
+    super::migrate_campaigns::will_success(&mut deps, None, None, None);
+
+    let response = exec(
+        &mut deps,
+        campaign_manager_env(),
+        CwContractResult::Ok(SubMsgExecutionResponse {
+            events: vec![],
+            data: None,
+        }),
+    ).unwrap();
+    assert!(response.attributes.iter().any(|a| a.key == "campaign" && a.value == "Campaign1"));
+    assert!(response.attributes.iter().any(|a| a.key == "result" && a.value == "success"));
+
+    let response = exec(
+        &mut deps,
+        campaign_manager_env(),
+        CwContractResult::Err("Unauthorized".to_string()),
+    ).unwrap();
+    assert!(response.attributes.iter().any(|a| a.key == "campaign" && a.value == "Campaign2"));
+    assert!(response.attributes.iter().any(|a| a.key == "result" && a.value == "failure"));
+    assert!(response.attributes.iter().any(|a| a.key == "error" && a.value == "Unauthorized"));
+
+    let campaign = Campaign::load(&deps.storage, &Addr::unchecked("Campaign1")).unwrap();
+    assert_eq!(campaign.code_id, NEW_CODE_ID);
+
+    let campaign = Campaign::load(&deps.storage, &Addr::unchecked("Campaign2")).unwrap();
+    assert_eq!(campaign.code_id, 1);
+
+    assert_eq!(MigrateCampaignsContext::may_load(&deps.storage).unwrap(), None);
+}
+
+#[test]
+fn failed_without_context() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        CwContractResult::Ok(SubMsgExecutionResponse {
+            events: vec![],
+            data: None,
+        }),
+    );
+    assert!(result.is_err());
+}
@@ -6,3 +6,20 @@ pub mod create_campaign;
 pub mod created_campaign;
 pub mod spend_fee;
 pub mod swap_fee;
+pub mod claim_all;
+pub mod query_campaigns;
+pub mod migrate;
+pub mod set_campaign_status;
+pub mod migrate_campaigns;
+pub mod migrated_campaign;
+pub mod create_template;
+pub mod update_template;
+pub mod remove_template;
+pub mod create_campaign_from_template;
+pub mod add_swap_route;
+pub mod remove_swap_route;
+pub mod update_fee_splits;
+pub mod set_price_source;
+pub mod record_price;
+pub mod update_add_pool_fee_tiers;
+pub mod set_campaign_overrides;
@@ -0,0 +1,151 @@
+use cosmwasm_std::Addr;
+
+use valkyrie::campaign_manager::enumerations::{CampaignFilter, CampaignStatus};
+use valkyrie::common::OrderBy;
+use valkyrie::mock_querier::{custom_deps, CustomDeps};
+
+use crate::states::Campaign;
+
+fn save_campaign(deps: &mut CustomDeps, address: &str, creator: &str, code_id: u64, height: u64, tags: &[&str]) {
+    save_campaign_with_status(deps, address, creator, code_id, height, tags, CampaignStatus::Unverified);
+}
+
+fn save_campaign_with_status(
+    deps: &mut CustomDeps,
+    address: &str,
+    creator: &str,
+    code_id: u64,
+    height: u64,
+    tags: &[&str],
+    status: CampaignStatus,
+) {
+    Campaign {
+        code_id,
+        address: Addr::unchecked(address),
+        creator: Addr::unchecked(creator),
+        created_height: height,
+        tags: tags.iter().map(|t| t.to_string()).collect(),
+        logo_url: None,
+        status,
+    }.save(&mut deps.storage).unwrap();
+}
+
+fn default_campaigns(deps: &mut CustomDeps) {
+    save_campaign(deps, "Campaign1", "Creator1", 1, 100, &["defi"]);
+    save_campaign(deps, "Campaign2", "Creator2", 1, 200, &["defi", "nft"]);
+    save_campaign(deps, "Campaign3", "Creator1", 2, 200, &["nft"]);
+    save_campaign(deps, "Campaign4", "Creator2", 2, 300, &[]);
+}
+
+fn query(
+    deps: &CustomDeps,
+    filter: Option<CampaignFilter>,
+    start_after: Option<&str>,
+    limit: Option<u32>,
+    order_by: Option<OrderBy>,
+) -> Vec<String> {
+    Campaign::query(
+        &deps.storage,
+        filter,
+        start_after.map(|v| v.to_string()),
+        limit,
+        order_by,
+    ).unwrap()
+        .campaigns
+        .into_iter()
+        .map(|c| c.address)
+        .collect()
+}
+
+#[test]
+fn succeed_filter_creator() {
+    let mut deps = custom_deps();
+    default_campaigns(&mut deps);
+
+    let filter = Some(CampaignFilter::Creator("Creator1".to_string()));
+    assert_eq!(query(&deps, filter.clone(), None, None, Some(OrderBy::Asc)), vec!["Campaign1", "Campaign3"]);
+    assert_eq!(query(&deps, filter.clone(), None, None, None), vec!["Campaign3", "Campaign1"]);
+    assert_eq!(query(&deps, filter, Some("Campaign1"), None, Some(OrderBy::Asc)), vec!["Campaign3"]);
+}
+
+#[test]
+fn succeed_filter_tag() {
+    let mut deps = custom_deps();
+    default_campaigns(&mut deps);
+
+    let filter = Some(CampaignFilter::Tag("nft".to_string()));
+    assert_eq!(query(&deps, filter, None, None, Some(OrderBy::Asc)), vec!["Campaign2", "Campaign3"]);
+
+    let filter = Some(CampaignFilter::Tag("defi".to_string()));
+    assert_eq!(query(&deps, filter, None, Some(1), Some(OrderBy::Asc)), vec!["Campaign1"]);
+}
+
+#[test]
+fn succeed_filter_code_id() {
+    let mut deps = custom_deps();
+    default_campaigns(&mut deps);
+
+    let filter = Some(CampaignFilter::CodeId(2));
+    assert_eq!(query(&deps, filter, None, None, Some(OrderBy::Asc)), vec!["Campaign3", "Campaign4"]);
+}
+
+#[test]
+fn succeed_filter_created_height() {
+    let mut deps = custom_deps();
+    default_campaigns(&mut deps);
+
+    let filter = Some(CampaignFilter::CreatedHeight { min: Some(200), max: Some(300) });
+    assert_eq!(
+        query(&deps, filter.clone(), None, None, Some(OrderBy::Asc)),
+        vec!["Campaign2", "Campaign3", "Campaign4"],
+    );
+    assert_eq!(
+        query(&deps, filter.clone(), Some("Campaign2"), None, Some(OrderBy::Asc)),
+        vec!["Campaign3", "Campaign4"],
+    );
+    assert_eq!(
+        query(&deps, filter, Some("Campaign4"), None, Some(OrderBy::Desc)),
+        vec!["Campaign3", "Campaign2"],
+    );
+
+    let filter = Some(CampaignFilter::CreatedHeight { min: None, max: Some(200) });
+    assert_eq!(
+        query(&deps, filter, None, None, None),
+        vec!["Campaign3", "Campaign2", "Campaign1"],
+    );
+}
+
+#[test]
+fn succeed_update_index() {
+    let mut deps = custom_deps();
+    default_campaigns(&mut deps);
+
+    save_campaign(&mut deps, "Campaign1", "Creator1", 3, 100, &["game"]);
+
+    let filter = Some(CampaignFilter::Tag("defi".to_string()));
+    assert_eq!(query(&deps, filter, None, None, Some(OrderBy::Asc)), vec!["Campaign2"]);
+
+    let filter = Some(CampaignFilter::Tag("game".to_string()));
+    assert_eq!(query(&deps, filter, None, None, Some(OrderBy::Asc)), vec!["Campaign1"]);
+
+    let filter = Some(CampaignFilter::CodeId(1));
+    assert_eq!(query(&deps, filter, None, None, Some(OrderBy::Asc)), vec!["Campaign2"]);
+}
+
+#[test]
+fn succeed_filter_status() {
+    let mut deps = custom_deps();
+    default_campaigns(&mut deps);
+
+    save_campaign_with_status(&mut deps, "Campaign2", "Creator2", 1, 200, &["defi", "nft"], CampaignStatus::Verified);
+    save_campaign_with_status(&mut deps, "Campaign4", "Creator2", 2, 300, &[], CampaignStatus::Delisted);
+
+    let filter = Some(CampaignFilter::Status(CampaignStatus::Unverified));
+    assert_eq!(query(&deps, filter, None, None, Some(OrderBy::Asc)), vec!["Campaign1", "Campaign3"]);
+
+    let filter = Some(CampaignFilter::Status(CampaignStatus::Verified));
+    assert_eq!(query(&deps, filter, None, None, Some(OrderBy::Asc)), vec!["Campaign2"]);
+
+    let filter = Some(CampaignFilter::Status(CampaignStatus::Delisted));
+    assert_eq!(query(&deps, filter, None, None, Some(OrderBy::Asc)), vec!["Campaign4"]);
+}
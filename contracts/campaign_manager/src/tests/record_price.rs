@@ -0,0 +1,171 @@
+use std::str::FromStr;
+
+use cosmwasm_std::{Decimal, Env, MessageInfo, Response, StdError};
+
+use valkyrie::campaign_manager::execute_msgs::PriceSourceMsg;
+use valkyrie::common::{ContractResult, Denom};
+use valkyrie::errors::ContractError;
+use valkyrie::mock_querier::{custom_deps, CustomDeps};
+use valkyrie::test_constants::{default_sender, VALKYRIE_TOKEN};
+use valkyrie::test_constants::campaign_manager::{CAMPAIGN_MANAGER, swap_fee_keeper_sender};
+use valkyrie::test_utils::{expect_generic_err, expect_unauthorized_err, mock_env_contract_height};
+
+use crate::executions::record_price;
+use crate::queries::get_price;
+
+use super::set_price_source::{uusd, vkr};
+
+pub fn exec(
+    deps: &mut CustomDeps,
+    env: Env,
+    info: MessageInfo,
+    base: Denom,
+    quote: Denom,
+) -> ContractResult<Response> {
+    record_price(deps.as_mut(), env, info, base, quote)
+}
+
+pub fn will_success(deps: &mut CustomDeps, height: u64, base: Denom, quote: Denom) -> (Env, MessageInfo, Response) {
+    let env = mock_env_contract_height(CAMPAIGN_MANAGER, height);
+    let info = swap_fee_keeper_sender();
+
+    let response = exec(deps, env.clone(), info.clone(), base, quote).unwrap();
+
+    (env, info, response)
+}
+
+#[test]
+fn succeed() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::set_price_source::will_success(
+        &mut deps,
+        1000,
+        uusd(),
+        vkr(),
+        PriceSourceMsg::Twap {
+            window: 100,
+        },
+        50,
+    );
+
+    let result = get_price(deps.as_ref(), mock_env_contract_height(CAMPAIGN_MANAGER, 1000), uusd(), vkr());
+    assert_eq!(result.unwrap_err(), ContractError::Std(StdError::generic_err("Price not recorded")));
+
+    deps.querier.with_terraswap_price("uusd".to_string(), VALKYRIE_TOKEN.to_string(), 2f64);
+    will_success(&mut deps, 1000, uusd(), vkr());
+
+    let result = get_price(deps.as_ref(), mock_env_contract_height(CAMPAIGN_MANAGER, 1050), uusd(), vkr());
+    assert_eq!(result.unwrap_err(), ContractError::Std(StdError::generic_err("Not enough price history")));
+
+    // a spot price spike only weighs as much as the blocks it lasted
+    deps.querier.with_terraswap_price("uusd".to_string(), VALKYRIE_TOKEN.to_string(), 4f64);
+    will_success(&mut deps, 1100, uusd(), vkr());
+
+    let price = get_price(deps.as_ref(), mock_env_contract_height(CAMPAIGN_MANAGER, 1100), uusd(), vkr()).unwrap();
+    assert_eq!(price.price, Decimal::percent(200));
+
+    let price = get_price(deps.as_ref(), mock_env_contract_height(CAMPAIGN_MANAGER, 1150), uusd(), vkr()).unwrap();
+    assert_eq!(price.price, Decimal::from_str("2.666666666666").unwrap());
+    assert_eq!(price.updated_height, 1100);
+
+    let result = get_price(deps.as_ref(), mock_env_contract_height(CAMPAIGN_MANAGER, 1151), uusd(), vkr());
+    assert_eq!(result.unwrap_err(), ContractError::Std(StdError::generic_err("Stale price")));
+
+    let result = exec(
+        &mut deps,
+        mock_env_contract_height(CAMPAIGN_MANAGER, 1100),
+        swap_fee_keeper_sender(),
+        uusd(),
+        vkr(),
+    );
+    expect_generic_err(&result, "Price already recorded at this height");
+}
+
+#[test]
+fn succeed_sampled_price_held_until_next_record() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::set_price_source::will_success(
+        &mut deps,
+        1000,
+        uusd(),
+        vkr(),
+        PriceSourceMsg::Twap {
+            window: 100,
+        },
+        200,
+    );
+
+    deps.querier.with_terraswap_price("uusd".to_string(), VALKYRIE_TOKEN.to_string(), 2f64);
+    will_success(&mut deps, 1000, uusd(), vkr());
+
+    // the average only sees the spot prices keepers sample,
+    // so a price recorded in a moved block counts until the next record
+    deps.querier.with_terraswap_price("uusd".to_string(), VALKYRIE_TOKEN.to_string(), 4f64);
+    will_success(&mut deps, 1001, uusd(), vkr());
+
+    deps.querier.with_terraswap_price("uusd".to_string(), VALKYRIE_TOKEN.to_string(), 2f64);
+    let price = get_price(deps.as_ref(), mock_env_contract_height(CAMPAIGN_MANAGER, 1100), uusd(), vkr()).unwrap();
+    assert_eq!(price.price, Decimal::percent(398));
+
+    will_success(&mut deps, 1002, uusd(), vkr());
+
+    let price = get_price(deps.as_ref(), mock_env_contract_height(CAMPAIGN_MANAGER, 1100), uusd(), vkr()).unwrap();
+    assert_eq!(price.price, Decimal::percent(202));
+}
+
+#[test]
+fn failed_invalid_permission() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::set_price_source::will_success(
+        &mut deps,
+        1000,
+        uusd(),
+        vkr(),
+        PriceSourceMsg::Twap {
+            window: 100,
+        },
+        50,
+    );
+
+    let result = exec(
+        &mut deps,
+        mock_env_contract_height(CAMPAIGN_MANAGER, 1000),
+        default_sender(),
+        uusd(),
+        vkr(),
+    );
+    expect_unauthorized_err(&result);
+}
+
+#[test]
+fn failed_fixed_price() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::set_price_source::will_success(
+        &mut deps,
+        1000,
+        uusd(),
+        vkr(),
+        PriceSourceMsg::Fixed {
+            price: Decimal::one(),
+        },
+        50,
+    );
+    deps.querier.with_terraswap_price("uusd".to_string(), VALKYRIE_TOKEN.to_string(), 2f64);
+
+    let result = exec(
+        &mut deps,
+        mock_env_contract_height(CAMPAIGN_MANAGER, 1000),
+        swap_fee_keeper_sender(),
+        uusd(),
+        vkr(),
+    );
+    expect_generic_err(&result, "Fixed price can not be recorded");
+}
@@ -0,0 +1,69 @@
+use cosmwasm_std::{Env, MessageInfo, Response};
+
+use valkyrie::common::{ContractResult, Denom};
+use valkyrie::mock_querier::{custom_deps, CustomDeps};
+use valkyrie::test_constants::{default_sender, VALKYRIE_TOKEN};
+use valkyrie::test_constants::campaign_manager::campaign_manager_env;
+use valkyrie::test_constants::governance::governance_sender;
+use valkyrie::test_utils::{expect_generic_err, expect_unauthorized_err};
+
+use crate::executions::remove_swap_route;
+use crate::queries::get_swap_routes;
+
+pub fn exec(
+    deps: &mut CustomDeps,
+    env: Env,
+    info: MessageInfo,
+    route: Vec<Denom>,
+) -> ContractResult<Response> {
+    remove_swap_route(deps.as_mut(), env, info, route)
+}
+
+fn route(via: &str) -> Vec<Denom> {
+    vec![
+        Denom::Native("ukrw".to_string()),
+        Denom::Native(via.to_string()),
+        Denom::Token(VALKYRIE_TOKEN.to_string()),
+    ]
+}
+
+#[test]
+fn succeed() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::add_swap_route::will_success(&mut deps, route("uusd"));
+    super::add_swap_route::will_success(&mut deps, route("uluna"));
+
+    exec(&mut deps, campaign_manager_env(), governance_sender(), route("uusd")).unwrap();
+
+    let routes = get_swap_routes(
+        deps.as_ref(),
+        campaign_manager_env(),
+        Denom::Native("ukrw".to_string()),
+        Denom::Token(VALKYRIE_TOKEN.to_string()),
+    ).unwrap();
+    assert_eq!(routes.routes, vec![route("uluna")]);
+}
+
+#[test]
+fn failed_invalid_permission() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::add_swap_route::will_success(&mut deps, route("uusd"));
+
+    let result = exec(&mut deps, campaign_manager_env(), default_sender(), route("uusd"));
+    expect_unauthorized_err(&result);
+}
+
+#[test]
+fn failed_not_registered_route() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::add_swap_route::will_success(&mut deps, route("uusd"));
+
+    let result = exec(&mut deps, campaign_manager_env(), governance_sender(), route("uluna"));
+    expect_generic_err(&result, "Not registered route");
+}
@@ -0,0 +1,47 @@
+use cosmwasm_std::{Env, MessageInfo, Response};
+use cosmwasm_std::testing::mock_info;
+
+use valkyrie::common::ContractResult;
+use valkyrie::mock_querier::{custom_deps, CustomDeps};
+use valkyrie::test_constants::default_sender;
+use valkyrie::test_constants::campaign_manager::campaign_manager_env;
+use valkyrie::test_utils::expect_unauthorized_err;
+
+use crate::executions::remove_template;
+use crate::states::CampaignTemplate;
+
+pub fn exec(
+    deps: &mut CustomDeps,
+    env: Env,
+    info: MessageInfo,
+    template_id: u64,
+) -> ContractResult<Response> {
+    remove_template(deps.as_mut(), env, info, template_id)
+}
+
+#[test]
+fn succeed() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::create_template::default(&mut deps);
+
+    exec(&mut deps, campaign_manager_env(), default_sender(), 1).unwrap();
+
+    assert_eq!(CampaignTemplate::may_load(&deps.storage, 1).unwrap(), None);
+
+    // template id is not reused
+    super::create_template::default(&mut deps);
+    assert!(CampaignTemplate::may_load(&deps.storage, 2).unwrap().is_some());
+}
+
+#[test]
+fn failed_invalid_permission() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::create_template::default(&mut deps);
+
+    let result = exec(&mut deps, campaign_manager_env(), mock_info("Other", &[]), 1);
+    expect_unauthorized_err(&result);
+}
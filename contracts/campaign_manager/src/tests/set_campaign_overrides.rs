@@ -0,0 +1,169 @@
+use cosmwasm_std::{Decimal, Env, MessageInfo, Response};
+
+use valkyrie::campaign_manager::enumerations::OverflowMode;
+use valkyrie::campaign_manager::execute_msgs::CampaignOverridesMsg;
+use valkyrie::campaign_manager::query_msgs::ReferralRewardLimitOptionResponse;
+use valkyrie::common::ContractResult;
+use valkyrie::mock_querier::{custom_deps, CustomDeps};
+use valkyrie::test_constants::campaign::CAMPAIGN;
+use valkyrie::test_constants::campaign_manager::{
+    ADD_POOL_FEE_RATE_PERCENT, CAMPAIGN_DEACTIVATE_PERIOD, campaign_manager_env,
+    REFERRAL_REWARD_LIMIT_BASE_COUNT, REFERRAL_REWARD_LIMIT_STAKING_PERCENT, REMOVE_POOL_FEE_RATE_PERCENT,
+};
+use valkyrie::test_constants::{default_sender, LP_STAKING};
+use valkyrie::test_constants::governance::governance_sender;
+use valkyrie::test_utils::{expect_generic_err, expect_not_found_err, expect_unauthorized_err};
+
+use crate::executions::set_campaign_overrides;
+use crate::queries::{get_campaign_overrides, get_effective_config, get_fee_quote};
+
+pub fn exec(
+    deps: &mut CustomDeps,
+    env: Env,
+    info: MessageInfo,
+    campaign: &str,
+    overrides: CampaignOverridesMsg,
+) -> ContractResult<Response> {
+    set_campaign_overrides(deps.as_mut(), env, info, campaign.to_string(), overrides)
+}
+
+pub fn will_success(
+    deps: &mut CustomDeps,
+    campaign: &str,
+    overrides: CampaignOverridesMsg,
+) -> (Env, MessageInfo, Response) {
+    let env = campaign_manager_env();
+    let info = governance_sender();
+
+    let response = exec(deps, env.clone(), info.clone(), campaign, overrides).unwrap();
+
+    (env, info, response)
+}
+
+fn default_overrides() -> CampaignOverridesMsg {
+    CampaignOverridesMsg {
+        add_pool_fee_rate: Some(Decimal::percent(3)),
+        deactivate_period: Some(100),
+        base_count: Some(10),
+        ..CampaignOverridesMsg::default()
+    }
+}
+
+#[test]
+fn succeed() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::migrate_campaigns::register_campaign(&mut deps, CAMPAIGN);
+
+    will_success(&mut deps, CAMPAIGN, default_overrides());
+
+    let overrides = get_campaign_overrides(deps.as_ref(), campaign_manager_env(), CAMPAIGN.to_string()).unwrap();
+    assert_eq!(overrides.overrides, default_overrides());
+
+    let effective = get_effective_config(deps.as_ref(), campaign_manager_env(), CAMPAIGN.to_string()).unwrap();
+    assert_eq!(effective.config.add_pool_fee_rate, Decimal::percent(3));
+    assert_eq!(effective.config.remove_pool_fee_rate, Decimal::percent(REMOVE_POOL_FEE_RATE_PERCENT));
+    assert_eq!(effective.config.deactivate_period, 100);
+    assert_eq!(effective.referral_reward_limit_option.base_count, 10);
+    assert_eq!(
+        effective.referral_reward_limit_option.percent_for_governance_staking,
+        REFERRAL_REWARD_LIMIT_STAKING_PERCENT,
+    );
+    assert!(!effective.delisted);
+
+    let quote = get_fee_quote(
+        deps.as_ref(),
+        campaign_manager_env(),
+        Some(CAMPAIGN.to_string()),
+        vec![],
+    ).unwrap();
+    assert_eq!(quote.fee_rate, Decimal::percent(3));
+
+    will_success(&mut deps, CAMPAIGN, CampaignOverridesMsg::default());
+
+    let effective = get_effective_config(deps.as_ref(), campaign_manager_env(), CAMPAIGN.to_string()).unwrap();
+    assert_eq!(effective.config.add_pool_fee_rate, Decimal::percent(ADD_POOL_FEE_RATE_PERCENT));
+    assert_eq!(effective.config.deactivate_period, CAMPAIGN_DEACTIVATE_PERIOD);
+    assert_eq!(effective.referral_reward_limit_option.base_count, REFERRAL_REWARD_LIMIT_BASE_COUNT);
+}
+
+#[test]
+fn succeed_referral_reward_limit_option() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::migrate_campaigns::register_campaign(&mut deps, CAMPAIGN);
+
+    will_success(&mut deps, CAMPAIGN, CampaignOverridesMsg {
+        overflow_amount_recipient: Some("OverflowRecipient".to_string()),
+        lp_staking: Some(LP_STAKING.to_string()),
+        percent_for_lp_staking: Some(30),
+        staking_window_period: Some(100),
+        limit_window_period: Some(1000),
+        ..CampaignOverridesMsg::default()
+    });
+
+    let effective = get_effective_config(deps.as_ref(), campaign_manager_env(), CAMPAIGN.to_string()).unwrap();
+    assert_eq!(effective.referral_reward_limit_option, ReferralRewardLimitOptionResponse {
+        overflow_mode: OverflowMode::Recipient,
+        overflow_amount_recipient: Some("OverflowRecipient".to_string()),
+        base_count: REFERRAL_REWARD_LIMIT_BASE_COUNT,
+        percent_for_governance_staking: REFERRAL_REWARD_LIMIT_STAKING_PERCENT,
+        lp_staking: Some(LP_STAKING.to_string()),
+        percent_for_lp_staking: 30,
+        staking_window_period: 100,
+        limit_window_period: 1000,
+    });
+
+    // an explicit mode wins over the recipient
+    will_success(&mut deps, CAMPAIGN, CampaignOverridesMsg {
+        overflow_mode: Some(OverflowMode::Burn),
+        overflow_amount_recipient: Some("OverflowRecipient".to_string()),
+        ..CampaignOverridesMsg::default()
+    });
+
+    let effective = get_effective_config(deps.as_ref(), campaign_manager_env(), CAMPAIGN.to_string()).unwrap();
+    assert_eq!(effective.referral_reward_limit_option.overflow_mode, OverflowMode::Burn);
+}
+
+#[test]
+fn failed_invalid_referral_reward_limit_option() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::migrate_campaigns::register_campaign(&mut deps, CAMPAIGN);
+
+    let result = exec(&mut deps, campaign_manager_env(), governance_sender(), CAMPAIGN, CampaignOverridesMsg {
+        overflow_mode: Some(OverflowMode::Recipient),
+        ..CampaignOverridesMsg::default()
+    });
+    expect_generic_err(&result, "Overflow amount recipient is required");
+
+    let result = exec(&mut deps, campaign_manager_env(), governance_sender(), CAMPAIGN, CampaignOverridesMsg {
+        percent_for_lp_staking: Some(30),
+        ..CampaignOverridesMsg::default()
+    });
+    expect_generic_err(&result, "LP staking contract is required");
+}
+
+#[test]
+fn failed_invalid_permission() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::migrate_campaigns::register_campaign(&mut deps, CAMPAIGN);
+
+    let result = exec(&mut deps, campaign_manager_env(), default_sender(), CAMPAIGN, default_overrides());
+    expect_unauthorized_err(&result);
+}
+
+#[test]
+fn failed_not_found() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    let result = exec(&mut deps, campaign_manager_env(), governance_sender(), CAMPAIGN, default_overrides());
+    expect_not_found_err(&result);
+}
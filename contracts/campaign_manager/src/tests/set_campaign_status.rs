@@ -0,0 +1,90 @@
+use cosmwasm_std::{Addr, Env, MessageInfo, Response};
+
+use valkyrie::campaign_manager::enumerations::CampaignStatus;
+use valkyrie::common::ContractResult;
+use valkyrie::mock_querier::{custom_deps, CustomDeps};
+use valkyrie::test_constants::campaign_manager::campaign_manager_env;
+use valkyrie::test_constants::default_sender;
+use valkyrie::test_constants::governance::governance_sender;
+use valkyrie::test_utils::{expect_not_found_err, expect_unauthorized_err};
+
+use crate::executions::set_campaign_status;
+use crate::queries::get_config;
+use crate::states::Campaign;
+
+const CAMPAIGN: &str = "CampaignContractAddress";
+
+pub fn exec(
+    deps: &mut CustomDeps,
+    env: Env,
+    info: MessageInfo,
+    address: String,
+    status: CampaignStatus,
+) -> ContractResult<Response> {
+    set_campaign_status(deps.as_mut(), env, info, address, status)
+}
+
+pub fn will_success(deps: &mut CustomDeps, address: &str, status: CampaignStatus) -> (Env, MessageInfo, Response) {
+    let env = campaign_manager_env();
+    let info = governance_sender();
+
+    let response = exec(deps, env.clone(), info.clone(), address.to_string(), status).unwrap();
+
+    (env, info, response)
+}
+
+#[test]
+fn succeed() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::create_campaign::default(&mut deps);
+    super::created_campaign::will_success(&mut deps, CAMPAIGN);
+
+    will_success(&mut deps, CAMPAIGN, CampaignStatus::Verified);
+
+    let campaign = Campaign::load(&deps.storage, &Addr::unchecked(CAMPAIGN)).unwrap();
+    assert_eq!(campaign.status, CampaignStatus::Verified);
+    assert!(get_config(deps.as_ref(), campaign_manager_env(), Some(CAMPAIGN.to_string())).is_ok());
+
+    will_success(&mut deps, CAMPAIGN, CampaignStatus::Delisted);
+
+    let campaign = Campaign::load(&deps.storage, &Addr::unchecked(CAMPAIGN)).unwrap();
+    assert_eq!(campaign.status, CampaignStatus::Delisted);
+    assert!(get_config(deps.as_ref(), campaign_manager_env(), Some(CAMPAIGN.to_string())).is_err());
+    assert!(get_config(deps.as_ref(), campaign_manager_env(), None).is_ok());
+}
+
+#[test]
+fn failed_invalid_permission() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::create_campaign::default(&mut deps);
+    super::created_campaign::will_success(&mut deps, CAMPAIGN);
+
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        default_sender(),
+        CAMPAIGN.to_string(),
+        CampaignStatus::Delisted,
+    );
+    expect_unauthorized_err(&result);
+}
+
+#[test]
+fn failed_not_registered_campaign() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        governance_sender(),
+        CAMPAIGN.to_string(),
+        CampaignStatus::Delisted,
+    );
+    expect_not_found_err(&result);
+}
@@ -0,0 +1,149 @@
+use cosmwasm_std::{Decimal, Env, MessageInfo, Response, StdError};
+
+use valkyrie::campaign_manager::execute_msgs::PriceSourceMsg;
+use valkyrie::common::{ContractResult, Denom};
+use valkyrie::errors::ContractError;
+use valkyrie::mock_querier::{custom_deps, CustomDeps};
+use valkyrie::test_constants::{default_sender, VALKYRIE_TOKEN};
+use valkyrie::test_constants::campaign_manager::{campaign_manager_env, CAMPAIGN_MANAGER};
+use valkyrie::test_constants::governance::governance_sender;
+use valkyrie::test_utils::{expect_generic_err, expect_unauthorized_err, mock_env_contract_height};
+
+use crate::executions::set_price_source;
+use crate::queries::{get_price, get_registered_price};
+
+pub fn exec(
+    deps: &mut CustomDeps,
+    env: Env,
+    info: MessageInfo,
+    base: Denom,
+    quote: Denom,
+    source: PriceSourceMsg,
+    max_staleness: u64,
+) -> ContractResult<Response> {
+    set_price_source(deps.as_mut(), env, info, base, quote, source, max_staleness)
+}
+
+pub fn will_success(
+    deps: &mut CustomDeps,
+    height: u64,
+    base: Denom,
+    quote: Denom,
+    source: PriceSourceMsg,
+    max_staleness: u64,
+) -> (Env, MessageInfo, Response) {
+    let env = mock_env_contract_height(CAMPAIGN_MANAGER, height);
+    let info = governance_sender();
+
+    let response = exec(deps, env.clone(), info.clone(), base, quote, source, max_staleness).unwrap();
+
+    (env, info, response)
+}
+
+pub fn uusd() -> Denom {
+    Denom::Native("uusd".to_string())
+}
+
+pub fn vkr() -> Denom {
+    Denom::Token(VALKYRIE_TOKEN.to_string())
+}
+
+#[test]
+fn succeed() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    will_success(
+        &mut deps,
+        1000,
+        uusd(),
+        vkr(),
+        PriceSourceMsg::Fixed {
+            price: Decimal::percent(150),
+        },
+        100,
+    );
+
+    let price = get_price(deps.as_ref(), mock_env_contract_height(CAMPAIGN_MANAGER, 1100), uusd(), vkr()).unwrap();
+    assert_eq!(price.price, Decimal::percent(150));
+    assert_eq!(price.updated_height, 1000);
+
+    let result = get_price(deps.as_ref(), mock_env_contract_height(CAMPAIGN_MANAGER, 1101), uusd(), vkr());
+    assert_eq!(result.unwrap_err(), ContractError::Std(StdError::generic_err("Stale price")));
+
+    let result = get_price(deps.as_ref(), mock_env_contract_height(CAMPAIGN_MANAGER, 1000), vkr(), uusd());
+    assert_eq!(result.unwrap_err(), ContractError::Std(StdError::generic_err("Price source not registered (ValkyrieToken > uusd)")));
+
+    let price = get_registered_price(deps.as_ref(), mock_env_contract_height(CAMPAIGN_MANAGER, 1100), uusd(), vkr()).unwrap();
+    assert_eq!(price.map(|p| p.price), Some(Decimal::percent(150)));
+
+    let price = get_registered_price(deps.as_ref(), mock_env_contract_height(CAMPAIGN_MANAGER, 1000), vkr(), uusd()).unwrap();
+    assert_eq!(price, None);
+}
+
+#[test]
+fn failed_invalid_permission() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        default_sender(),
+        uusd(),
+        vkr(),
+        PriceSourceMsg::Fixed {
+            price: Decimal::one(),
+        },
+        100,
+    );
+    expect_unauthorized_err(&result);
+}
+
+#[test]
+fn failed_invalid_source() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        governance_sender(),
+        uusd(),
+        uusd(),
+        PriceSourceMsg::Fixed {
+            price: Decimal::one(),
+        },
+        100,
+    );
+    expect_generic_err(&result, "base and quote must be different");
+
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        governance_sender(),
+        uusd(),
+        vkr(),
+        PriceSourceMsg::Fixed {
+            price: Decimal::zero(),
+        },
+        100,
+    );
+    expect_generic_err(&result, "price must be greater than zero");
+
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        governance_sender(),
+        uusd(),
+        vkr(),
+        PriceSourceMsg::Twap {
+            window: 0,
+        },
+        100,
+    );
+    expect_generic_err(&result, "window must be greater than zero");
+}
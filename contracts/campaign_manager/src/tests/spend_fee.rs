@@ -7,6 +7,10 @@ use valkyrie::test_constants::{default_sender, VALKYRIE_TOKEN};
 use crate::executions::spend_fee;
 use cw20::Cw20ExecuteMsg;
 use valkyrie::test_constants::campaign_manager::{campaign_manager_env, FEE_RECIPIENT, CAMPAIGN_MANAGER};
+use valkyrie::test_constants::community::COMMUNITY;
+use valkyrie::test_constants::distributor::DISTRIBUTOR;
+
+use super::update_fee_splits::DUST_RECIPIENT;
 
 pub fn exec(
     deps: &mut CustomDeps,
@@ -71,6 +75,11 @@ fn succeed() {
         None,
         None,
         None,
+        None,
+        None,
+        None,
+        None,
+        None,
     );
 
     deps.querier.plus_token_balances(&[
@@ -98,3 +107,51 @@ fn succeed() {
         })),
     ]);
 }
+
+#[test]
+fn succeed_fee_splits() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::update_fee_splits::will_success(
+        &mut deps,
+        super::update_fee_splits::default_splits(),
+        Some(DUST_RECIPIENT.to_string()),
+    );
+
+    let (_, _, response) = will_success(&mut deps, Some(Uint128::new(101)));
+    assert_eq!(response.messages, vec![
+        SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: VALKYRIE_TOKEN.to_string(),
+            funds: vec![],
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: DISTRIBUTOR.to_string(),
+                amount: Uint128::new(40),
+            }).unwrap(),
+        })),
+        SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: VALKYRIE_TOKEN.to_string(),
+            funds: vec![],
+            msg: to_binary(&Cw20ExecuteMsg::Send {
+                contract: COMMUNITY.to_string(),
+                amount: Uint128::new(30),
+                msg: to_binary("hook").unwrap(),
+            }).unwrap(),
+        })),
+        SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: VALKYRIE_TOKEN.to_string(),
+            funds: vec![],
+            msg: to_binary(&Cw20ExecuteMsg::Burn {
+                amount: Uint128::new(30),
+            }).unwrap(),
+        })),
+        SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: VALKYRIE_TOKEN.to_string(),
+            funds: vec![],
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: DUST_RECIPIENT.to_string(),
+                amount: Uint128::new(1),
+            }).unwrap(),
+        })),
+    ]);
+}
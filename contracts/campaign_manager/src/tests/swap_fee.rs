@@ -2,12 +2,13 @@ use valkyrie::mock_querier::{CustomDeps, custom_deps};
 use cosmwasm_std::{Env, MessageInfo, Uint128, Response, coin, CosmosMsg, WasmMsg, to_binary, Addr, SubMsg};
 use valkyrie::common::{ContractResult, Denom};
 use crate::executions::swap_fee;
-use valkyrie::test_utils::expect_generic_err;
+use valkyrie::test_utils::{expect_generic_err, expect_unauthorized_err};
 use terraswap::router::{ExecuteMsg, SwapOperation};
 use terraswap::asset::AssetInfo;
 use cw20::Cw20ExecuteMsg;
 use valkyrie::test_constants::{default_sender, TERRASWAP_ROUTER, VALKYRIE_TOKEN};
-use valkyrie::test_constants::campaign_manager::{CAMPAIGN_MANAGER, campaign_manager_env};
+use valkyrie::test_constants::campaign_manager::{CAMPAIGN_MANAGER, campaign_manager_env, swap_fee_keeper_sender};
+use valkyrie::test_constants::governance::governance_sender;
 
 pub fn exec(
     deps: &mut CustomDeps,
@@ -16,6 +17,7 @@ pub fn exec(
     denom: Denom,
     amount: Option<Uint128>,
     route: Option<Vec<Denom>>,
+    minimum_receive: Option<Uint128>,
 ) -> ContractResult<Response> {
     swap_fee(
         deps.as_mut(),
@@ -24,6 +26,7 @@ pub fn exec(
         denom,
         amount,
         route,
+        minimum_receive,
     )
 }
 
@@ -34,7 +37,11 @@ pub fn will_success(
     route: Option<Vec<Denom>>,
 ) -> (Env, MessageInfo, Response) {
     let env = campaign_manager_env();
-    let info = default_sender();
+    let info = swap_fee_keeper_sender();
+
+    deps.querier.with_terraswap_price("uusd".to_string(), VALKYRIE_TOKEN.to_string(), 2f64);
+    deps.querier.with_terraswap_price("ukrw".to_string(), "uusd".to_string(), 0.5f64);
+    deps.querier.with_terraswap_price("Token1".to_string(), VALKYRIE_TOKEN.to_string(), 1f64);
 
     let response = exec(
         deps,
@@ -43,6 +50,7 @@ pub fn will_success(
         denom,
         amount,
         route,
+        None,
     ).unwrap();
 
     (env, info, response)
@@ -80,7 +88,7 @@ fn succeed_native() {
                         },
                     },
                 ],
-                minimum_receive: None,
+                minimum_receive: Some(Uint128::new(19800)),
                 to: None,
             }).unwrap(),
         })),
@@ -122,7 +130,7 @@ fn succeed_token() {
                             },
                         },
                     ],
-                    minimum_receive: None,
+                    minimum_receive: Some(Uint128::new(9900)),
                     to: None,
                 }).unwrap(),
             }).unwrap(),
@@ -170,7 +178,7 @@ fn succeed_route() {
                         },
                     },
                 ],
-                minimum_receive: None,
+                minimum_receive: Some(Uint128::new(9900)),
                 to: None,
             }).unwrap(),
         })),
@@ -190,12 +198,13 @@ fn failed_invalid_route() {
     let result = exec(
         &mut deps,
         campaign_manager_env(),
-        default_sender(),
+        swap_fee_keeper_sender(),
         Denom::Native("ukrw".to_string()),
         None,
         Some(vec![
             Denom::Native("ukrw".to_string()),
         ]),
+        None,
     );
     expect_generic_err(
         &result,
@@ -208,13 +217,14 @@ fn failed_invalid_route() {
     let result = exec(
         &mut deps,
         campaign_manager_env(),
-        default_sender(),
+        swap_fee_keeper_sender(),
         Denom::Native("ukrw".to_string()),
         None,
         Some(vec![
             Denom::Native("uusd".to_string()),
             Denom::Token(VALKYRIE_TOKEN.to_string()),
         ]),
+        None,
     );
     expect_generic_err(
         &result,
@@ -227,13 +237,14 @@ fn failed_invalid_route() {
     let result = exec(
         &mut deps,
         campaign_manager_env(),
-        default_sender(),
+        swap_fee_keeper_sender(),
         Denom::Native("ukrw".to_string()),
         None,
         Some(vec![
             Denom::Native("ukrw".to_string()),
             Denom::Native("uusd".to_string()),
         ]),
+        None,
     );
     expect_generic_err(
         &result,
@@ -261,20 +272,174 @@ fn failed_overflow() {
     let result = exec(
         &mut deps,
         campaign_manager_env(),
-        default_sender(),
+        swap_fee_keeper_sender(),
         Denom::Native("ukrw".to_string()),
         Some(Uint128::new(10001)),
         None,
+        None,
     );
     expect_generic_err(&result, "Insufficient balance");
 
     let result = exec(
         &mut deps,
         campaign_manager_env(),
-        default_sender(),
+        swap_fee_keeper_sender(),
         Denom::Token("Token1".to_string()),
         Some(Uint128::new(10001)),
         None,
+        None,
     );
     expect_generic_err(&result, "Insufficient balance");
-}
\ No newline at end of file
+}
+
+#[test]
+fn succeed_minimum_receive() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    deps.querier.plus_native_balance(CAMPAIGN_MANAGER, vec![
+        coin(10000u128, "uusd"),
+    ]);
+
+    deps.querier.with_terraswap_price("uusd".to_string(), VALKYRIE_TOKEN.to_string(), 2f64);
+
+    let response = exec(
+        &mut deps,
+        campaign_manager_env(),
+        governance_sender(),
+        Denom::Native("uusd".to_string()),
+        None,
+        None,
+        Some(Uint128::new(20000)),
+    ).unwrap();
+
+    assert_eq!(response.messages, vec![
+        SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: TERRASWAP_ROUTER.to_string(),
+            funds: vec![coin(10000, "uusd")],
+            msg: to_binary(&ExecuteMsg::ExecuteSwapOperations {
+                operations: vec![
+                    SwapOperation::TerraSwap {
+                        offer_asset_info: AssetInfo::NativeToken {
+                            denom: "uusd".to_string(),
+                        },
+                        ask_asset_info: AssetInfo::Token {
+                            contract_addr: Addr::unchecked(VALKYRIE_TOKEN).to_string(),
+                        },
+                    },
+                ],
+                minimum_receive: Some(Uint128::new(20000)),
+                to: None,
+            }).unwrap(),
+        })),
+    ]);
+}
+
+#[test]
+fn failed_invalid_permission() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    deps.querier.plus_native_balance(CAMPAIGN_MANAGER, vec![
+        coin(10000u128, "uusd"),
+    ]);
+
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        default_sender(),
+        Denom::Native("uusd".to_string()),
+        None,
+        None,
+        None,
+    );
+    expect_unauthorized_err(&result);
+}
+
+#[test]
+fn succeed_best_route() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    deps.querier.plus_native_balance(CAMPAIGN_MANAGER, vec![
+        coin(10000u128, "ukrw"),
+    ]);
+    deps.querier.with_terraswap_price("ukrw".to_string(), "Token1".to_string(), 3f64);
+
+    super::add_swap_route::will_success(&mut deps, vec![
+        Denom::Native("ukrw".to_string()),
+        Denom::Native("uusd".to_string()),
+        Denom::Token(VALKYRIE_TOKEN.to_string()),
+    ]);
+    super::add_swap_route::will_success(&mut deps, vec![
+        Denom::Native("ukrw".to_string()),
+        Denom::Token("Token1".to_string()),
+        Denom::Token(VALKYRIE_TOKEN.to_string()),
+    ]);
+    super::add_swap_route::will_success(&mut deps, vec![
+        Denom::Native("ukrw".to_string()),
+        Denom::Native("uluna".to_string()),
+        Denom::Token(VALKYRIE_TOKEN.to_string()),
+    ]);
+
+    let (_, _, response) = will_success(
+        &mut deps,
+        Denom::Native("ukrw".to_string()),
+        None,
+        None,
+    );
+
+    assert_eq!(response.messages, vec![
+        SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: TERRASWAP_ROUTER.to_string(),
+            funds: vec![coin(10000, "ukrw")],
+            msg: to_binary(&ExecuteMsg::ExecuteSwapOperations {
+                operations: vec![
+                    SwapOperation::TerraSwap {
+                        offer_asset_info: AssetInfo::NativeToken {
+                            denom: "ukrw".to_string(),
+                        },
+                        ask_asset_info: AssetInfo::Token {
+                            contract_addr: "Token1".to_string(),
+                        },
+                    },
+                    SwapOperation::TerraSwap {
+                        offer_asset_info: AssetInfo::Token {
+                            contract_addr: "Token1".to_string(),
+                        },
+                        ask_asset_info: AssetInfo::Token {
+                            contract_addr: Addr::unchecked(VALKYRIE_TOKEN).to_string(),
+                        },
+                    },
+                ],
+                minimum_receive: Some(Uint128::new(29700)),
+                to: None,
+            }).unwrap(),
+        })),
+    ]);
+}
+
+#[test]
+fn failed_no_available_route() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    deps.querier.plus_native_balance(CAMPAIGN_MANAGER, vec![
+        coin(10000u128, "uluna"),
+    ]);
+
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        swap_fee_keeper_sender(),
+        Denom::Native("uluna".to_string()),
+        None,
+        None,
+        None,
+    );
+    expect_generic_err(&result, &format!("No available swap route (uluna > {})", VALKYRIE_TOKEN));
+}
@@ -0,0 +1,148 @@
+use cosmwasm_std::{Decimal, Env, MessageInfo, Response, Uint128};
+
+use valkyrie::campaign_manager::execute_msgs::FeeTierMsg;
+use valkyrie::common::ContractResult;
+use valkyrie::governance::query_msgs::StakerStateResponse;
+use valkyrie::mock_querier::{custom_deps, CustomDeps};
+use valkyrie::test_constants::campaign_manager::campaign_manager_env;
+use valkyrie::test_constants::default_sender;
+use valkyrie::test_constants::governance::governance_sender;
+use valkyrie::test_utils::{expect_generic_err, expect_unauthorized_err};
+
+use crate::executions::update_add_pool_fee_tiers;
+use crate::queries::{get_add_pool_fee_tiers, get_fee_quote};
+
+pub fn exec(
+    deps: &mut CustomDeps,
+    env: Env,
+    info: MessageInfo,
+    tiers: Vec<FeeTierMsg>,
+) -> ContractResult<Response> {
+    update_add_pool_fee_tiers(deps.as_mut(), env, info, tiers)
+}
+
+pub fn will_success(deps: &mut CustomDeps, tiers: Vec<FeeTierMsg>) -> (Env, MessageInfo, Response) {
+    let env = campaign_manager_env();
+    let info = governance_sender();
+
+    let response = exec(deps, env.clone(), info.clone(), tiers).unwrap();
+
+    (env, info, response)
+}
+
+fn default_tiers() -> Vec<FeeTierMsg> {
+    vec![
+        FeeTierMsg {
+            min_staking_amount: Uint128::new(1000),
+            fee_rate: Decimal::percent(5),
+        },
+        FeeTierMsg {
+            min_staking_amount: Uint128::new(10000),
+            fee_rate: Decimal::percent(1),
+        },
+    ]
+}
+
+fn set_add_pool_fee_rate(deps: &mut CustomDeps, add_pool_fee_rate: Decimal) {
+    super::update_config::will_success(
+        deps,
+        None,
+        None,
+        None,
+        None,
+        Some(add_pool_fee_rate),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+}
+
+#[test]
+fn succeed() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    set_add_pool_fee_rate(&mut deps, Decimal::percent(10));
+
+    will_success(&mut deps, default_tiers());
+
+    let tiers = get_add_pool_fee_tiers(deps.as_ref(), campaign_manager_env()).unwrap();
+    assert_eq!(tiers.tiers, default_tiers());
+
+    deps.querier.with_gov_staker_state("Creator", StakerStateResponse {
+        balance: Uint128::new(999),
+        share: Uint128::new(999),
+        votes: vec![],
+        ..StakerStateResponse::default()
+    });
+    deps.querier.with_gov_staker_state("Admin", StakerStateResponse {
+        balance: Uint128::new(10000),
+        share: Uint128::new(10000),
+        votes: vec![],
+        ..StakerStateResponse::default()
+    });
+
+    let quote = get_fee_quote(deps.as_ref(), campaign_manager_env(), None, vec!["Creator".to_string()]).unwrap();
+    assert_eq!(quote.address, Some("Creator".to_string()));
+    assert_eq!(quote.tier, None);
+    assert_eq!(quote.fee_rate, Decimal::percent(10));
+
+    let quote = get_fee_quote(
+        deps.as_ref(),
+        campaign_manager_env(),
+        None,
+        vec!["Creator".to_string(), "Admin".to_string()],
+    ).unwrap();
+    assert_eq!(quote.address, Some("Admin".to_string()));
+    assert_eq!(quote.staking_amount, Uint128::new(10000));
+    assert_eq!(quote.tier, Some(1));
+    assert_eq!(quote.fee_rate, Decimal::percent(1));
+
+    // tiers never exceed the base fee rate
+    set_add_pool_fee_rate(&mut deps, Decimal::zero());
+    let quote = get_fee_quote(deps.as_ref(), campaign_manager_env(), None, vec!["Admin".to_string()]).unwrap();
+    assert_eq!(quote.fee_rate, Decimal::zero());
+}
+
+#[test]
+fn failed_invalid_permission() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    set_add_pool_fee_rate(&mut deps, Decimal::percent(10));
+
+    let result = exec(&mut deps, campaign_manager_env(), default_sender(), default_tiers());
+    expect_unauthorized_err(&result);
+}
+
+#[test]
+fn failed_invalid_tiers() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    set_add_pool_fee_rate(&mut deps, Decimal::percent(10));
+
+    let mut tiers = default_tiers();
+    tiers[0].fee_rate = Decimal::percent(11);
+    let result = exec(&mut deps, campaign_manager_env(), governance_sender(), tiers);
+    expect_generic_err(&result, "Tier fee rate must be less than or equal to add_pool_fee_rate");
+
+    let mut tiers = default_tiers();
+    tiers[1].min_staking_amount = Uint128::new(1000);
+    let result = exec(&mut deps, campaign_manager_env(), governance_sender(), tiers);
+    expect_generic_err(&result, "Tiers must be sorted by min_staking_amount in ascending order");
+
+    let mut tiers = default_tiers();
+    tiers[1].fee_rate = Decimal::percent(6);
+    let result = exec(&mut deps, campaign_manager_env(), governance_sender(), tiers);
+    expect_generic_err(&result, "Higher tier must not have higher fee rate");
+}
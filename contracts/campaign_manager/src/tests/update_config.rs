@@ -1,4 +1,4 @@
-use cosmwasm_std::{Addr, Decimal, Env, MessageInfo, Response};
+use cosmwasm_std::{Addr, Decimal, Env, MessageInfo, Response, Uint128};
 
 use valkyrie::common::{ContractResult, Denom};
 use valkyrie::mock_querier::{custom_deps, CustomDeps};
@@ -26,6 +26,11 @@ pub fn exec(
     deactivate_period: Option<u64>,
     key_denom: Option<Denom>,
     contract_admin: Option<String>,
+    creation_fee_denom: Option<Denom>,
+    creation_fee_amount: Option<Uint128>,
+    swap_fee_max_spread: Option<Decimal>,
+    swap_fee_keepers: Option<Vec<String>>,
+    spot_price_fallback: Option<bool>,
 ) -> ContractResult<Response> {
     update_config(
         deps.as_mut(),
@@ -43,6 +48,11 @@ pub fn exec(
         deactivate_period,
         key_denom,
         contract_admin,
+        creation_fee_denom,
+        creation_fee_amount,
+        swap_fee_max_spread,
+        swap_fee_keepers,
+        spot_price_fallback,
     )
 }
 
@@ -60,6 +70,11 @@ pub fn will_success(
     deactivate_period: Option<u64>,
     key_denom: Option<Denom>,
     contract_admin: Option<String>,
+    creation_fee_denom: Option<Denom>,
+    creation_fee_amount: Option<Uint128>,
+    swap_fee_max_spread: Option<Decimal>,
+    swap_fee_keepers: Option<Vec<String>>,
+    spot_price_fallback: Option<bool>,
 ) -> (Env, MessageInfo, Response) {
     let env = campaign_manager_env();
     let info = governance_sender();
@@ -80,6 +95,11 @@ pub fn will_success(
         deactivate_period,
         key_denom,
         contract_admin,
+        creation_fee_denom,
+        creation_fee_amount,
+        swap_fee_max_spread,
+        swap_fee_keepers,
+        spot_price_fallback,
     ).unwrap();
 
     (env, info, response)
@@ -103,6 +123,10 @@ fn succeed() {
     let deactivate_period = 99u64;
     let key_denom = Denom::Native("ukrw".to_string());
     let contract_admin = "ChangedContAdm";
+    let creation_fee_denom = Denom::Token("ChangedFeeToken".to_string());
+    let creation_fee_amount = Uint128::new(1000);
+    let swap_fee_max_spread = Decimal::percent(3);
+    let swap_fee_keepers = vec!["ChangedKeeper".to_string()];
 
     will_success(
         &mut deps,
@@ -118,6 +142,11 @@ fn succeed() {
         Some(deactivate_period),
         Some(key_denom.clone()),
         Some(contract_admin.to_string()),
+        Some(creation_fee_denom.clone()),
+        Some(creation_fee_amount),
+        Some(swap_fee_max_spread),
+        Some(swap_fee_keepers),
+        Some(true),
     );
 
     let config = Config::load(&deps.storage).unwrap();
@@ -134,6 +163,11 @@ fn succeed() {
         deactivate_period: deactivate_period.clone(),
         key_denom: key_denom.to_cw20(&deps.api),
         contract_admin: Addr::unchecked(contract_admin),
+        creation_fee_denom: creation_fee_denom.to_cw20(&deps.api),
+        creation_fee_amount,
+        swap_fee_max_spread,
+        swap_fee_keepers: vec![Addr::unchecked("ChangedKeeper")],
+        spot_price_fallback: true,
     });
 }
 
@@ -159,6 +193,11 @@ fn failed_invalid_permission() {
         None,
         None,
         None,
+        None,
+        None,
+        None,
+        None,
+        None,
     );
     expect_unauthorized_err(&result);
 }
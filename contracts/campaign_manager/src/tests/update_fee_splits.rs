@@ -0,0 +1,142 @@
+use cosmwasm_std::{Decimal, Env, MessageInfo, Response, to_binary};
+
+use valkyrie::campaign_manager::enumerations::FeeSplitRecipient;
+use valkyrie::campaign_manager::execute_msgs::FeeSplitMsg;
+use valkyrie::common::ContractResult;
+use valkyrie::mock_querier::{custom_deps, CustomDeps};
+use valkyrie::test_constants::campaign_manager::{campaign_manager_env, FEE_RECIPIENT};
+use valkyrie::test_constants::community::COMMUNITY;
+use valkyrie::test_constants::default_sender;
+use valkyrie::test_constants::distributor::DISTRIBUTOR;
+use valkyrie::test_constants::governance::governance_sender;
+use valkyrie::test_utils::{expect_generic_err, expect_unauthorized_err};
+
+use crate::executions::update_fee_splits;
+use crate::queries::get_fee_splits;
+
+pub const DUST_RECIPIENT: &str = "DustRecipient";
+
+pub fn exec(
+    deps: &mut CustomDeps,
+    env: Env,
+    info: MessageInfo,
+    splits: Vec<FeeSplitMsg>,
+    dust_recipient: Option<String>,
+) -> ContractResult<Response> {
+    update_fee_splits(deps.as_mut(), env, info, splits, dust_recipient)
+}
+
+pub fn will_success(
+    deps: &mut CustomDeps,
+    splits: Vec<FeeSplitMsg>,
+    dust_recipient: Option<String>,
+) -> (Env, MessageInfo, Response) {
+    let env = campaign_manager_env();
+    let info = governance_sender();
+
+    let response = exec(deps, env.clone(), info.clone(), splits, dust_recipient).unwrap();
+
+    (env, info, response)
+}
+
+pub fn default_splits() -> Vec<FeeSplitMsg> {
+    vec![
+        FeeSplitMsg {
+            recipient: FeeSplitRecipient::Address(DISTRIBUTOR.to_string()),
+            weight: Decimal::percent(40),
+            msg: None,
+        },
+        FeeSplitMsg {
+            recipient: FeeSplitRecipient::Address(COMMUNITY.to_string()),
+            weight: Decimal::percent(30),
+            msg: Some(to_binary("hook").unwrap()),
+        },
+        FeeSplitMsg {
+            recipient: FeeSplitRecipient::Burn,
+            weight: Decimal::percent(30),
+            msg: None,
+        },
+    ]
+}
+
+#[test]
+fn succeed() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    will_success(&mut deps, default_splits(), Some(DUST_RECIPIENT.to_string()));
+
+    let fee_splits = get_fee_splits(deps.as_ref(), campaign_manager_env()).unwrap();
+    assert_eq!(fee_splits.splits, default_splits());
+    assert_eq!(fee_splits.dust_recipient, DUST_RECIPIENT.to_string());
+
+    will_success(&mut deps, vec![], None);
+
+    let fee_splits = get_fee_splits(deps.as_ref(), campaign_manager_env()).unwrap();
+    assert!(fee_splits.splits.is_empty());
+    assert_eq!(fee_splits.dust_recipient, FEE_RECIPIENT.to_string());
+}
+
+#[test]
+fn failed_invalid_permission() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        default_sender(),
+        default_splits(),
+        None,
+    );
+    expect_unauthorized_err(&result);
+}
+
+#[test]
+fn failed_invalid_weights() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    let mut splits = default_splits();
+    splits[0].weight = Decimal::percent(39);
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        governance_sender(),
+        splits,
+        None,
+    );
+    expect_generic_err(&result, "Sum of fee split weights must be 1");
+
+    let mut splits = default_splits();
+    splits[0].weight = Decimal::zero();
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        governance_sender(),
+        splits,
+        None,
+    );
+    expect_generic_err(&result, "Fee split weight must be in (0, 1]");
+}
+
+#[test]
+fn failed_burn_with_msg() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    let mut splits = default_splits();
+    splits[2].msg = Some(to_binary("hook").unwrap());
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        governance_sender(),
+        splits,
+        None,
+    );
+    expect_generic_err(&result, "Burn fee split can not have a msg");
+}
@@ -1,11 +1,12 @@
 use cosmwasm_std::{Addr, Env, MessageInfo, Response};
 
+use valkyrie::campaign_manager::enumerations::OverflowMode;
 use valkyrie::common::ContractResult;
 use valkyrie::mock_querier::{custom_deps, CustomDeps};
 use valkyrie::test_constants::campaign_manager::campaign_manager_env;
 use valkyrie::test_constants::default_sender;
 use valkyrie::test_constants::governance::governance_sender;
-use valkyrie::test_utils::expect_unauthorized_err;
+use valkyrie::test_utils::{expect_unauthorized_err, expect_generic_err};
 
 use crate::executions::update_referral_reward_limit_option;
 use crate::states::ReferralRewardLimitOption;
@@ -14,25 +15,40 @@ pub fn exec(
     deps: &mut CustomDeps,
     env: Env,
     info: MessageInfo,
+    overflow_mode: Option<OverflowMode>,
     overflow_amount_recipient: Option<String>,
     base_count: Option<u8>,
     percent_for_governance_staking: Option<u16>,
+    lp_staking: Option<String>,
+    percent_for_lp_staking: Option<u16>,
+    staking_window_period: Option<u64>,
+    limit_window_period: Option<u64>,
 ) -> ContractResult<Response> {
     update_referral_reward_limit_option(
         deps.as_mut(),
         env,
         info,
+        overflow_mode,
         overflow_amount_recipient,
         base_count,
         percent_for_governance_staking,
+        lp_staking,
+        percent_for_lp_staking,
+        staking_window_period,
+        limit_window_period,
     )
 }
 
 pub fn will_success(
     deps: &mut CustomDeps,
+    overflow_mode: Option<OverflowMode>,
     overflow_amount_recipient: Option<String>,
     base_count: Option<u8>,
     percent_for_governance_staking: Option<u16>,
+    lp_staking: Option<String>,
+    percent_for_lp_staking: Option<u16>,
+    staking_window_period: Option<u64>,
+    limit_window_period: Option<u64>,
 ) -> (Env, MessageInfo, Response) {
     let env = campaign_manager_env();
     let info = governance_sender();
@@ -41,9 +57,14 @@ pub fn will_success(
         deps,
         env.clone(),
         info.clone(),
+        overflow_mode,
         overflow_amount_recipient,
         base_count,
         percent_for_governance_staking,
+        lp_staking,
+        percent_for_lp_staking,
+        staking_window_period,
+        limit_window_period,
     ).unwrap();
 
     (env, info, response)
@@ -58,22 +79,97 @@ fn succeed() {
     let overflow_amount_recipient = "ChangedRecipient";
     let base_count = 1u8;
     let percent_for_governance_staking = 10u16;
+    let lp_staking = "ChangedLpStaking";
+    let percent_for_lp_staking = 20u16;
+    let staking_window_period = 100u64;
+    let limit_window_period = 100000u64;
 
     will_success(
         &mut deps,
+        None,
         Some(overflow_amount_recipient.to_string()),
         Some(base_count),
         Some(percent_for_governance_staking),
+        Some(lp_staking.to_string()),
+        Some(percent_for_lp_staking),
+        Some(staking_window_period),
+        Some(limit_window_period),
     );
 
     let option = ReferralRewardLimitOption::load(&deps.storage).unwrap();
     assert_eq!(option, ReferralRewardLimitOption {
+        overflow_mode: OverflowMode::Recipient,
         overflow_amount_recipient: Some(Addr::unchecked(overflow_amount_recipient)),
         base_count,
         percent_for_governance_staking,
+        lp_staking: Some(Addr::unchecked(lp_staking)),
+        percent_for_lp_staking,
+        staking_window_period,
+        limit_window_period,
     });
 }
 
+#[test]
+fn succeed_overflow_mode() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    will_success(&mut deps, Some(OverflowMode::Burn), None, None, None, None, None, None, None);
+
+    let option = ReferralRewardLimitOption::load(&deps.storage).unwrap();
+    assert_eq!(option.overflow_mode, OverflowMode::Burn);
+
+    will_success(&mut deps, Some(OverflowMode::Reuse), None, None, None, None, None, None, None);
+
+    let option = ReferralRewardLimitOption::load(&deps.storage).unwrap();
+    assert_eq!(option.overflow_mode, OverflowMode::Reuse);
+}
+
+#[test]
+fn failed_recipient_mode_without_recipient() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        governance_sender(),
+        Some(OverflowMode::Recipient),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    expect_generic_err(&result, "Overflow amount recipient is required");
+}
+
+#[test]
+fn failed_lp_staking_percent_without_contract() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        governance_sender(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(10),
+        None,
+        None,
+    );
+    expect_generic_err(&result, "LP staking contract is required");
+}
+
 #[test]
 fn failed_invalid_permission() {
     let mut deps = custom_deps();
@@ -87,6 +183,11 @@ fn failed_invalid_permission() {
         None,
         None,
         None,
+        None,
+        None,
+        None,
+        None,
+        None,
     );
     expect_unauthorized_err(&result);
 }
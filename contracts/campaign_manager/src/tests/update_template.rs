@@ -0,0 +1,100 @@
+use cosmwasm_std::{Env, MessageInfo, Response};
+use cosmwasm_std::testing::mock_info;
+
+use valkyrie::campaign_manager::execute_msgs::CampaignTemplateMsg;
+use valkyrie::common::ContractResult;
+use valkyrie::errors::ContractError;
+use valkyrie::mock_querier::{custom_deps, CustomDeps};
+use valkyrie::test_constants::default_sender;
+use valkyrie::test_constants::campaign_manager::campaign_manager_env;
+use valkyrie::test_constants::governance::governance_sender;
+use valkyrie::test_utils::expect_unauthorized_err;
+
+use crate::executions::update_template;
+use crate::states::CampaignTemplate;
+
+pub fn exec(
+    deps: &mut CustomDeps,
+    env: Env,
+    info: MessageInfo,
+    template_id: u64,
+    name: Option<String>,
+    template: Option<CampaignTemplateMsg>,
+) -> ContractResult<Response> {
+    update_template(deps.as_mut(), env, info, template_id, name, template)
+}
+
+#[test]
+fn succeed() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::create_template::default(&mut deps);
+
+    let template = CampaignTemplateMsg {
+        title: Some("Changed".to_string()),
+        ..CampaignTemplateMsg::default()
+    };
+
+    exec(
+        &mut deps,
+        campaign_manager_env(),
+        default_sender(),
+        1,
+        Some("ChangedName".to_string()),
+        Some(template.clone()),
+    ).unwrap();
+
+    let campaign_template = CampaignTemplate::load(&deps.storage, 1).unwrap();
+    assert_eq!(campaign_template.name, "ChangedName".to_string());
+    assert_eq!(campaign_template.template, template);
+
+    // governance can manage all templates
+    exec(
+        &mut deps,
+        campaign_manager_env(),
+        governance_sender(),
+        1,
+        Some("GovernanceName".to_string()),
+        None,
+    ).unwrap();
+
+    let campaign_template = CampaignTemplate::load(&deps.storage, 1).unwrap();
+    assert_eq!(campaign_template.name, "GovernanceName".to_string());
+    assert_eq!(campaign_template.template, template);
+}
+
+#[test]
+fn failed_invalid_permission() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+    super::create_template::default(&mut deps);
+
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        mock_info("Other", &[]),
+        1,
+        Some("ChangedName".to_string()),
+        None,
+    );
+    expect_unauthorized_err(&result);
+}
+
+#[test]
+fn failed_not_found() {
+    let mut deps = custom_deps();
+
+    super::instantiate::default(&mut deps);
+
+    let result = exec(
+        &mut deps,
+        campaign_manager_env(),
+        default_sender(),
+        1,
+        Some("ChangedName".to_string()),
+        None,
+    );
+    assert_eq!(result.unwrap_err(), ContractError::NotFound {});
+}
@@ -30,10 +30,12 @@ fn main() {
     export_schema(&schema_for!(ContractConfigResponse), &out_dir);
     export_schema(&schema_for!(StakingStateResponse), &out_dir);
     export_schema(&schema_for!(StakerStateResponse), &out_dir);
+    export_schema(&schema_for!(StakerStateAtResponse), &out_dir);
     export_schema(&schema_for!(PollConfigResponse), &out_dir);
     export_schema(&schema_for!(PollStateResponse), &out_dir);
     export_schema(&schema_for!(PollResponse), &out_dir);
     export_schema(&schema_for!(PollsResponse), &out_dir);
+    export_schema(&schema_for!(QueueResponse), &out_dir);
     export_schema(&schema_for!(PollCountResponse), &out_dir);
     export_schema(&schema_for!(VotersResponse), &out_dir);
     export_schema(&schema_for!(VotingPowerResponse), &out_dir);
@@ -1,6 +1,7 @@
-use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdError};
 
 use valkyrie::common::ContractResult;
+use valkyrie::errors::ContractError;
 use valkyrie::governance::execute_msgs::ContractConfigInitMsg;
 
 use super::states::ContractConfig;
@@ -18,7 +19,61 @@ pub fn instantiate(
     ContractConfig {
         address: env.contract.address,
         governance_token: deps.api.addr_validate(&msg.governance_token)?,
+        community: msg.community.map(|c| deps.api.addr_validate(c.as_str())).transpose()?,
+        guardian: msg.guardian.map(|g| deps.api.addr_validate(g.as_str())).transpose()?,
     }.save(deps.storage)?;
 
     Ok(response)
 }
+
+pub fn update_contract_config(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    community: Option<String>,
+    guardian: Option<String>,
+    remove_community: bool,
+    remove_guardian: bool,
+) -> ContractResult<Response> {
+    // Validate
+    if env.contract.address != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if remove_community && community.is_some() {
+        return Err(ContractError::Std(StdError::generic_err("Cannot set and remove community at once")));
+    }
+
+    if remove_guardian && guardian.is_some() {
+        return Err(ContractError::Std(StdError::generic_err("Cannot set and remove guardian at once")));
+    }
+
+    // Execute
+    let mut response = make_response("update_contract_config");
+
+    let mut config = ContractConfig::load(deps.storage)?;
+
+    if let Some(community) = community {
+        config.community = Some(deps.api.addr_validate(community.as_str())?);
+        response = response.add_attribute("is_updated_community", "true");
+    }
+
+    if remove_community {
+        config.community = None;
+        response = response.add_attribute("is_removed_community", "true");
+    }
+
+    if let Some(guardian) = guardian {
+        config.guardian = Some(deps.api.addr_validate(guardian.as_str())?);
+        response = response.add_attribute("is_updated_guardian", "true");
+    }
+
+    if remove_guardian {
+        config.guardian = None;
+        response = response.add_attribute("is_removed_guardian", "true");
+    }
+
+    config.save(deps.storage)?;
+
+    Ok(response)
+}
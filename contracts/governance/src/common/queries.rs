@@ -14,6 +14,8 @@ pub fn get_contract_config(
     Ok(
         ContractConfigResponse {
             governance_token: contract_config.governance_token.to_string(),
+            community: contract_config.community.map(|c| c.to_string()),
+            guardian: contract_config.guardian.map(|g| g.to_string()),
         }
     )
 }
\ No newline at end of file
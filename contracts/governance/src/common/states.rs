@@ -4,6 +4,7 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use valkyrie::cw20::query_cw20_balance;
 use crate::poll::states::PollState;
+use crate::staking::states::StakingState;
 
 
 const CONTRACT_CONFIG: Item<ContractConfig> = Item::new("contract-config");
@@ -12,6 +13,12 @@ const CONTRACT_CONFIG: Item<ContractConfig> = Item::new("contract-config");
 pub struct ContractConfig {
     pub address: Addr, // contract address
     pub governance_token: Addr,
+    // default for configs saved before poll categories
+    #[serde(default)]
+    pub community: Option<Addr>,
+    // default for configs saved before guardian veto
+    #[serde(default)]
+    pub guardian: Option<Addr>,
 }
 
 impl ContractConfig {
@@ -26,6 +33,14 @@ impl ContractConfig {
     pub fn is_governance_token(&self, address: &Addr) -> bool {
         self.governance_token.eq(address)
     }
+
+    pub fn is_community(&self, address: &Addr) -> bool {
+        self.community.as_ref() == Some(address)
+    }
+
+    pub fn is_guardian(&self, address: &Addr) -> bool {
+        self.guardian.as_ref() == Some(address)
+    }
 }
 
 pub fn load_available_balance(deps: Deps, _height: u64) -> StdResult<Uint128> {
@@ -36,7 +51,10 @@ pub fn load_available_balance(deps: Deps, _height: u64) -> StdResult<Uint128> {
         &contract_config.address,
     )?;
     let poll_state = PollState::load(deps.storage)?;
-    let available_balance = contract_balance.checked_sub(poll_state.total_deposit)?;
+    let staking_state = StakingState::load(deps.storage)?;
+    let available_balance = contract_balance
+        .checked_sub(poll_state.total_deposit)?
+        .checked_sub(staking_state.total_unbonding)?;
 
     Ok(available_balance)
 }
\ No newline at end of file
@@ -17,6 +17,8 @@ pub fn exec(
 ) -> ContractResult<Response> {
     let msg = ContractConfigInitMsg {
         governance_token,
+        community: None,
+        guardian: None,
     };
 
     // Execute
@@ -1 +1,2 @@
-pub mod instantiate;
\ No newline at end of file
+pub mod instantiate;
+pub mod update_contract_config;
\ No newline at end of file
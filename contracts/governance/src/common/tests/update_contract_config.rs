@@ -0,0 +1,126 @@
+use cosmwasm_std::{attr, Env, MessageInfo, Response};
+use cosmwasm_std::testing::mock_info;
+
+use valkyrie::common::ContractResult;
+use valkyrie::mock_querier::{custom_deps, CustomDeps};
+use valkyrie::test_constants::default_sender;
+use valkyrie::test_constants::governance::{governance_env, GOVERNANCE, GUARDIAN};
+use valkyrie::test_utils::{expect_generic_err, expect_unauthorized_err};
+
+use crate::common::executions::update_contract_config;
+use crate::common::states::ContractConfig;
+use crate::tests::init_default;
+
+pub fn exec(
+    deps: &mut CustomDeps,
+    env: Env,
+    info: MessageInfo,
+    community: Option<String>,
+    guardian: Option<String>,
+    remove_community: bool,
+    remove_guardian: bool,
+) -> ContractResult<Response> {
+    update_contract_config(deps.as_mut(), env, info, community, guardian, remove_community, remove_guardian)
+}
+
+pub fn will_success(
+    deps: &mut CustomDeps,
+    community: Option<String>,
+    guardian: Option<String>,
+    remove_community: bool,
+    remove_guardian: bool,
+) -> (Env, MessageInfo, Response) {
+    let env = governance_env();
+    let info = mock_info(GOVERNANCE, &[]);
+
+    let response = exec(deps, env.clone(), info.clone(), community, guardian, remove_community, remove_guardian).unwrap();
+
+    (env, info, response)
+}
+
+#[test]
+fn succeed() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    will_success(&mut deps, Some("NewCommunity".to_string()), Some(GUARDIAN.to_string()), false, false);
+
+    let config = ContractConfig::load(&deps.storage).unwrap();
+    assert_eq!(config.community.unwrap().as_str(), "NewCommunity");
+    assert_eq!(config.guardian.unwrap().as_str(), GUARDIAN);
+}
+
+#[test]
+fn succeed_remove() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    will_success(&mut deps, Some("NewCommunity".to_string()), Some(GUARDIAN.to_string()), false, false);
+
+    // unset fields are left unchanged
+    will_success(&mut deps, None, None, false, false);
+    let config = ContractConfig::load(&deps.storage).unwrap();
+    assert_eq!(config.guardian.unwrap().as_str(), GUARDIAN);
+
+    let (_, _, response) = will_success(&mut deps, None, None, false, true);
+    assert!(response.attributes.contains(&attr("is_removed_guardian", "true")));
+
+    let config = ContractConfig::load(&deps.storage).unwrap();
+    assert_eq!(config.community.unwrap().as_str(), "NewCommunity");
+    assert_eq!(config.guardian, None);
+
+    will_success(&mut deps, None, None, true, false);
+
+    let config = ContractConfig::load(&deps.storage).unwrap();
+    assert_eq!(config.community, None);
+}
+
+#[test]
+fn failed_set_and_remove() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    let result = exec(
+        &mut deps,
+        governance_env(),
+        mock_info(GOVERNANCE, &[]),
+        Some("NewCommunity".to_string()),
+        None,
+        true,
+        false,
+    );
+    expect_generic_err(&result, "Cannot set and remove community at once");
+
+    let result = exec(
+        &mut deps,
+        governance_env(),
+        mock_info(GOVERNANCE, &[]),
+        None,
+        Some(GUARDIAN.to_string()),
+        false,
+        true,
+    );
+    expect_generic_err(&result, "Cannot set and remove guardian at once");
+}
+
+#[test]
+fn failed_invalid_permission() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    let result = exec(
+        &mut deps,
+        governance_env(),
+        default_sender(),
+        Some("NewCommunity".to_string()),
+        None,
+        false,
+        false,
+    );
+
+    expect_unauthorized_err(&result);
+}
@@ -49,31 +49,61 @@ pub fn execute(
 ) -> ContractResult<Response> {
     match msg {
         ExecuteMsg::Receive(msg) => receive_cw20(deps, env, info, msg),
+        ExecuteMsg::UpdateContractConfig {
+            community,
+            guardian,
+            remove_community,
+            remove_guardian,
+        } => crate::common::executions::update_contract_config(
+            deps,
+            env,
+            info,
+            community,
+            guardian,
+            remove_community.unwrap_or(false),
+            remove_guardian.unwrap_or(false),
+        ),
         ExecuteMsg::UpdateStakingConfig {
             distributor,
+            unbonding_period,
+            max_lock_duration,
+            max_lock_multiplier,
         } => crate::staking::executions::update_staking_config(
             deps,
             env,
             info,
             distributor,
+            unbonding_period,
+            max_lock_duration,
+            max_lock_multiplier,
         ),
         ExecuteMsg::UpdatePollConfig {
+            category,
             quorum,
             threshold,
             voting_period,
             execution_delay_period,
             proposal_deposit,
             snapshot_period,
+            cancel_grace_period,
+            cancel_fee_rate,
+            refund_vetoed_deposit,
+            execution_retry_period,
         } => crate::poll::executions::update_poll_config(
             deps,
             env,
             info,
+            category,
             quorum,
             threshold,
             voting_period,
             execution_delay_period,
             proposal_deposit,
             snapshot_period,
+            cancel_grace_period,
+            cancel_fee_rate,
+            refund_vetoed_deposit,
+            execution_retry_period,
         ),
         ExecuteMsg::StakeGovernanceTokenHook {
             staker,
@@ -90,20 +120,45 @@ pub fn execute(
         } => crate::staking::executions::unstake_governance_token_hook(
             deps, env, info, staker, amount,
         ),
+        ExecuteMsg::ClaimUnbonded {} => crate::staking::executions::claim_unbonded(deps, env, info),
+        ExecuteMsg::LockStake {
+            amount,
+            duration,
+        } => crate::staking::executions::lock_stake(deps, env, info, amount, duration),
+        ExecuteMsg::Delegate {
+            to,
+        } => crate::staking::executions::delegate(deps, env, info, to),
+        ExecuteMsg::Undelegate {} => crate::staking::executions::undelegate(deps, env, info),
         ExecuteMsg::CastVote {
             poll_id,
             vote,
             amount,
         } => crate::poll::executions::cast_vote(deps, env, info, poll_id, vote, amount),
+        ExecuteMsg::CastWeightedVote {
+            poll_id,
+            votes,
+        } => crate::poll::executions::cast_weighted_vote(deps, env, info, poll_id, votes),
+        ExecuteMsg::RetractVote {
+            poll_id,
+        } => crate::poll::executions::retract_vote(deps, env, info, poll_id),
+        ExecuteMsg::CancelPoll {
+            poll_id,
+        } => crate::poll::executions::cancel_poll(deps, env, info, poll_id),
         ExecuteMsg::SnapshotPoll {
             poll_id,
         } => crate::poll::executions::snapshot_poll(deps, env, info, poll_id),
         ExecuteMsg::EndPoll {
             poll_id,
         } => crate::poll::executions::end_poll(deps, env, info, poll_id),
+        ExecuteMsg::VetoPoll {
+            poll_id,
+        } => crate::poll::executions::veto_poll(deps, env, info, poll_id),
         ExecuteMsg::ExecutePoll {
             poll_id,
         } => crate::poll::executions::execute_poll(deps, env, info, poll_id),
+        ExecuteMsg::RetryExecution {
+            poll_id,
+        } => crate::poll::executions::retry_execution(deps, env, info, poll_id),
         ExecuteMsg::RunExecution {
             executions,
         } => crate::poll::executions::run_execution(deps, env, info, executions),
@@ -128,7 +183,9 @@ pub fn receive_cw20(
             title,
             description,
             link,
+            category,
             executions,
+            independent_executions,
         } => crate::poll::executions::create_poll(
             deps,
             env,
@@ -138,7 +195,9 @@ pub fn receive_cw20(
             title,
             description,
             link,
+            category,
             executions,
+            independent_executions.unwrap_or(false),
         ),
     }
 }
@@ -146,7 +205,8 @@ pub fn receive_cw20(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> ContractResult<Response> {
     match msg.id {
-        crate::poll::executions::REPLY_EXECUTION => {
+        crate::poll::executions::REPLY_EXECUTION
+        | crate::poll::executions::REPLY_INDEPENDENT_EXECUTION => {
             crate::poll::executions::reply_execution(deps, env, msg)
         }
         _ => Err(ContractError::Std(StdError::not_found("reply_id"))),
@@ -164,7 +224,9 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> ContractResult<Binary> {
         QueryMsg::ContractConfig {} => {
             to_binary(&crate::common::queries::get_contract_config(deps, env)?)
         }
-        QueryMsg::PollConfig {} => to_binary(&crate::poll::queries::get_poll_config(deps, env)?),
+        QueryMsg::PollConfig {
+            category,
+        } => to_binary(&crate::poll::queries::get_poll_config(deps, env, category)?),
         QueryMsg::PollState {} => to_binary(&crate::poll::queries::get_poll_state(deps, env)?),
         QueryMsg::Poll { poll_id } => {
             to_binary(&crate::poll::queries::get_poll(deps, env, poll_id)?)
@@ -182,6 +244,17 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> ContractResult<Binary> {
             limit,
             order_by,
         )?),
+        QueryMsg::Queue {
+            start_after,
+            limit,
+            order_by,
+        } => to_binary(&crate::poll::queries::query_queue(
+            deps,
+            env,
+            start_after,
+            limit,
+            order_by,
+        )?),
         QueryMsg::Voters {
             poll_id,
             start_after,
@@ -204,6 +277,10 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> ContractResult<Binary> {
         QueryMsg::StakerState { address } => to_binary(&crate::staking::queries::get_staker_state(
             deps, env, address,
         )?),
+        QueryMsg::StakerStateAt {
+            address,
+            height,
+        } => to_binary(&crate::staking::queries::get_staker_state_at(deps, env, address, height)?),
         QueryMsg::AllStaker {
             start_after,
             limit,
@@ -1,17 +1,18 @@
-use cosmwasm_std::{Addr, Decimal, DepsMut, Env, MessageInfo, Reply, ReplyOn, Response, StdError, StdResult, SubMsg, Uint128};
+use cosmwasm_std::{Addr, Decimal, from_binary, DepsMut, Env, MessageInfo, Reply, ReplyOn, Response, StdError, StdResult, Storage, SubMsg, Uint128};
 
 use valkyrie::common::{ContractResult, Execution, ExecutionMsg};
 use valkyrie::errors::ContractError;
-use valkyrie::governance::enumerations::{PollStatus, VoteOption};
+use valkyrie::community::execute_msgs::ExecuteMsg as CommunityExecuteMsg;
+use valkyrie::governance::enumerations::{PollCategory, PollStatus, VoteOption};
 use valkyrie::governance::execute_msgs::{ExecuteMsg, PollConfigInitMsg};
 use valkyrie::message_factories;
 use valkyrie::utils::make_response;
 
 use crate::common::states::{ContractConfig, load_available_balance};
 use crate::poll::states::{PollExecutionContext, PollResult};
-use crate::staking::states::StakerState;
+use crate::staking::states::{StakerState, StakingState};
 
-use super::states::{get_poll_id, Poll, PollConfig, PollState};
+use super::states::{get_poll_id, Poll, PollConfig, PollState, VoteInfo};
 
 const MIN_TITLE_LENGTH: usize = 4;
 const MAX_TITLE_LENGTH: usize = 64;
@@ -29,6 +30,7 @@ pub fn instantiate(
     // Validate
     validate_quorum(msg.quorum)?;
     validate_threshold(msg.threshold)?;
+    validate_cancel_fee_rate(msg.cancel_fee_rate)?;
 
     // Execute
     let response = make_response("instantiate");
@@ -40,6 +42,10 @@ pub fn instantiate(
         execution_delay_period: msg.execution_delay_period,
         proposal_deposit: msg.proposal_deposit,
         snapshot_period: msg.snapshot_period,
+        cancel_grace_period: msg.cancel_grace_period,
+        cancel_fee_rate: msg.cancel_fee_rate,
+        refund_vetoed_deposit: msg.refund_vetoed_deposit,
+        execution_retry_period: msg.execution_retry_period,
     };
 
     let poll_state = PollState {
@@ -58,12 +64,17 @@ pub fn update_poll_config(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
+    category: Option<PollCategory>,
     quorum: Option<Decimal>,
     threshold: Option<Decimal>,
     voting_period: Option<u64>,
     execution_delay_period: Option<u64>,
     proposal_deposit: Option<Uint128>,
     snapshot_period: Option<u64>,
+    cancel_grace_period: Option<u64>,
+    cancel_fee_rate: Option<Decimal>,
+    refund_vetoed_deposit: Option<bool>,
+    execution_retry_period: Option<u64>,
 ) -> ContractResult<Response> {
     // Validate
     if env.contract.address != info.sender {
@@ -73,7 +84,7 @@ pub fn update_poll_config(
     // Execute
     let mut response = make_response("update_poll_config");
 
-    let mut poll_config = PollConfig::load(deps.storage)?;
+    let mut poll_config = PollConfig::load_category(deps.storage, category.as_ref())?;
 
     if let Some(quorum) = quorum {
         validate_quorum(quorum)?;
@@ -107,7 +118,34 @@ pub fn update_poll_config(
         response = response.add_attribute("is_updated_period", "true");
     }
 
-    poll_config.save(deps.storage)?;
+    if let Some(cancel_grace_period) = cancel_grace_period {
+        poll_config.cancel_grace_period = cancel_grace_period;
+        response = response.add_attribute("is_updated_cancel_grace_period", "true");
+    }
+
+    if let Some(cancel_fee_rate) = cancel_fee_rate {
+        validate_cancel_fee_rate(cancel_fee_rate)?;
+        poll_config.cancel_fee_rate = cancel_fee_rate;
+        response = response.add_attribute("is_updated_cancel_fee_rate", "true");
+    }
+
+    if let Some(refund_vetoed_deposit) = refund_vetoed_deposit {
+        poll_config.refund_vetoed_deposit = refund_vetoed_deposit;
+        response = response.add_attribute("is_updated_refund_vetoed_deposit", "true");
+    }
+
+    if let Some(execution_retry_period) = execution_retry_period {
+        poll_config.execution_retry_period = execution_retry_period;
+        response = response.add_attribute("is_updated_execution_retry_period", "true");
+    }
+
+    match category {
+        Some(category) => {
+            response = response.add_attribute("category", category.to_string());
+            poll_config.save_category(deps.storage, &category)?;
+        }
+        None => poll_config.save(deps.storage)?,
+    }
 
     Ok(response)
 }
@@ -122,7 +160,9 @@ pub fn create_poll(
     title: String,
     description: String,
     link: Option<String>,
+    category: Option<PollCategory>,
     executions: Vec<ExecutionMsg>,
+    independent_executions: bool,
 ) -> ContractResult<Response> {
     // Validate
     validate_title(&title)?;
@@ -134,7 +174,26 @@ pub fn create_poll(
         return Err(ContractError::Unauthorized {});
     }
 
-    let poll_config = PollConfig::load(deps.storage)?;
+    let executions = executions.iter()
+        .map(|execution| Execution::from(deps.api, execution))
+        .collect::<StdResult<Vec<Execution>>>()?;
+
+    let category = if is_treasury_spend(&config, &executions) {
+        Some(PollCategory::TreasurySpend)
+    } else {
+        category
+    };
+
+    if category == Some(PollCategory::Text) && !executions.is_empty() {
+        return Err(ContractError::Std(StdError::generic_err("Text poll cannot have executions")));
+    }
+
+    // Emergency polls run on a shorter period, so only the guardian can propose them
+    if category == Some(PollCategory::Emergency) && !config.is_guardian(&proposer) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let poll_config = PollConfig::load_category(deps.storage, category.as_ref())?;
     if deposit_amount < poll_config.proposal_deposit {
         return Err(ContractError::Std(StdError::generic_err(
             format!("Must deposit more than {} token", poll_config.proposal_deposit)
@@ -144,10 +203,6 @@ pub fn create_poll(
     // Execute
     let mut response = make_response("create_poll");
 
-    let executions = executions.iter()
-        .map(|execution| Execution::from(deps.api, execution))
-        .collect::<StdResult<Vec<Execution>>>()?;
-
     let mut poll = Poll {
         id: get_poll_id(deps.storage, &deposit_amount)?,
         creator: proposer.clone(),
@@ -164,17 +219,45 @@ pub fn create_poll(
         total_balance_at_end_poll: None,
         snapped_staked_amount: None,
         _status: None,
+        created_height: Some(env.block.height),
+        category,
+        deposit_held: false,
+        independent_executions,
+        executed_height: None,
+        execution_results: vec![],
+        boost_votes: Uint128::zero(),
     };
 
     poll.save_with_index(deps.storage)?;
 
+    // voting power is measured by the balance per share before the creation block,
+    // which is already checkpointed if staking has changed in this block
+    let voting_height = poll.voting_height(env.block.height);
+    if !StakingState::has_checkpoint_after(deps.storage, voting_height) {
+        let contract_available_balance = load_available_balance(deps.as_ref(), env.block.height)?;
+        StakingState::load(deps.storage)?.save_at(deps.storage, voting_height, contract_available_balance)?;
+    }
+
     response = response.add_attribute("creator", proposer.as_str());
     response = response.add_attribute("poll_id", poll.id.to_string());
     response = response.add_attribute("end_height", poll.end_height.to_string());
+    if let Some(category) = poll.category.as_ref() {
+        response = response.add_attribute("category", category.to_string());
+    }
 
     Ok(response)
 }
 
+fn is_treasury_spend(config: &ContractConfig, executions: &[Execution]) -> bool {
+    executions.iter().any(|execution| {
+        config.is_community(&execution.contract)
+            && matches!(
+                from_binary(&execution.msg),
+                Ok(CommunityExecuteMsg::Transfer { .. }) | Ok(CommunityExecuteMsg::IncreaseAllowance { .. })
+            )
+    })
+}
+
 pub fn cast_vote(
     deps: DepsMut,
     env: Env,
@@ -183,6 +266,79 @@ pub fn cast_vote(
     option: VoteOption,
     amount: Uint128,
 ) -> ContractResult<Response> {
+    // Execute
+    let mut response = make_response("cast_vote");
+
+    let (delegated_amount, previous_vote) = _cast_vote(deps, env, &info.sender, poll_id, vec![(option.clone(), amount)])?;
+
+    if let Some(previous_vote) = previous_vote {
+        response = response.add_attribute("previous_voter_option", previous_vote.option.to_string());
+        response = response.add_attribute("previous_amount", previous_vote.amount.to_string());
+    }
+
+    response = response.add_attribute("poll_id", &poll_id.to_string());
+    response = response.add_attribute("amount", &amount.to_string());
+    response = response.add_attribute("voter", info.sender.as_str());
+    response = response.add_attribute("voter_option", option.to_string());
+    response = response.add_attribute("delegated_amount", delegated_amount.to_string());
+
+    Ok(response)
+}
+
+pub fn cast_weighted_vote(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    poll_id: u64,
+    votes: Vec<(VoteOption, Uint128)>,
+) -> ContractResult<Response> {
+    // Validate
+    if votes.is_empty() {
+        return Err(ContractError::Std(StdError::generic_err("Votes must not be empty")));
+    }
+
+    for (index, (option, amount)) in votes.iter().enumerate() {
+        if amount.is_zero() {
+            return Err(ContractError::Std(StdError::generic_err("Vote amount must be greater than zero")));
+        }
+
+        if votes[..index].iter().any(|(prev_option, _)| prev_option == option) {
+            return Err(ContractError::Std(StdError::generic_err("Duplicated vote option")));
+        }
+    }
+
+    // Execute
+    let mut response = make_response("cast_weighted_vote");
+
+    let amount: Uint128 = votes.iter().map(|(_, amount)| *amount).sum();
+    let votes_attr = votes.iter()
+        .map(|(option, amount)| format!("{}:{}", option, amount))
+        .collect::<Vec<String>>()
+        .join(",");
+
+    let (delegated_amount, previous_vote) = _cast_vote(deps, env, &info.sender, poll_id, votes)?;
+
+    if let Some(previous_vote) = previous_vote {
+        response = response.add_attribute("previous_voter_option", previous_vote.option.to_string());
+        response = response.add_attribute("previous_amount", previous_vote.amount.to_string());
+    }
+
+    response = response.add_attribute("poll_id", poll_id.to_string());
+    response = response.add_attribute("amount", amount.to_string());
+    response = response.add_attribute("voter", info.sender.as_str());
+    response = response.add_attribute("votes", votes_attr);
+    response = response.add_attribute("delegated_amount", delegated_amount.to_string());
+
+    Ok(response)
+}
+
+fn _cast_vote(
+    deps: DepsMut,
+    env: Env,
+    voter: &Addr,
+    poll_id: u64,
+    votes: Vec<(VoteOption, Uint128)>,
+) -> ContractResult<(Uint128, Option<VoteInfo>)> {
     // Validate
     let poll_state = PollState::load(deps.storage)?;
 
@@ -196,31 +352,83 @@ pub fn cast_vote(
         return Err(ContractError::Std(StdError::generic_err("Poll is not in progress")));
     }
 
-    // Check the voter already has a vote on the poll
-    if poll.is_voted(deps.storage, &info.sender) {
-        return Err(ContractError::Std(StdError::generic_err("User has already voted.")));
-    }
-
     let contract_available_balance = load_available_balance(deps.as_ref(), env.block.height)?;
-    let mut staker_state = StakerState::load_safe(deps.storage, &info.sender)?;
+    let mut staker_state = StakerState::load_safe(deps.storage, voter)?;
 
-    if !staker_state.can_vote(deps.storage, contract_available_balance, amount)? {
+    let height = poll.voting_height(env.block.height);
+
+    // delegators who voted themselves are excluded from the delegated balance
+    let delegated_balance = staker_state.load_delegated_balance_at(deps.storage, contract_available_balance, height)?
+        .checked_sub(poll.load_delegation_override(deps.storage, voter)?)
+        .unwrap_or_default();
+
+    let amount: Uint128 = votes.iter().map(|(_, amount)| *amount).sum();
+    if !staker_state.can_vote(deps.storage, contract_available_balance, height, amount, delegated_balance)? {
         return Err(ContractError::Std(StdError::generic_err("User does not have enough staked tokens.")));
     }
 
     // Execute
-    let mut response = make_response("cast_vote");
-
-    poll.vote(deps.storage, &mut staker_state, option.clone(), amount)?;
+    // A new vote replaces the previous one while the poll is in progress
+    let previous_vote = poll.retract_vote(deps.storage, &mut staker_state, env.block.height)?;
+
+    // Voting by oneself overrides the delegate on this poll
+    let balance = staker_state.load_balance_at(deps.storage, contract_available_balance, height)?;
+    poll.override_delegation(deps.storage, &staker_state, balance, contract_available_balance, env.block.height)?;
+
+    // own voting balance includes the boost of the locked shares
+    let voting_balance = staker_state.load_voting_balance_at(deps.storage, contract_available_balance, height)?;
+    let delegated_amount = amount.checked_sub(voting_balance).unwrap_or_default();
+    // own votes over the unboosted balance are cast with the boost
+    let boost_amount = amount.checked_sub(delegated_amount)?
+        .checked_sub(balance)
+        .unwrap_or_default();
+    poll.vote(deps.storage, &mut staker_state, votes, delegated_amount, boost_amount)?;
     poll.snapshot_staked_amount(deps.storage, env.block.height, contract_available_balance).ok(); //snapshot 실패하더라도 무시
 
     poll.save(deps.storage)?;
     staker_state.save(deps.storage)?;
 
-    response = response.add_attribute("poll_id", &poll_id.to_string());
-    response = response.add_attribute("amount", &amount.to_string());
+    Ok((delegated_amount, previous_vote))
+}
+
+pub fn retract_vote(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    poll_id: u64,
+) -> ContractResult<Response> {
+    // Validate
+    let poll_state = PollState::load(deps.storage)?;
+
+    if poll_id == 0 || poll_state.poll_count < poll_id {
+        return Err(ContractError::Std(StdError::generic_err("Poll does not exist")));
+    }
+
+    let mut poll = Poll::load(deps.storage, &poll_id)?;
+
+    if !poll.in_progress(env.block.height) {
+        return Err(ContractError::Std(StdError::generic_err("Poll is not in progress")));
+    }
+
+    if !poll.is_voted(deps.storage, &info.sender) {
+        return Err(ContractError::Std(StdError::generic_err("User has not voted.")));
+    }
+
+    let mut staker_state = StakerState::load_safe(deps.storage, &info.sender)?;
+
+    // Execute
+    let mut response = make_response("retract_vote");
+
+    let vote = poll.retract_vote(deps.storage, &mut staker_state, env.block.height)?
+        .ok_or_else(|| StdError::generic_err("User has not voted."))?;
+
+    poll.save(deps.storage)?;
+    staker_state.save(deps.storage)?;
+
+    response = response.add_attribute("poll_id", poll_id.to_string());
     response = response.add_attribute("voter", info.sender.as_str());
-    response = response.add_attribute("voter_option", option.to_string());
+    response = response.add_attribute("voter_option", vote.option.to_string());
+    response = response.add_attribute("amount", vote.amount.to_string());
 
     Ok(response)
 }
@@ -256,8 +464,14 @@ pub fn end_poll(
         PollStatus::Rejected
     };
 
+    // Deposit of a passed poll is held during the timelock so the guardian can veto it
+    poll.deposit_held = poll_result == PollResult::Passed
+        && contract_config.guardian.is_some()
+        && !poll.executions.is_empty()
+        && !poll.deposit_amount.is_zero();
+
     // Refunds deposit only when quorum is reached
-    if poll_result != PollResult::QuorumNotReached && !poll.deposit_amount.is_zero() {
+    if poll_result != PollResult::QuorumNotReached && !poll.deposit_amount.is_zero() && !poll.deposit_held {
         response = response.add_message(
             message_factories::cw20_transfer(
                 &contract_config.governance_token,
@@ -272,8 +486,10 @@ pub fn end_poll(
     poll.save_with_index(deps.storage)?;
 
     // Decrease total deposit amount
-    poll_state.total_deposit = poll_state.total_deposit.checked_sub(poll.deposit_amount)?;
-    poll_state.save(deps.storage)?;
+    if !poll.deposit_held {
+        poll_state.total_deposit = poll_state.total_deposit.checked_sub(poll.deposit_amount)?;
+        poll_state.save(deps.storage)?;
+    }
 
     response = response.add_attribute("poll_id", poll_id.to_string());
     response = response.add_attribute("result", poll_result.to_string());
@@ -282,7 +498,139 @@ pub fn end_poll(
     Ok(response)
 }
 
+pub fn cancel_poll(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    poll_id: u64,
+) -> ContractResult<Response> {
+    // Validate
+    let mut poll = Poll::load(deps.storage, &poll_id)?;
+
+    if poll.creator != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if !poll.in_progress(env.block.height) {
+        return Err(ContractError::Std(StdError::generic_err("Poll is not in progress")));
+    }
+
+    let poll_config = poll.load_config(deps.storage)?;
+    if !poll.is_cancelable(env.block.height, poll_config.cancel_grace_period) {
+        return Err(ContractError::Std(StdError::generic_err("Poll cannot be cancelled after votes are cast")));
+    }
+
+    // Execute
+    let mut response = make_response("cancel_poll");
+
+    let contract_config = ContractConfig::load(deps.storage)?;
+    let mut poll_state = PollState::load(deps.storage)?;
+
+    // Cancel fee is charged only when the community contract is set
+    let fee_amount = match contract_config.community.as_ref() {
+        Some(community) => {
+            let fee_amount = poll.deposit_amount * poll_config.cancel_fee_rate;
+            if !fee_amount.is_zero() {
+                response = response.add_message(
+                    message_factories::cw20_transfer(
+                        &contract_config.governance_token,
+                        community,
+                        fee_amount,
+                    )
+                );
+            }
+            fee_amount
+        }
+        None => Uint128::zero(),
+    };
+
+    let refund_amount = poll.deposit_amount.checked_sub(fee_amount)?;
+    if !refund_amount.is_zero() {
+        response = response.add_message(
+            message_factories::cw20_transfer(
+                &contract_config.governance_token,
+                &poll.creator,
+                refund_amount,
+            )
+        );
+    }
+
+    // Voters' stakes are released since clean_votes drops polls not in progress
+    poll.status = PollStatus::Cancelled;
+    poll.save_with_index(deps.storage)?;
+
+    poll_state.total_deposit = poll_state.total_deposit.checked_sub(poll.deposit_amount)?;
+    poll_state.save(deps.storage)?;
+
+    response = response.add_attribute("poll_id", poll_id.to_string());
+    response = response.add_attribute("refund_amount", refund_amount.to_string());
+    response = response.add_attribute("fee_amount", fee_amount.to_string());
+
+    Ok(response)
+}
+
+pub fn veto_poll(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    poll_id: u64,
+) -> ContractResult<Response> {
+    // Validate
+    let contract_config = ContractConfig::load(deps.storage)?;
+    if !contract_config.is_guardian(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut poll = Poll::load(deps.storage, &poll_id)?;
+    let poll_config = poll.load_config(deps.storage)?;
+
+    if poll.status != PollStatus::Passed {
+        return Err(ContractError::Std(StdError::generic_err("Poll is not in passed status")));
+    }
+
+    if poll.end_height + poll_config.execution_delay_period <= env.block.height {
+        return Err(ContractError::Std(StdError::generic_err("Execution delay period has expired")));
+    }
+
+    // Execute
+    let mut response = make_response("veto_poll");
+
+    if poll.deposit_held {
+        // Deposit goes to the community when not refunded, or stays with the stakers without it
+        let recipient = if poll_config.refund_vetoed_deposit {
+            Some(&poll.creator)
+        } else {
+            contract_config.community.as_ref()
+        };
+
+        if let Some(recipient) = recipient {
+            response = response.add_message(
+                message_factories::cw20_transfer(
+                    &contract_config.governance_token,
+                    recipient,
+                    poll.deposit_amount,
+                )
+            );
+        }
+
+        let mut poll_state = PollState::load(deps.storage)?;
+        poll_state.total_deposit = poll_state.total_deposit.checked_sub(poll.deposit_amount)?;
+        poll_state.save(deps.storage)?;
+
+        poll.deposit_held = false;
+    }
+
+    poll.status = PollStatus::Vetoed;
+    poll.save_with_index(deps.storage)?;
+
+    response = response.add_attribute("poll_id", poll_id.to_string());
+    response = response.add_attribute("guardian", info.sender.as_str());
+
+    Ok(response)
+}
+
 pub const REPLY_EXECUTION: u64 = 1;
+pub const REPLY_INDEPENDENT_EXECUTION: u64 = 2;
 
 pub fn execute_poll(
     deps: DepsMut,
@@ -291,8 +639,8 @@ pub fn execute_poll(
     poll_id: u64,
 ) -> ContractResult<Response> {
     // Validate
-    let poll_config = PollConfig::load(deps.storage)?;
-    let poll = Poll::load(deps.storage, &poll_id)?;
+    let mut poll = Poll::load(deps.storage, &poll_id)?;
+    let poll_config = poll.load_config(deps.storage)?;
 
     if poll.status != PollStatus::Passed {
         return Err(ContractError::Std(StdError::generic_err("Poll is not in passed status")));
@@ -302,7 +650,7 @@ pub fn execute_poll(
         return Err(ContractError::Std(StdError::generic_err("Execution delay period has not expired")));
     }
 
-    let mut executions = poll.executions;
+    let executions = poll.executions.clone();
     if executions.is_empty() {
         return Err(ContractError::Std(StdError::generic_err("The poll does not have executions")));
     }
@@ -310,14 +658,96 @@ pub fn execute_poll(
     // Execute
     let mut response = make_response("execute_poll");
 
+    response = response.add_submessages(_run_executions(deps.storage, &env, &poll, executions)?);
+
+    // Refunds the deposit held during the timelock
+    if poll.deposit_held {
+        let contract_config = ContractConfig::load(deps.storage)?;
+        response = response.add_message(
+            message_factories::cw20_transfer(
+                &contract_config.governance_token,
+                &poll.creator,
+                poll.deposit_amount,
+            )
+        );
+
+        let mut poll_state = PollState::load(deps.storage)?;
+        poll_state.total_deposit = poll_state.total_deposit.checked_sub(poll.deposit_amount)?;
+        poll_state.save(deps.storage)?;
+
+        poll.deposit_held = false;
+    }
+
+    poll.executed_height = Some(env.block.height);
+    poll.save(deps.storage)?;
+
+    response = response.add_attribute("poll_id", poll_id.to_string());
+
+    Ok(response)
+}
+
+pub fn retry_execution(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    poll_id: u64,
+) -> ContractResult<Response> {
+    // Validate
+    let poll = Poll::load(deps.storage, &poll_id)?;
+    let poll_config = poll.load_config(deps.storage)?;
+
+    if poll.status != PollStatus::Failed {
+        return Err(ContractError::Std(StdError::generic_err("Poll is not in failed status")));
+    }
+
+    let is_expired = match poll.executed_height {
+        Some(executed_height) => executed_height + poll_config.execution_retry_period < env.block.height,
+        None => true,
+    };
+    if is_expired {
+        return Err(ContractError::Std(StdError::generic_err("Execution retry period has expired")));
+    }
+
+    let executions = poll.failed_executions();
+    if executions.is_empty() {
+        return Err(ContractError::Std(StdError::generic_err("The poll does not have failed executions")));
+    }
+
+    // Execute
+    let mut response = make_response("retry_execution");
+
+    response = response.add_attribute("poll_id", poll_id.to_string());
+    response = response.add_attribute("retry_count", executions.len().to_string());
+    response = response.add_submessages(_run_executions(deps.storage, &env, &poll, executions)?);
+
+    Ok(response)
+}
+
+fn _run_executions(
+    storage: &mut dyn Storage,
+    env: &Env,
+    poll: &Poll,
+    mut executions: Vec<Execution>,
+) -> StdResult<Vec<SubMsg>> {
     executions.sort();
 
     PollExecutionContext {
         poll_id: poll.id,
         execution_count: executions.len() as u64,
-    }.save(deps.storage)?;
+        pending_orders: executions.iter().map(|e| e.order).collect(),
+    }.save(storage)?;
+
+    // Each execution runs on its own, so a failing one does not revert the others
+    if poll.independent_executions {
+        return Ok(executions.iter().map(|execution| SubMsg {
+            id: REPLY_INDEPENDENT_EXECUTION,
+            msg: message_factories::wasm_execute_bin(&execution.contract, execution.msg.clone()),
+            gas_limit: None,
+            reply_on: ReplyOn::Always,
+        }).collect());
+    }
 
-    response = response.add_submessage(SubMsg {
+    Ok(vec![SubMsg {
         id: REPLY_EXECUTION,
         msg: message_factories::wasm_execute(
             &env.contract.address,
@@ -327,11 +757,7 @@ pub fn execute_poll(
         ),
         gas_limit: None,
         reply_on: ReplyOn::Always,
-    });
-
-    response = response.add_attribute("poll_id", poll_id.to_string());
-
-    Ok(response)
+    }])
 }
 
 pub fn run_execution(
@@ -368,26 +794,51 @@ pub fn reply_execution(
     msg: Reply,
 ) -> ContractResult<Response> {
     // Validate
-    let poll_execution_context = PollExecutionContext::load(deps.storage)?;
+    let mut poll_execution_context = PollExecutionContext::load(deps.storage)?;
     let mut poll = Poll::load(deps.storage, &poll_execution_context.poll_id)?;
 
-    if poll.status == PollStatus::Failed || poll.status == PollStatus::Executed {
+    if poll.status == PollStatus::Executed {
         return Err(ContractError::Std(StdError::generic_err("Already executed")));
     }
 
     // Execute
     let mut response = make_response("reply_execution");
 
-    poll.status = if msg.result.is_ok() {
-        PollStatus::Executed
+    // Independent executions reply one by one in order, a batch shares its result.
+    // A failed batch is reverted as a whole, so all of its executions are retried.
+    let orders = if msg.id == REPLY_INDEPENDENT_EXECUTION {
+        if poll_execution_context.pending_orders.is_empty() {
+            vec![]
+        } else {
+            vec![poll_execution_context.pending_orders.remove(0)]
+        }
     } else {
-        PollStatus::Failed
+        std::mem::take(&mut poll_execution_context.pending_orders)
     };
 
-    poll.save_with_index(deps.storage)?;
-    PollExecutionContext::clear(deps.storage);
+    let error = msg.result.into_result().err();
+    for order in orders.iter() {
+        poll.record_execution_result(*order, error.clone());
+    }
+
+    if poll_execution_context.pending_orders.is_empty() {
+        poll.status = if error.is_none() && poll.execution_results.iter().all(|r| r.is_success()) {
+            PollStatus::Executed
+        } else {
+            PollStatus::Failed
+        };
+
+        poll.save_with_index(deps.storage)?;
+        PollExecutionContext::clear(deps.storage);
+    } else {
+        poll.save(deps.storage)?;
+        poll_execution_context.save(deps.storage)?;
+    }
 
     response = response.add_attribute("poll_status", poll.status.to_string());
+    if let Some(error) = error {
+        response = response.add_attribute("error", error);
+    }
 
     Ok(response)
 }
@@ -432,6 +883,16 @@ fn validate_quorum(quorum: Decimal) -> StdResult<()> {
     }
 }
 
+// Validate_cancel_fee_rate returns an error if the cancel fee rate is invalid
+/// (we require 0-1)
+fn validate_cancel_fee_rate(cancel_fee_rate: Decimal) -> StdResult<()> {
+    if cancel_fee_rate > Decimal::one() {
+        Err(StdError::generic_err("cancel_fee_rate must be 0 to 1"))
+    } else {
+        Ok(())
+    }
+}
+
 // Validate_threshold returns an error if the threshold is invalid
 /// (we require 0-1)
 fn validate_threshold(threshold: Decimal) -> StdResult<()> {
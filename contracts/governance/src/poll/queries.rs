@@ -1,9 +1,9 @@
-use cosmwasm_std::{Deps, Env, StdError};
+use cosmwasm_std::{Deps, Env, StdError, StdResult};
 
 use valkyrie::common::{ContractResult, OrderBy};
 use valkyrie::errors::ContractError;
-use valkyrie::governance::enumerations::PollStatus;
-use valkyrie::governance::query_msgs::{PollConfigResponse, PollResponse, PollsResponse, PollStateResponse, VotersResponse};
+use valkyrie::governance::enumerations::{PollCategory, PollStatus};
+use valkyrie::governance::query_msgs::{PollConfigResponse, PollResponse, PollsResponse, PollStateResponse, QueuedPollResponse, QueueResponse, VotersResponse};
 
 use crate::poll::states::Poll;
 
@@ -13,8 +13,9 @@ use valkyrie::governance::models::VoteInfoMsg;
 pub fn get_poll_config(
     deps: Deps,
     _env: Env,
+    category: Option<PollCategory>,
 ) -> ContractResult<PollConfigResponse> {
-    let poll_config = PollConfig::load(deps.storage)?;
+    let poll_config = PollConfig::load_category(deps.storage, category.as_ref())?;
 
     Ok(
         PollConfigResponse {
@@ -24,6 +25,10 @@ pub fn get_poll_config(
             execution_delay_period: poll_config.execution_delay_period,
             proposal_deposit: poll_config.proposal_deposit,
             snapshot_period: poll_config.snapshot_period,
+            cancel_grace_period: poll_config.cancel_grace_period,
+            cancel_fee_rate: poll_config.cancel_fee_rate,
+            refund_vetoed_deposit: poll_config.refund_vetoed_deposit,
+            execution_retry_period: poll_config.execution_retry_period,
         }
     )
 }
@@ -72,6 +77,32 @@ pub fn query_polls(
     )
 }
 
+pub fn query_queue(
+    deps: Deps,
+    _env: Env,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+    order_by: Option<OrderBy>,
+) -> ContractResult<QueueResponse> {
+    let polls = Poll::query_queue(deps.storage, start_after, limit, order_by)?.iter()
+        .map(|poll| -> StdResult<QueuedPollResponse> {
+            let poll_config = poll.load_config(deps.storage)?;
+
+            Ok(QueuedPollResponse {
+                poll_id: poll.id,
+                executable_height: poll.end_height + poll_config.execution_delay_period,
+                executions: poll.to_response().executions,
+            })
+        })
+        .collect::<StdResult<Vec<QueuedPollResponse>>>()?;
+
+    Ok(
+        QueueResponse {
+            polls
+        }
+    )
+}
+
 pub fn query_voters(
     deps: Deps,
     _env: Env,
@@ -96,6 +127,8 @@ pub fn query_voters(
             voter: voter.to_string(),
             option: voter_info.option.clone(),
             amount: voter_info.amount,
+            delegated_amount: voter_info.delegated_amount,
+            votes: voter_info.weighted_votes(),
         }
     }).collect();
 
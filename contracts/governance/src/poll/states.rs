@@ -6,7 +6,8 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use valkyrie::common::{OrderBy, Execution, ExecutionMsg};
-use valkyrie::governance::enumerations::{PollStatus, VoteOption};
+use valkyrie::governance::enumerations::{PollCategory, PollStatus, VoteOption};
+use valkyrie::governance::models::ExecutionResultMsg;
 use valkyrie::governance::query_msgs::PollResponse;
 
 use crate::common::states::load_available_balance;
@@ -17,6 +18,7 @@ const DEFAULT_LIMIT: u32 = 10;
 
 
 const POLL_CONFIG: Item<PollConfig> = Item::new("poll-config");
+const POLL_CATEGORY_CONFIGS: Map<&[u8], PollConfig> = Map::new("poll-category-config");
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct PollConfig {
@@ -26,6 +28,17 @@ pub struct PollConfig {
     pub execution_delay_period: u64,
     pub proposal_deposit: Uint128,
     pub snapshot_period: u64,
+    // default for configs saved before poll cancellation
+    #[serde(default)]
+    pub cancel_grace_period: u64,
+    #[serde(default)]
+    pub cancel_fee_rate: Decimal,
+    // default for configs saved before guardian veto
+    #[serde(default)]
+    pub refund_vetoed_deposit: bool,
+    // default for configs saved before execution retries
+    #[serde(default)]
+    pub execution_retry_period: u64,
 }
 
 impl PollConfig {
@@ -36,6 +49,21 @@ impl PollConfig {
     pub fn load(storage: &dyn Storage) -> StdResult<PollConfig> {
         POLL_CONFIG.load(storage)
     }
+
+    pub fn save_category(&self, storage: &mut dyn Storage, category: &PollCategory) -> StdResult<()> {
+        POLL_CATEGORY_CONFIGS.save(storage, category.to_string().as_bytes(), self)
+    }
+
+    /// Falls back to the base config when the category has no config of its own
+    pub fn load_category(storage: &dyn Storage, category: Option<&PollCategory>) -> StdResult<PollConfig> {
+        match category {
+            Some(category) => match POLL_CATEGORY_CONFIGS.may_load(storage, category.to_string().as_bytes())? {
+                Some(config) => Ok(config),
+                None => PollConfig::load(storage),
+            },
+            None => PollConfig::load(storage),
+        }
+    }
 }
 
 
@@ -72,6 +100,10 @@ pub fn get_poll_id(storage: &mut dyn Storage, deposit_amount: &Uint128) -> StdRe
 const POLLS: Map<&[u8], Poll> = Map::new("poll");
 const POLL_STATUS_INDEX: Map<(&[u8], &[u8]), bool> = Map::new("poll-status-index");
 const POLL_VOTERS: Map<(&[u8], &[u8]), VoteInfo> = Map::new("poll-voter");
+// (poll, delegate) => balance of delegators who voted themselves
+const POLL_DELEGATE_OVERRIDES: Map<(&[u8], &[u8]), Uint128> = Map::new("poll-delegate-override");
+// (poll, delegator) => balance taken back from the delegate
+const POLL_DELEGATOR_OVERRIDES: Map<(&[u8], &[u8]), Uint128> = Map::new("poll-delegator-override");
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct Poll {
@@ -92,6 +124,25 @@ pub struct Poll {
     pub total_balance_at_end_poll: Option<Uint128>,
 
     pub _status: Option<PollStatus>,
+    // default for polls created before share checkpoints
+    #[serde(default)]
+    pub created_height: Option<u64>,
+    // default for polls created before poll categories
+    #[serde(default)]
+    pub category: Option<PollCategory>,
+    /// Deposit of a passed poll kept until it is executed or vetoed
+    #[serde(default)]
+    pub deposit_held: bool,
+    // default for polls created before execution results
+    #[serde(default)]
+    pub independent_executions: bool,
+    #[serde(default)]
+    pub executed_height: Option<u64>,
+    #[serde(default)]
+    pub execution_results: Vec<ExecutionResult>,
+    // default for polls created before lock boost
+    #[serde(default)]
+    pub boost_votes: Uint128, // part of the votes cast with the lock boost, excluded from the quorum
 }
 
 impl Poll {
@@ -156,6 +207,31 @@ impl Poll {
         }
     }
 
+    /// Passed polls with executions, filtered before the limit so pages are filled.
+    pub fn query_queue(
+        storage: &dyn Storage,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+        order_by: Option<OrderBy>,
+    ) -> StdResult<Vec<Poll>> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start_after = start_after.map(|v| Bound::exclusive(v.to_be_bytes()));
+        let (min, max, order_by) = match order_by {
+            Some(OrderBy::Asc) => (start_after, None, OrderBy::Asc),
+            _ => (None, start_after, OrderBy::Desc),
+        };
+
+        POLL_STATUS_INDEX.prefix(PollStatus::Passed.to_string().as_bytes())
+            .range(storage, min, max, order_by.into())
+            .map(|item| {
+                let (k, _) = item?;
+                POLLS.load(storage, k.as_slice())
+            })
+            .filter(|poll| poll.as_ref().map_or(true, |poll| !poll.executions.is_empty()))
+            .take(limit)
+            .collect()
+    }
+
     pub fn read_voters<'a>(
         storage: &'a dyn Storage,
         poll_id: &u64,
@@ -193,28 +269,168 @@ impl Poll {
         self.load_voter(storage, address).is_ok()
     }
 
-    pub fn vote(&mut self, storage: &mut dyn Storage, staker_state: &mut StakerState, vote_option: VoteOption, amount: Uint128) -> StdResult<()> {
-        let vote = VoteInfo {
-            voter: staker_state.address.clone(),
-            option: vote_option,
-            amount,
+    pub fn vote(
+        &mut self,
+        storage: &mut dyn Storage,
+        staker_state: &mut StakerState,
+        votes: Vec<(VoteOption, Uint128)>,
+        delegated_amount: Uint128,
+        boost_amount: Uint128,
+    ) -> StdResult<()> {
+        let vote = VoteInfo::new(staker_state.address.clone(), votes, delegated_amount, boost_amount);
+
+        for (option, amount) in vote.weighted_votes() {
+            self.add_votes(&option, amount);
+        }
+        self.boost_votes += vote.boost_amount;
+
+        POLL_VOTERS.save(storage, (&self.id.to_be_bytes(), vote.voter.as_str().as_bytes()), &vote)?;
+
+        staker_state.vote(self.id, vote);
+
+        Ok(())
+    }
+
+    /// Removes the voter's vote from the tallies and returns it, if any.
+    pub fn retract_vote(&mut self, storage: &mut dyn Storage, staker_state: &mut StakerState, block_height: u64) -> StdResult<Option<VoteInfo>> {
+        let vote = match POLL_VOTERS.may_load(storage, (&self.id.to_be_bytes(), staker_state.address.as_str().as_bytes()))? {
+            Some(vote) => vote,
+            None => return Ok(None),
         };
 
-        match vote.option {
+        for (option, amount) in vote.weighted_votes() {
+            self.sub_votes(&option, amount)?;
+        }
+        self.boost_votes = self.boost_votes.checked_sub(vote.boost_amount)?;
+
+        POLL_VOTERS.remove(storage, (&self.id.to_be_bytes(), staker_state.address.as_str().as_bytes()));
+
+        staker_state.retract_vote(self.id);
+
+        let poll_id = self.id.to_be_bytes();
+        let delegator_key: (&[u8], &[u8]) = (&poll_id, staker_state.address.as_str().as_bytes());
+        if let (Some(overridden), Some(delegate)) = (
+            POLL_DELEGATOR_OVERRIDES.may_load(storage, delegator_key)?,
+            staker_state.load_checkpoint_at(storage, self.voting_height(block_height))?.delegate.as_ref(),
+        ) {
+            POLL_DELEGATOR_OVERRIDES.remove(storage, delegator_key);
+
+            let delegate_overridden = self.load_delegation_override(storage, delegate)?
+                .checked_sub(overridden)?;
+            POLL_DELEGATE_OVERRIDES.save(storage, (&poll_id, delegate.as_str().as_bytes()), &delegate_overridden)?;
+        }
+
+        Ok(Some(vote))
+    }
+
+    fn add_votes(&mut self, option: &VoteOption, amount: Uint128) {
+        match option {
             VoteOption::Yes => self.yes_votes += amount,
             VoteOption::No => self.no_votes += amount,
             VoteOption::Abstain => self.abstain_votes += amount,
         }
+    }
 
-        POLL_VOTERS.save(storage, (&self.id.to_be_bytes(), vote.voter.as_str().as_bytes()), &vote)?;
-
-        staker_state.vote(self.id, vote);
+    fn sub_votes(&mut self, option: &VoteOption, amount: Uint128) -> StdResult<()> {
+        match option {
+            VoteOption::Yes => self.yes_votes = self.yes_votes.checked_sub(amount)?,
+            VoteOption::No => self.no_votes = self.no_votes.checked_sub(amount)?,
+            VoteOption::Abstain => self.abstain_votes = self.abstain_votes.checked_sub(amount)?,
+        }
 
         Ok(())
     }
 
+    pub fn load_config(&self, storage: &dyn Storage) -> StdResult<PollConfig> {
+        PollConfig::load_category(storage, self.category.as_ref())
+    }
+
+    pub fn is_cancelable(&self, block_height: u64, cancel_grace_period: u64) -> bool {
+        if self.get_vote_amount().is_zero() {
+            return true;
+        }
+
+        self.created_height
+            .map(|created_height| block_height <= created_height + cancel_grace_period)
+            .unwrap_or(false)
+    }
+
+    pub fn record_execution_result(&mut self, order: u64, error: Option<String>) {
+        self.execution_results.retain(|result| result.order != order);
+        self.execution_results.push(ExecutionResult { order, error });
+        self.execution_results.sort_by_key(|result| result.order);
+    }
+
+    pub fn failed_executions(&self) -> Vec<Execution> {
+        self.executions.iter()
+            .filter(|execution| self.execution_results.iter()
+                .any(|result| result.order == execution.order && !result.is_success()))
+            .cloned()
+            .collect()
+    }
+
+    /// Voting power is measured at the height before the creation,
+    /// so stakes in the creation block are not counted.
+    /// Polls created before share checkpoints are measured at the current height.
+    pub fn voting_height(&self, block_height: u64) -> u64 {
+        self.created_height.map(|created_height| created_height.saturating_sub(1)).unwrap_or(block_height)
+    }
+
+    pub fn load_delegation_override(&self, storage: &dyn Storage, delegate: &Addr) -> StdResult<Uint128> {
+        Ok(POLL_DELEGATE_OVERRIDES.may_load(storage, (&self.id.to_be_bytes(), delegate.as_str().as_bytes()))?
+            .unwrap_or_default())
+    }
+
+    /// Takes the delegator's balance back from its delegate on this poll.
+    /// The delegate's vote is reduced when it no longer fits the remaining delegated balance.
+    pub fn override_delegation(
+        &mut self,
+        storage: &mut dyn Storage,
+        delegator: &StakerState,
+        balance: Uint128,
+        contract_available_balance: Uint128,
+        block_height: u64,
+    ) -> StdResult<()> {
+        let height = self.voting_height(block_height);
+        let delegate = match delegator.load_checkpoint_at(storage, height)?.delegate {
+            Some(delegate) => delegate,
+            None => return Ok(()),
+        };
+
+        let poll_id = self.id.to_be_bytes();
+        POLL_DELEGATOR_OVERRIDES.save(storage, (&poll_id, delegator.address.as_str().as_bytes()), &balance)?;
+
+        let overridden = self.load_delegation_override(storage, &delegate)? + balance;
+        POLL_DELEGATE_OVERRIDES.save(storage, (&poll_id, delegate.as_str().as_bytes()), &overridden)?;
+
+        let mut vote = match POLL_VOTERS.may_load(storage, (&poll_id, delegate.as_str().as_bytes()))? {
+            Some(vote) => vote,
+            None => return Ok(()),
+        };
+
+        let mut delegate_state = StakerState::load(storage, &delegate)?;
+        let remain_delegated_balance = delegate_state.load_delegated_balance_at(storage, contract_available_balance, height)?
+            .checked_sub(overridden)
+            .unwrap_or_default();
+        if vote.delegated_amount <= remain_delegated_balance {
+            return Ok(());
+        }
+
+        let reduced_amount = vote.delegated_amount.checked_sub(remain_delegated_balance)?;
+        for (option, amount) in vote.reduce(reduced_amount)? {
+            self.sub_votes(&option, amount)?;
+        }
+        vote.delegated_amount = remain_delegated_balance;
+
+        POLL_VOTERS.save(storage, (&poll_id, delegate.as_str().as_bytes()), &vote)?;
+
+        delegate_state.retract_vote(self.id);
+        delegate_state.vote(self.id, vote);
+        delegate_state.save(storage)
+    }
+
     pub fn snapshot_staked_amount(&mut self, storage: &dyn Storage, block_height: u64, contract_available_balance: Uint128) -> StdResult<Uint128> {
-        let poll_config = PollConfig::load(storage)?;
+        let poll_config = self.load_config(storage)?;
 
         let remain_to_end: i128 = self.end_height as i128 - block_height as i128;
         if remain_to_end >= poll_config.snapshot_period as i128 {
@@ -225,31 +441,47 @@ impl Poll {
             return Err(StdError::generic_err("Snapshot has already occurred"));
         }
 
-        self.snapped_staked_amount = Some(contract_available_balance);
+        let staked_amount = self.load_staked_amount(storage, block_height, contract_available_balance)?;
+        self.snapped_staked_amount = Some(staked_amount);
 
-        Ok(contract_available_balance)
+        Ok(staked_amount)
+    }
+
+    /// Staked balance at the voting height, which voting power is measured against.
+    /// Polls created before checkpoints use the current balance.
+    pub fn load_staked_amount(&self, storage: &dyn Storage, block_height: u64, contract_available_balance: Uint128) -> StdResult<Uint128> {
+        if self.created_height.is_none() {
+            return Ok(contract_available_balance);
+        }
+
+        Ok(StakingState::load_checkpoint_at(storage, self.voting_height(block_height), contract_available_balance)?.available_balance)
     }
 
     pub fn get_vote_amount(&self) -> Uint128 {
         self.yes_votes + self.no_votes + self.abstain_votes
     }
 
+    /// Votes without the lock boost, which is not part of the staked amount.
+    pub fn get_quorum_vote_amount(&self) -> Uint128 {
+        self.get_vote_amount().checked_sub(self.boost_votes).unwrap_or_default()
+    }
+
     pub fn calculate_quorum(&self, deps: Deps, height: u64) -> StdResult<(Decimal, Uint128)> {
         let snapped_staked_amount = self.snapped_staked_amount.unwrap_or(Uint128::zero());
         let staked_amount = if snapped_staked_amount.is_zero() {
-            load_available_balance(deps, height)?
+            self.load_staked_amount(deps.storage, height, load_available_balance(deps, height)?)?
         } else {
             snapped_staked_amount
         };
 
         Ok((
-            Decimal::from_ratio(self.get_vote_amount(), staked_amount),
+            Decimal::from_ratio(self.get_quorum_vote_amount(), staked_amount),
             staked_amount,
         ))
     }
 
     pub fn get_result(&self, deps: Deps, height: u64) -> StdResult<(PollResult, Uint128)> {
-        let poll_config = PollConfig::load(deps.storage)?;
+        let poll_config = self.load_config(deps.storage)?;
         let staking_state = StakingState::load(deps.storage)?;
 
         let votes = self.get_vote_amount();
@@ -293,8 +525,16 @@ impl Poll {
             abstain_votes: self.abstain_votes,
             end_height: self.end_height,
             status: self.status.clone(),
+            category: self.category.clone(),
             staked_amount: self.snapped_staked_amount,
             total_balance_at_end_poll: self.total_balance_at_end_poll,
+            independent_executions: self.independent_executions,
+            executed_height: self.executed_height,
+            execution_results: self.execution_results.iter().map(|result| ExecutionResultMsg {
+                order: result.order,
+                success: result.is_success(),
+                error: result.error.clone(),
+            }).collect(),
         }
     }
 }
@@ -302,10 +542,25 @@ impl Poll {
 
 const POLL_EXECUTION_TEMP: Item<PollExecutionContext> = Item::new("poll-execution-context");
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ExecutionResult {
+    pub order: u64,
+    pub error: Option<String>, // none when succeeded
+}
+
+impl ExecutionResult {
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct PollExecutionContext {
     pub poll_id: u64,
     pub execution_count: u64,
+    // default for contexts saved before execution results
+    #[serde(default)]
+    pub pending_orders: Vec<u64>, // orders waiting for the reply
 }
 
 impl PollExecutionContext {
@@ -332,6 +587,74 @@ pub struct VoteInfo {
     pub voter: Addr,
     pub option: VoteOption,
     pub amount: Uint128,
+    // default for votes saved before delegation
+    #[serde(default)]
+    pub delegated_amount: Uint128, // part of amount voted with delegated balance
+    // default for votes saved before weighted votes
+    #[serde(default)]
+    pub split_votes: Vec<(VoteOption, Uint128)>, // empty when voted for a single option
+    // default for votes saved before lock boost
+    #[serde(default)]
+    pub boost_amount: Uint128, // part of amount voted with the lock boost
+}
+
+impl VoteInfo {
+    /// The option with the largest amount becomes the representative `option` of a split vote.
+    pub fn new(voter: Addr, votes: Vec<(VoteOption, Uint128)>, delegated_amount: Uint128, boost_amount: Uint128) -> VoteInfo {
+        let amount = votes.iter().map(|(_, amount)| *amount).sum();
+        let option = votes.iter()
+            .rev()
+            .max_by_key(|(_, amount)| *amount)
+            .map(|(option, _)| option.clone())
+            .unwrap_or(VoteOption::Abstain);
+
+        VoteInfo {
+            voter,
+            option,
+            amount,
+            delegated_amount,
+            split_votes: if votes.len() > 1 { votes } else { vec![] },
+            boost_amount,
+        }
+    }
+
+    pub fn weighted_votes(&self) -> Vec<(VoteOption, Uint128)> {
+        if self.split_votes.is_empty() {
+            vec![(self.option.clone(), self.amount)]
+        } else {
+            self.split_votes.clone()
+        }
+    }
+
+    /// Reduces the vote keeping the ratio between options and returns the reduced amount per option.
+    pub fn reduce(&mut self, reduced_amount: Uint128) -> StdResult<Vec<(VoteOption, Uint128)>> {
+        let remain_amount = self.amount.checked_sub(reduced_amount)?;
+
+        if self.split_votes.is_empty() {
+            self.amount = remain_amount;
+            return Ok(vec![(self.option.clone(), reduced_amount)]);
+        }
+
+        let mut remain_votes: Vec<(VoteOption, Uint128)> = self.split_votes.iter()
+            .map(|(option, amount)| (option.clone(), amount.multiply_ratio(remain_amount, self.amount)))
+            .collect();
+
+        // rounding remainder goes to the representative option
+        let remainder = remain_amount.checked_sub(remain_votes.iter().map(|(_, amount)| *amount).sum())?;
+        if let Some(vote) = remain_votes.iter_mut().find(|(option, _)| *option == self.option) {
+            vote.1 += remainder;
+        }
+
+        let reduced_votes = self.split_votes.iter()
+            .zip(remain_votes.iter())
+            .map(|((option, amount), (_, remain))| (option.clone(), amount.checked_sub(*remain).unwrap_or_default()))
+            .collect();
+
+        self.amount = remain_amount;
+        self.split_votes = remain_votes;
+
+        Ok(reduced_votes)
+    }
 }
 
 #[derive(PartialEq)]
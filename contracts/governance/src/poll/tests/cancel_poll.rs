@@ -0,0 +1,160 @@
+use cosmwasm_std::{CosmosMsg, Decimal, Env, MessageInfo, Response, SubMsg, to_binary, Uint128, WasmMsg};
+use cosmwasm_std::testing::mock_info;
+use cw20::Cw20ExecuteMsg;
+
+use valkyrie::common::ContractResult;
+use valkyrie::governance::enumerations::{PollStatus, VoteOption};
+use valkyrie::message_matchers;
+use valkyrie::mock_querier::{custom_deps, CustomDeps};
+use valkyrie::test_constants::community::COMMUNITY;
+use valkyrie::test_constants::governance::*;
+use valkyrie::test_utils::{expect_generic_err, expect_unauthorized_err};
+
+use crate::poll::executions::cancel_poll;
+use crate::poll::states::{Poll, PollState};
+use crate::poll::tests::cast_vote::VOTER1;
+use crate::poll::tests::create_poll::PROPOSER1;
+use crate::tests::init_default;
+
+pub fn exec(deps: &mut CustomDeps, env: Env, info: MessageInfo, poll_id: u64) -> ContractResult<Response> {
+    let response = cancel_poll(deps.as_mut(), env, info, poll_id)?;
+
+    for msg in message_matchers::cw20_transfer(&response.messages) {
+        deps.querier.minus_token_balances(&[(
+            &msg.contract_addr,
+            &[(GOVERNANCE, &msg.amount)],
+        )]);
+        deps.querier.plus_token_balances(&[(
+            &msg.contract_addr,
+            &[(&msg.recipient, &msg.amount)],
+        )]);
+    }
+
+    Ok(response)
+}
+
+pub fn will_success(deps: &mut CustomDeps, poll_id: u64) -> (Env, MessageInfo, Response) {
+    let env = governance_env();
+    let info = mock_info(PROPOSER1, &[]);
+
+    let response = exec(deps, env.clone(), info.clone(), poll_id).unwrap();
+
+    (env, info, response)
+}
+
+#[test]
+fn succeed() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    super::create_poll::default(&mut deps);
+
+    let poll_id = 1u64;
+    let (_, _, response) = will_success(&mut deps, poll_id);
+
+    let fee_amount = POLL_PROPOSAL_DEPOSIT * Decimal::percent(POLL_CANCEL_FEE_PERCENT);
+    assert_eq!(response.messages, vec![
+        SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: GOVERNANCE_TOKEN.to_string(),
+            funds: vec![],
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: COMMUNITY.to_string(),
+                amount: fee_amount,
+            }).unwrap(),
+        })),
+        SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: GOVERNANCE_TOKEN.to_string(),
+            funds: vec![],
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: PROPOSER1.to_string(),
+                amount: POLL_PROPOSAL_DEPOSIT.checked_sub(fee_amount).unwrap(),
+            }).unwrap(),
+        })),
+    ]);
+
+    let poll = Poll::load(&deps.storage, &poll_id).unwrap();
+    assert_eq!(poll.status, PollStatus::Cancelled);
+
+    let polls = Poll::query(&deps.storage, Some(PollStatus::Cancelled), None, None, None).unwrap();
+    assert_eq!(polls.len(), 1);
+    let polls = Poll::query(&deps.storage, Some(PollStatus::InProgress), None, None, None).unwrap();
+    assert!(polls.is_empty());
+
+    let poll_state = PollState::load(&deps.storage).unwrap();
+    assert_eq!(poll_state.total_deposit, Uint128::zero());
+}
+
+#[test]
+fn succeed_with_votes_in_grace_period() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    crate::staking::tests::stake_governance_token_hook::will_success(&mut deps, VOTER1, Uint128::new(100));
+    let (env, _, _) = super::create_poll::default(&mut deps);
+
+    let poll_id = 1u64;
+    super::cast_vote::will_success(&mut deps, VOTER1, poll_id, VoteOption::Yes, Uint128::new(100));
+
+    exec(
+        &mut deps,
+        governance_env_height(env.block.height + POLL_CANCEL_GRACE_PERIOD),
+        mock_info(PROPOSER1, &[]),
+        poll_id,
+    ).unwrap();
+
+    let poll = Poll::load(&deps.storage, &poll_id).unwrap();
+    assert_eq!(poll.status, PollStatus::Cancelled);
+
+    // voter's stake is no longer locked by the cancelled poll
+    crate::staking::tests::unstake_governance_token_hook::will_success(&mut deps, VOTER1, None);
+}
+
+#[test]
+fn failed_with_votes_after_grace_period() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    crate::staking::tests::stake_governance_token_hook::will_success(&mut deps, VOTER1, Uint128::new(100));
+    let (env, _, _) = super::create_poll::default(&mut deps);
+
+    let poll_id = 1u64;
+    super::cast_vote::will_success(&mut deps, VOTER1, poll_id, VoteOption::Yes, Uint128::new(100));
+
+    let result = exec(
+        &mut deps,
+        governance_env_height(env.block.height + POLL_CANCEL_GRACE_PERIOD + 1),
+        mock_info(PROPOSER1, &[]),
+        poll_id,
+    );
+    expect_generic_err(&result, "Poll cannot be cancelled after votes are cast");
+}
+
+#[test]
+fn failed_not_in_progress() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    super::create_poll::default(&mut deps);
+
+    let poll_id = 1u64;
+    will_success(&mut deps, poll_id);
+
+    let result = exec(&mut deps, governance_env(), mock_info(PROPOSER1, &[]), poll_id);
+    expect_generic_err(&result, "Poll is not in progress");
+}
+
+#[test]
+fn failed_invalid_permission() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    super::create_poll::default(&mut deps);
+
+    let result = exec(&mut deps, governance_env(), mock_info(VOTER1, &[]), 1u64);
+    expect_unauthorized_err(&result);
+}
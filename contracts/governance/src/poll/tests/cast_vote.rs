@@ -1,10 +1,10 @@
-use cosmwasm_std::{Addr, Env, MessageInfo, Response, Uint128};
+use cosmwasm_std::{Addr, attr, Env, MessageInfo, Response, Uint128};
 use cosmwasm_std::testing::mock_info;
 
 use valkyrie::common::ContractResult;
 use valkyrie::governance::enumerations::VoteOption;
 use valkyrie::mock_querier::{custom_deps, CustomDeps};
-use valkyrie::test_constants::governance::governance_env;
+use valkyrie::test_constants::governance::{governance_env, governance_env_height};
 use valkyrie::test_utils::expect_generic_err;
 
 use crate::poll::executions::cast_vote;
@@ -76,6 +76,9 @@ fn succeed() {
         voter: voter_addr.clone(),
         option: vote_option.clone(),
         amount: vote_amount,
+        delegated_amount: Uint128::zero(),
+        split_votes: vec![],
+        boost_amount: Uint128::zero(),
     };
 
     let staker_state = StakerState::load(&deps.storage, &voter_addr).unwrap();
@@ -135,7 +138,7 @@ fn failed_cast_vote_without_poll() {
 }
 
 #[test]
-fn failed_cast_vote_twice() {
+fn succeed_revise_vote() {
     let mut deps = custom_deps();
 
     init_default(deps.as_mut());
@@ -143,15 +146,55 @@ fn failed_cast_vote_twice() {
     super::create_poll::default(&mut deps);
     crate::staking::tests::stake_governance_token_hook::will_success(&mut deps, VOTER1, Uint128::new(100));
 
+    let voter_addr = Addr::unchecked(VOTER1);
     let poll_id = 1u64;
 
-    will_success(
-        &mut deps,
-        VOTER1,
-        poll_id,
-        VoteOption::Yes,
-        Uint128::new(10),
-    );
+    will_success(&mut deps, VOTER1, poll_id, VoteOption::Yes, Uint128::new(10));
+    let (_, _, response) = will_success(&mut deps, VOTER1, poll_id, VoteOption::No, Uint128::new(30));
+
+    assert!(response.attributes.contains(&attr("previous_voter_option", "yes")));
+    assert!(response.attributes.contains(&attr("previous_amount", "10")));
+
+    let vote_info = VoteInfo {
+        voter: voter_addr.clone(),
+        option: VoteOption::No,
+        amount: Uint128::new(30),
+        delegated_amount: Uint128::zero(),
+        split_votes: vec![],
+        boost_amount: Uint128::zero(),
+    };
+
+    let staker_state = StakerState::load(&deps.storage, &voter_addr).unwrap();
+    assert_eq!(staker_state.votes, vec![(poll_id, vote_info.clone())]);
+
+    let poll = Poll::load(&deps.storage, &poll_id).unwrap();
+    assert_eq!(poll.yes_votes, Uint128::zero());
+    assert_eq!(poll.no_votes, Uint128::new(30));
+
+    let voter = poll.load_voter(&deps.storage, &voter_addr).unwrap();
+    assert_eq!(voter, vote_info);
+}
+
+#[test]
+fn succeed_with_delegated_balance() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    super::create_poll::default(&mut deps);
+    crate::staking::tests::stake_governance_token_hook::will_success(&mut deps, VOTER1, Uint128::new(100));
+    crate::staking::tests::stake_governance_token_hook::will_success(&mut deps, VOTER2, Uint128::new(50));
+    crate::staking::tests::delegate::will_success(&mut deps, VOTER2, VOTER1);
+
+    let poll_id = 1u64;
+    let (_, _, response) = will_success(&mut deps, VOTER1, poll_id, VoteOption::Yes, Uint128::new(150));
+    assert!(response.attributes.contains(&attr("delegated_amount", "50")));
+
+    let poll = Poll::load(&deps.storage, &poll_id).unwrap();
+    assert_eq!(poll.yes_votes, Uint128::new(150));
+
+    let voter = poll.load_voter(&deps.storage, &Addr::unchecked(VOTER1)).unwrap();
+    assert_eq!(voter.delegated_amount, Uint128::new(50));
 
     let result = exec(
         &mut deps,
@@ -159,8 +202,61 @@ fn failed_cast_vote_twice() {
         mock_info(VOTER1, &[]),
         poll_id,
         VoteOption::Yes,
-        Uint128::new(10),
+        Uint128::new(151),
     );
+    expect_generic_err(&result, "User does not have enough staked tokens.");
+}
+
+#[test]
+fn succeed_delegator_override() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    super::create_poll::default(&mut deps);
+    crate::staking::tests::stake_governance_token_hook::will_success(&mut deps, VOTER1, Uint128::new(100));
+    crate::staking::tests::stake_governance_token_hook::will_success(&mut deps, VOTER2, Uint128::new(50));
+    crate::staking::tests::delegate::will_success(&mut deps, VOTER2, VOTER1);
+
+    let poll_id = 1u64;
+    will_success(&mut deps, VOTER1, poll_id, VoteOption::Yes, Uint128::new(150));
+    will_success(&mut deps, VOTER2, poll_id, VoteOption::No, Uint128::new(50));
+
+    let poll = Poll::load(&deps.storage, &poll_id).unwrap();
+    assert_eq!(poll.yes_votes, Uint128::new(100));
+    assert_eq!(poll.no_votes, Uint128::new(50));
+    assert_eq!(poll.load_delegation_override(&deps.storage, &Addr::unchecked(VOTER1)).unwrap(), Uint128::new(50));
+
+    let voter = poll.load_voter(&deps.storage, &Addr::unchecked(VOTER1)).unwrap();
+    assert_eq!(voter.amount, Uint128::new(100));
+    assert_eq!(voter.delegated_amount, Uint128::zero());
+}
+
+#[test]
+fn failed_cast_vote_staked_after_poll_creation() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    super::create_poll::default(&mut deps);
+    crate::staking::tests::stake_governance_token_hook::will_success(&mut deps, VOTER1, Uint128::new(100));
+
+    // staked in the block the poll is created
+    let poll = Poll::load(&deps.storage, &1).unwrap();
+    let env = governance_env_height(poll.created_height.unwrap());
+    crate::staking::tests::stake_governance_token_hook::exec(
+        &mut deps,
+        env.clone(),
+        mock_info(env.contract.address.as_str(), &[]),
+        Addr::unchecked(VOTER2),
+        Uint128::new(100),
+    ).unwrap();
+
+    let result = exec(&mut deps, env.clone(), mock_info(VOTER2, &[]), 1, VoteOption::Yes, Uint128::new(1));
+    expect_generic_err(&result, "User does not have enough staked tokens.");
+
+    let result = exec(&mut deps, env.clone(), mock_info(VOTER1, &[]), 1, VoteOption::Yes, Uint128::new(101));
+    expect_generic_err(&result, "User does not have enough staked tokens.");
 
-    expect_generic_err(&result, "User has already voted.");
-}
\ No newline at end of file
+    exec(&mut deps, env, mock_info(VOTER1, &[]), 1, VoteOption::Yes, Uint128::new(100)).unwrap();
+}
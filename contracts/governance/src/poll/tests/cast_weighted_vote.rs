@@ -0,0 +1,163 @@
+use cosmwasm_std::{Addr, attr, Env, MessageInfo, Response, Uint128};
+use cosmwasm_std::testing::mock_info;
+
+use valkyrie::common::ContractResult;
+use valkyrie::governance::enumerations::VoteOption;
+use valkyrie::mock_querier::{custom_deps, CustomDeps};
+use valkyrie::test_constants::governance::governance_env;
+use valkyrie::test_utils::expect_generic_err;
+
+use crate::poll::executions::cast_weighted_vote;
+use crate::poll::queries::query_voters;
+use crate::poll::states::Poll;
+use crate::staking::states::StakerState;
+use crate::tests::init_default;
+
+use super::cast_vote::{VOTER1, VOTER2};
+
+pub fn exec(
+    deps: &mut CustomDeps,
+    env: Env,
+    info: MessageInfo,
+    poll_id: u64,
+    votes: Vec<(VoteOption, Uint128)>,
+) -> ContractResult<Response> {
+    cast_weighted_vote(
+        deps.as_mut(),
+        env,
+        info,
+        poll_id,
+        votes,
+    )
+}
+
+pub fn will_success(
+    deps: &mut CustomDeps,
+    voter: &str,
+    poll_id: u64,
+    votes: Vec<(VoteOption, Uint128)>,
+) -> (Env, MessageInfo, Response) {
+    let env = governance_env();
+    let info = mock_info(voter, &[]);
+
+    let response = exec(
+        deps,
+        env.clone(),
+        info.clone(),
+        poll_id,
+        votes,
+    ).unwrap();
+
+    (env, info, response)
+}
+
+#[test]
+fn succeed() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    super::create_poll::default(&mut deps);
+    crate::staking::tests::stake_governance_token_hook::will_success(&mut deps, VOTER1, Uint128::new(100));
+
+    let voter_addr = Addr::unchecked(VOTER1);
+    let poll_id = 1u64;
+    let votes = vec![
+        (VoteOption::No, Uint128::new(30)),
+        (VoteOption::Yes, Uint128::new(60)),
+        (VoteOption::Abstain, Uint128::new(10)),
+    ];
+
+    let (_, _, response) = will_success(&mut deps, VOTER1, poll_id, votes.clone());
+    assert!(response.attributes.contains(&attr("amount", "100")));
+    assert!(response.attributes.contains(&attr("votes", "no:30,yes:60,abstain:10")));
+
+    let poll = Poll::load(&deps.storage, &poll_id).unwrap();
+    assert_eq!(poll.yes_votes, Uint128::new(60));
+    assert_eq!(poll.no_votes, Uint128::new(30));
+    assert_eq!(poll.abstain_votes, Uint128::new(10));
+
+    let voter = poll.load_voter(&deps.storage, &voter_addr).unwrap();
+    assert_eq!(voter.option, VoteOption::Yes);
+    assert_eq!(voter.amount, Uint128::new(100));
+    assert_eq!(voter.split_votes, votes);
+
+    let staker_state = StakerState::load(&deps.storage, &voter_addr).unwrap();
+    assert_eq!(staker_state.get_locked_balance(), Uint128::new(100));
+
+    let voters = query_voters(deps.as_ref(), governance_env(), poll_id, None, None, None).unwrap();
+    assert_eq!(voters.voters[0].votes, votes);
+
+    // revise to a single option
+    super::cast_vote::will_success(&mut deps, VOTER1, poll_id, VoteOption::No, Uint128::new(50));
+
+    let poll = Poll::load(&deps.storage, &poll_id).unwrap();
+    assert_eq!(poll.yes_votes, Uint128::zero());
+    assert_eq!(poll.no_votes, Uint128::new(50));
+    assert_eq!(poll.abstain_votes, Uint128::zero());
+
+    let voters = query_voters(deps.as_ref(), governance_env(), poll_id, None, None, None).unwrap();
+    assert_eq!(voters.voters[0].votes, vec![(VoteOption::No, Uint128::new(50))]);
+}
+
+#[test]
+fn succeed_delegator_override() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    super::create_poll::default(&mut deps);
+    crate::staking::tests::stake_governance_token_hook::will_success(&mut deps, VOTER1, Uint128::new(60));
+    crate::staking::tests::stake_governance_token_hook::will_success(&mut deps, VOTER2, Uint128::new(40));
+    crate::staking::tests::delegate::will_success(&mut deps, VOTER2, VOTER1);
+
+    let poll_id = 1u64;
+    will_success(&mut deps, VOTER1, poll_id, vec![
+        (VoteOption::Yes, Uint128::new(70)),
+        (VoteOption::No, Uint128::new(30)),
+    ]);
+    super::cast_vote::will_success(&mut deps, VOTER2, poll_id, VoteOption::Abstain, Uint128::new(40));
+
+    let poll = Poll::load(&deps.storage, &poll_id).unwrap();
+    assert_eq!(poll.yes_votes, Uint128::new(42));
+    assert_eq!(poll.no_votes, Uint128::new(18));
+    assert_eq!(poll.abstain_votes, Uint128::new(40));
+
+    let voter = poll.load_voter(&deps.storage, &Addr::unchecked(VOTER1)).unwrap();
+    assert_eq!(voter.amount, Uint128::new(60));
+    assert_eq!(voter.split_votes, vec![
+        (VoteOption::Yes, Uint128::new(42)),
+        (VoteOption::No, Uint128::new(18)),
+    ]);
+}
+
+#[test]
+fn failed_invalid_votes() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    super::create_poll::default(&mut deps);
+    crate::staking::tests::stake_governance_token_hook::will_success(&mut deps, VOTER1, Uint128::new(100));
+
+    let result = exec(&mut deps, governance_env(), mock_info(VOTER1, &[]), 1, vec![]);
+    expect_generic_err(&result, "Votes must not be empty");
+
+    let result = exec(&mut deps, governance_env(), mock_info(VOTER1, &[]), 1, vec![
+        (VoteOption::Yes, Uint128::new(10)),
+        (VoteOption::No, Uint128::zero()),
+    ]);
+    expect_generic_err(&result, "Vote amount must be greater than zero");
+
+    let result = exec(&mut deps, governance_env(), mock_info(VOTER1, &[]), 1, vec![
+        (VoteOption::Yes, Uint128::new(10)),
+        (VoteOption::Yes, Uint128::new(20)),
+    ]);
+    expect_generic_err(&result, "Duplicated vote option");
+
+    let result = exec(&mut deps, governance_env(), mock_info(VOTER1, &[]), 1, vec![
+        (VoteOption::Yes, Uint128::new(60)),
+        (VoteOption::No, Uint128::new(41)),
+    ]);
+    expect_generic_err(&result, "User does not have enough staked tokens.");
+}
@@ -3,8 +3,10 @@ use cosmwasm_std::testing::mock_info;
 use cw20::Cw20ExecuteMsg;
 
 use valkyrie::common::{ContractResult, Execution, ExecutionMsg};
-use valkyrie::governance::enumerations::PollStatus;
+use valkyrie::community::execute_msgs::ExecuteMsg as CommunityExecuteMsg;
+use valkyrie::governance::enumerations::{PollCategory, PollStatus};
 use valkyrie::mock_querier::{custom_deps, CustomDeps};
+use valkyrie::test_constants::community::COMMUNITY;
 use valkyrie::test_constants::default_sender;
 use valkyrie::test_constants::governance::*;
 use valkyrie::test_utils::{expect_generic_err, expect_unauthorized_err};
@@ -28,7 +30,9 @@ pub fn exec(
     title: String,
     description: String,
     link: Option<String>,
+    category: Option<PollCategory>,
     execution_msgs: Vec<ExecutionMsg>,
+    independent_executions: bool,
 ) -> ContractResult<Response> {
     deps.querier.plus_token_balances(&[(
         GOVERNANCE_TOKEN,
@@ -44,7 +48,9 @@ pub fn exec(
         title,
         description,
         link,
+        category,
         execution_msgs,
+        independent_executions,
     )
 }
 
@@ -57,7 +63,8 @@ pub fn will_success(
     link: Option<&str>,
     execution_msgs: Vec<ExecutionMsg>,
 ) -> (Env, MessageInfo, Response) {
-    let env = governance_env();
+    // voting power is measured before the creation height
+    let env = governance_env_height(governance_env().block.height + 1);
     let info = mock_info(GOVERNANCE_TOKEN, &[]);
 
     let response = exec(
@@ -69,7 +76,9 @@ pub fn will_success(
         title.to_string(),
         description.to_string(),
         link.map(|v| v.to_string()),
+        None,
         execution_msgs,
+        false,
     ).unwrap();
 
     (env, info, response)
@@ -136,6 +145,13 @@ fn succeed() {
         total_balance_at_end_poll: None,
         snapped_staked_amount: None,
         _status: Some(PollStatus::InProgress),
+        created_height: Some(env.block.height),
+        category: None,
+        deposit_held: false,
+        independent_executions: false,
+        executed_height: None,
+        execution_results: vec![],
+        boost_votes: Uint128::zero(),
     });
 
     let polls = Poll::query(
@@ -164,7 +180,9 @@ fn failed_invalid_permission() {
         POLL_TITLE.to_string(),
         POLL_DESCRIPTION.to_string(),
         None,
+        None,
         vec![],
+        false,
     );
 
     expect_unauthorized_err(&result);
@@ -185,7 +203,9 @@ fn failed_create_poll_invalid_deposit() {
         POLL_TITLE.to_string(),
         POLL_DESCRIPTION.to_string(),
         None,
+        None,
         vec![],
+        false,
     );
 
     expect_generic_err(
@@ -209,7 +229,9 @@ fn failed_create_poll_invalid_title() {
         "a".to_string(),
         POLL_DESCRIPTION.to_string(),
         None,
+        None,
         vec![],
+        false,
     );
     expect_generic_err(&result, "Title too short");
 
@@ -222,7 +244,9 @@ fn failed_create_poll_invalid_title() {
         "0123456789012345678901234567890123456789012345678901234567890123401234567890123456789012345678901234567890123456789012345678901234012345678901234567890123456789012345678901234567890123456789012340123456789012345678901234567890123456789012345678901234567890123401234567890123456789012345678901234567890123456789012345678901234".to_string(),
         POLL_DESCRIPTION.to_string(),
         None,
+        None,
         vec![],
+        false,
     );
     expect_generic_err(&result, "Title too long");
 }
@@ -242,7 +266,9 @@ fn failed_create_poll_invalid_description() {
         POLL_TITLE.to_string(),
         "a".to_string(),
         None,
+        None,
         vec![],
+        false,
     );
     expect_generic_err(&result, "Description too short");
 
@@ -255,7 +281,9 @@ fn failed_create_poll_invalid_description() {
         POLL_TITLE.to_string(),
         "0123456789012345678901234567890123456789012345678901234567890123401234567890123456789012345678901234567890123456789012345678901234012345678901234567890123456789012345678901234567890123456789012340123456789012345678901234567890123456789012345678901234567890123401234567890123456789012345678901234567890123456789012345678901234012345678901234567890123456789012345678901234567890123456789012340123456789012345678901234567890123456789012345678901234567890123401234567890123456789012345678901234567890123456789012345678901234012345678901234567890123456789012345678901234567890123456789012340123456789012345678901234567890123456789012345678901234567890123401234567890123456789012345678901234567890123456789012345678901234012345678901234567890123456789012345678901234567890123456789012340123456789012345678901234567890123456789012345678901234567890123401234567890123456789012345678901234567890123456789012345678901234012345678901234567890123456789012345678901234567890123456789012340123456789012345678901234567890123456789012345678901234567890123401234567890123456789012345678901234567890123456789012345678901234012345678901234567890123456789012345678901234567890123456789012340123456789012345678901234567890123456789012345678901234567890123401234567890123456789012345678901234567890123456789012345678901234012345678901234567890123456789012345678901234567890123456789012340123456789012345678901234567890123456789012345678901234567890123401234567890123456789012345678901234567890123456789012345678901234012345678901234567890123456789012345678901234567890123456789012340123456789012345678901234567890123456789012345678901234567890123401234567890123456789012345678901234567890123456789012345678901234012345678901234567890123456789012345678901234567890123456789012340123456789012345678901234567890123456789012345678901234567890123401234567890123456789012345678901234567890123456789012345678901234012345678901234567890123456789012345678901234567890123456789012340123456789012345678901234567890123456789012345678901234567890123401234567890123456789012345678901234567890123456789012345678901234012345678901234567890123456789012345678901234567890123456789012340123456789012345678901234567890123456789012345678901234567890123401234567890123456789012345678901234567890123456789012345678901234".to_string(),
         None,
+        None,
         vec![],
+        false,
     );
     expect_generic_err(&result, "Description too long");
 }
@@ -275,7 +303,9 @@ fn failed_create_poll_invalid_link() {
         POLL_TITLE.to_string(),
         POLL_DESCRIPTION.to_string(),
         Some("http://".to_string()),
+        None,
         vec![],
+        false,
     );
     expect_generic_err(&result, "Link too short");
 
@@ -288,11 +318,192 @@ fn failed_create_poll_invalid_link() {
         POLL_TITLE.to_string(),
         POLL_DESCRIPTION.to_string(),
         Some("0123456789012345678901234567890123456789012345678901234567890123401234567890123456789012345678901234567890123456789012345678901234012345678901234567890123456789012345678901234567890123456789012340123456789012345678901234567890123456789012345678901234567890123401234567890123456789012345678901234567890123456789012345678901234".to_string()),
+        None,
         vec![],
+        false,
     );
     expect_generic_err(&result, "Link too long");
 }
 
+#[test]
+fn succeed_category_config() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+    crate::common::tests::update_contract_config::will_success(&mut deps, None, Some(GUARDIAN.to_string()), false, false);
+
+    let deposit = POLL_PROPOSAL_DEPOSIT + Uint128::new(100);
+    super::update_config::will_success(
+        &mut deps,
+        Some(PollCategory::Emergency),
+        None,
+        None,
+        Some(POLL_VOTING_PERIOD / 2),
+        None,
+        Some(deposit),
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+
+    let result = exec(
+        &mut deps,
+        governance_env(),
+        mock_info(GOVERNANCE_TOKEN, &[]),
+        Addr::unchecked(GUARDIAN),
+        POLL_PROPOSAL_DEPOSIT,
+        POLL_TITLE.to_string(),
+        POLL_DESCRIPTION.to_string(),
+        None,
+        Some(PollCategory::Emergency),
+        vec![],
+        false,
+    );
+    expect_generic_err(&result, format!("Must deposit more than {} token", deposit).as_str());
+
+    let env = governance_env();
+    let response = exec(
+        &mut deps,
+        env.clone(),
+        mock_info(GOVERNANCE_TOKEN, &[]),
+        Addr::unchecked(GUARDIAN),
+        deposit,
+        POLL_TITLE.to_string(),
+        POLL_DESCRIPTION.to_string(),
+        None,
+        Some(PollCategory::Emergency),
+        vec![],
+        false,
+    ).unwrap();
+    assert_eq!(response.attributes.last().unwrap(), &attr("category", "emergency"));
+
+    let poll = Poll::load(&deps.storage, &1).unwrap();
+    assert_eq!(poll.category, Some(PollCategory::Emergency));
+    assert_eq!(poll.end_height, env.block.height + POLL_VOTING_PERIOD / 2);
+}
+
+#[test]
+fn succeed_forced_treasury_category() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    let transfer_msg = ExecutionMsg {
+        order: 1,
+        contract: COMMUNITY.to_string(),
+        msg: to_binary(&CommunityExecuteMsg::Transfer {
+            recipient: PROPOSER1.to_string(),
+            amount: Uint128::new(100),
+        }).unwrap(),
+    };
+
+    exec(
+        &mut deps,
+        governance_env(),
+        mock_info(GOVERNANCE_TOKEN, &[]),
+        Addr::unchecked(PROPOSER1),
+        POLL_PROPOSAL_DEPOSIT,
+        POLL_TITLE.to_string(),
+        POLL_DESCRIPTION.to_string(),
+        None,
+        Some(PollCategory::ParameterChange),
+        vec![mock_exec_msg(2), transfer_msg],
+        false,
+    ).unwrap();
+
+    let poll = Poll::load(&deps.storage, &1).unwrap();
+    assert_eq!(poll.category, Some(PollCategory::TreasurySpend));
+
+    let allowance_msg = ExecutionMsg {
+        order: 1,
+        contract: COMMUNITY.to_string(),
+        msg: to_binary(&CommunityExecuteMsg::IncreaseAllowance {
+            address: PROPOSER1.to_string(),
+            amount: Uint128::new(100),
+        }).unwrap(),
+    };
+
+    exec(
+        &mut deps,
+        governance_env(),
+        mock_info(GOVERNANCE_TOKEN, &[]),
+        Addr::unchecked(PROPOSER1),
+        POLL_PROPOSAL_DEPOSIT,
+        POLL_TITLE.to_string(),
+        POLL_DESCRIPTION.to_string(),
+        None,
+        None,
+        vec![allowance_msg],
+        false,
+    ).unwrap();
+
+    let poll = Poll::load(&deps.storage, &2).unwrap();
+    assert_eq!(poll.category, Some(PollCategory::TreasurySpend));
+}
+
+#[test]
+fn failed_emergency_poll_not_guardian() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    let result = exec(
+        &mut deps,
+        governance_env(),
+        mock_info(GOVERNANCE_TOKEN, &[]),
+        Addr::unchecked(PROPOSER1),
+        POLL_PROPOSAL_DEPOSIT,
+        POLL_TITLE.to_string(),
+        POLL_DESCRIPTION.to_string(),
+        None,
+        Some(PollCategory::Emergency),
+        vec![],
+        false,
+    );
+    expect_unauthorized_err(&result);
+
+    crate::common::tests::update_contract_config::will_success(&mut deps, None, Some(GUARDIAN.to_string()), false, false);
+
+    let result = exec(
+        &mut deps,
+        governance_env(),
+        mock_info(GOVERNANCE_TOKEN, &[]),
+        Addr::unchecked(PROPOSER1),
+        POLL_PROPOSAL_DEPOSIT,
+        POLL_TITLE.to_string(),
+        POLL_DESCRIPTION.to_string(),
+        None,
+        Some(PollCategory::Emergency),
+        vec![],
+        false,
+    );
+    expect_unauthorized_err(&result);
+}
+
+#[test]
+fn failed_text_poll_with_executions() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    let result = exec(
+        &mut deps,
+        governance_env(),
+        mock_info(GOVERNANCE_TOKEN, &[]),
+        Addr::unchecked(PROPOSER1),
+        POLL_PROPOSAL_DEPOSIT,
+        POLL_TITLE.to_string(),
+        POLL_DESCRIPTION.to_string(),
+        None,
+        Some(PollCategory::Text),
+        vec![mock_exec_msg(1)],
+        false,
+    );
+    expect_generic_err(&result, "Text poll cannot have executions");
+}
+
 pub fn mock_exec_msg(order: u64) -> ExecutionMsg {
     ExecutionMsg {
         order,
@@ -1,5 +1,5 @@
 use valkyrie::mock_querier::{CustomDeps, custom_deps};
-use cosmwasm_std::{Env, MessageInfo, Response, CosmosMsg, WasmMsg, Uint128, attr, to_binary, SubMsg};
+use cosmwasm_std::{Addr, Env, MessageInfo, Response, CosmosMsg, WasmMsg, Uint128, attr, to_binary, SubMsg};
 use valkyrie::common::ContractResult;
 use crate::poll::executions::end_poll;
 use crate::tests::init_default;
@@ -271,3 +271,33 @@ fn failed_quorum_inflation_without_snapshot_poll() {
     assert_eq!(poll.status, PollStatus::Rejected);
     assert_eq!(poll.snapped_staked_amount, Some(Uint128::new(1100)));
 }
+
+#[test]
+fn succeed_quorum_measured_before_creation() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    super::create_poll::default(&mut deps);
+    crate::staking::tests::stake_governance_token_hook::will_success(&mut deps, VOTER1, Uint128::new(100));
+
+    let poll_id = 1u64;
+
+    super::cast_vote::will_success(&mut deps, VOTER1, poll_id, VoteOption::Yes, Uint128::new(100));
+
+    // staked after the creation, so not counted in the quorum
+    let poll = Poll::load(&deps.storage, &poll_id).unwrap();
+    crate::staking::tests::stake_governance_token_hook::exec(
+        &mut deps,
+        governance_env_height(poll.created_height.unwrap() + 1),
+        mock_info(GOVERNANCE, &[]),
+        Addr::unchecked(VOTER2),
+        Uint128::new(1000),
+    ).unwrap();
+
+    will_success(&mut deps, poll_id);
+
+    let poll = Poll::load(&deps.storage, &poll_id).unwrap();
+    assert_eq!(poll.status, PollStatus::Passed);
+    assert_eq!(poll.total_balance_at_end_poll, Some(Uint128::new(100)));
+}
@@ -1,4 +1,5 @@
 use cosmwasm_std::{CosmosMsg, Env, MessageInfo, ReplyOn, Response, SubMsg, to_binary, Uint128, WasmMsg};
+use cw20::Cw20ExecuteMsg;
 
 use valkyrie::common::ContractResult;
 use valkyrie::governance::enumerations::VoteOption;
@@ -9,7 +10,7 @@ use valkyrie::test_constants::governance::*;
 use valkyrie::test_utils::expect_generic_err;
 
 use crate::poll::executions::{execute_poll, REPLY_EXECUTION};
-use crate::poll::states::{Poll, PollExecutionContext};
+use crate::poll::states::{Poll, PollExecutionContext, PollState};
 use crate::poll::tests::cast_vote::VOTER1;
 use crate::poll::tests::create_poll::{mock_exec_msg, POLL_DESCRIPTION, POLL_LINK, POLL_TITLE, PROPOSER1};
 use crate::tests::init_default;
@@ -81,9 +82,33 @@ fn succeed() {
     assert_eq!(context, PollExecutionContext {
         poll_id,
         execution_count: execution_msgs.len() as u64,
+        pending_orders: vec![1, 2, 3],
     });
 }
 
+#[test]
+fn succeed_refund_held_deposit() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    let poll_id = super::veto_poll::passed_poll(&mut deps);
+
+    let (_, _, response) = will_success(&mut deps, poll_id);
+    assert_eq!(response.messages[1], SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: GOVERNANCE_TOKEN.to_string(),
+        funds: vec![],
+        msg: to_binary(&Cw20ExecuteMsg::Transfer {
+            recipient: PROPOSER1.to_string(),
+            amount: POLL_PROPOSAL_DEPOSIT,
+        }).unwrap(),
+    })));
+
+    let poll = Poll::load(&deps.storage, &poll_id).unwrap();
+    assert!(!poll.deposit_held);
+    assert_eq!(PollState::load(&deps.storage).unwrap().total_deposit, Uint128::zero());
+}
+
 #[test]
 fn failed_not_passed() {
     let mut deps = custom_deps();
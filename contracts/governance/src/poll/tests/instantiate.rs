@@ -28,6 +28,10 @@ pub fn exec(
         execution_delay_period,
         proposal_deposit,
         snapshot_period,
+        cancel_grace_period: POLL_CANCEL_GRACE_PERIOD,
+        cancel_fee_rate: Decimal::percent(POLL_CANCEL_FEE_PERCENT),
+        refund_vetoed_deposit: false,
+        execution_retry_period: POLL_EXECUTION_RETRY_PERIOD,
     };
 
     instantiate(deps.as_mut(), env, info, msg)
@@ -66,6 +70,10 @@ fn succeed() {
     assert_eq!(poll_config.execution_delay_period, POLL_EXECUTION_DELAY_PERIOD);
     assert_eq!(poll_config.proposal_deposit, POLL_PROPOSAL_DEPOSIT);
     assert_eq!(poll_config.snapshot_period, POLL_SNAPSHOT_PERIOD);
+    assert_eq!(poll_config.cancel_grace_period, POLL_CANCEL_GRACE_PERIOD);
+    assert_eq!(poll_config.cancel_fee_rate, Decimal::percent(POLL_CANCEL_FEE_PERCENT));
+    assert!(!poll_config.refund_vetoed_deposit);
+    assert_eq!(poll_config.execution_retry_period, POLL_EXECUTION_RETRY_PERIOD);
 
     let poll_state = PollState::load(&deps.storage).unwrap();
     assert_eq!(poll_state.poll_count, 0);
@@ -2,8 +2,13 @@ pub mod instantiate;
 pub mod update_config;
 pub mod create_poll;
 pub mod cast_vote;
+pub mod cast_weighted_vote;
+pub mod retract_vote;
+pub mod cancel_poll;
 pub mod snapshot_poll;
 pub mod end_poll;
+pub mod veto_poll;
 pub mod execute_poll;
 pub mod run_execution;
 pub mod reply_execution;
+pub mod retry_execution;
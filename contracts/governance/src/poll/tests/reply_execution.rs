@@ -1,11 +1,13 @@
-use cosmwasm_std::{ContractResult as CwContractResult, Env, Reply, Response, SubMsgExecutionResponse, Uint128};
+use cosmwasm_std::{Addr, ContractResult as CwContractResult, Env, Reply, Response, SubMsgExecutionResponse, Uint128};
+use cosmwasm_std::testing::mock_info;
 
 use valkyrie::common::ContractResult;
 use valkyrie::governance::enumerations::{PollStatus, VoteOption};
+use valkyrie::governance::models::ExecutionResultMsg;
 use valkyrie::mock_querier::{custom_deps, CustomDeps};
-use valkyrie::test_constants::governance::POLL_PROPOSAL_DEPOSIT;
+use valkyrie::test_constants::governance::{governance_env, governance_env_height, GOVERNANCE_TOKEN, POLL_PROPOSAL_DEPOSIT};
 
-use crate::poll::executions::{reply_execution, REPLY_EXECUTION};
+use crate::poll::executions::{reply_execution, REPLY_EXECUTION, REPLY_INDEPENDENT_EXECUTION};
 use crate::poll::states::{Poll, PollExecutionContext};
 use crate::poll::tests::cast_vote::VOTER1;
 use crate::poll::tests::create_poll::{mock_exec_msg, POLL_DESCRIPTION, POLL_LINK, POLL_TITLE, PROPOSER1};
@@ -98,6 +100,76 @@ fn succeed_failed_reply() {
 
     let poll = Poll::load(&deps.storage, &context.poll_id).unwrap();
     assert_eq!(poll.status, PollStatus::Failed);
+    assert_eq!(poll.execution_results.len(), execution_msgs.len());
+    assert!(poll.execution_results.iter().all(|r| r.error == Some("Mock err".to_string())));
+}
+
+#[test]
+fn succeed_independent_replies() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    let poll_id = executed_independent_poll(&mut deps);
+
+    let env = governance_env();
+    exec_independent(&mut deps, env.clone(), CwContractResult::Ok(mock_subcall_response())).unwrap();
+
+    let poll = Poll::load(&deps.storage, &poll_id).unwrap();
+    assert_eq!(poll.status, PollStatus::Passed);
+    assert_eq!(PollExecutionContext::load(&deps.storage).unwrap().pending_orders, vec![2, 3]);
+
+    exec_independent(&mut deps, env.clone(), CwContractResult::Err("Mock err".to_string())).unwrap();
+    exec_independent(&mut deps, env, CwContractResult::Ok(mock_subcall_response())).unwrap();
+
+    assert!(PollExecutionContext::may_load(&deps.storage).unwrap().is_none());
+
+    let poll = Poll::load(&deps.storage, &poll_id).unwrap();
+    assert_eq!(poll.status, PollStatus::Failed);
+    assert_eq!(poll.to_response().execution_results, vec![
+        ExecutionResultMsg { order: 1, success: true, error: None },
+        ExecutionResultMsg { order: 2, success: false, error: Some("Mock err".to_string()) },
+        ExecutionResultMsg { order: 3, success: true, error: None },
+    ]);
+}
+
+pub fn exec_independent(
+    deps: &mut CustomDeps,
+    env: Env,
+    result: CwContractResult<SubMsgExecutionResponse>,
+) -> ContractResult<Response> {
+    reply_execution(deps.as_mut(), env, Reply {
+        id: REPLY_INDEPENDENT_EXECUTION,
+        result,
+    })
+}
+
+pub fn executed_independent_poll(deps: &mut CustomDeps) -> u64 {
+    super::create_poll::exec(
+        deps,
+        governance_env_height(governance_env().block.height + 1),
+        mock_info(GOVERNANCE_TOKEN, &[]),
+        Addr::unchecked(PROPOSER1),
+        POLL_PROPOSAL_DEPOSIT,
+        POLL_TITLE.to_string(),
+        POLL_DESCRIPTION.to_string(),
+        Some(POLL_LINK.to_string()),
+        None,
+        vec![mock_exec_msg(2), mock_exec_msg(1), mock_exec_msg(3)],
+        true,
+    ).unwrap();
+    crate::staking::tests::stake_governance_token_hook::will_success(deps, VOTER1, Uint128::new(100));
+
+    let poll_id = 1u64;
+
+    super::cast_vote::will_success(deps, VOTER1, poll_id, VoteOption::Yes, Uint128::new(100));
+    super::end_poll::will_success(deps, poll_id);
+
+    let (_, _, response) = super::execute_poll::will_success(deps, poll_id);
+    assert_eq!(response.messages.len(), 3);
+    assert!(response.messages.iter().all(|m| m.id == REPLY_INDEPENDENT_EXECUTION));
+
+    poll_id
 }
 
 pub fn mock_subcall_response() -> SubMsgExecutionResponse {
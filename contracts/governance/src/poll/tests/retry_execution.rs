@@ -0,0 +1,93 @@
+use cosmwasm_std::{ContractResult as CwContractResult, CosmosMsg, Env, MessageInfo, ReplyOn, Response, SubMsg, WasmMsg};
+
+use valkyrie::common::ContractResult;
+use valkyrie::governance::enumerations::PollStatus;
+use valkyrie::mock_querier::{custom_deps, CustomDeps};
+use valkyrie::test_constants::default_sender;
+use valkyrie::test_constants::governance::*;
+use valkyrie::test_utils::expect_generic_err;
+
+use crate::poll::executions::{retry_execution, REPLY_INDEPENDENT_EXECUTION};
+use crate::poll::states::Poll;
+use crate::poll::tests::create_poll::mock_exec_msg;
+use crate::poll::tests::reply_execution::{exec_independent, executed_independent_poll, mock_subcall_response};
+use crate::tests::init_default;
+
+pub fn exec(deps: &mut CustomDeps, env: Env, info: MessageInfo, poll_id: u64) -> ContractResult<Response> {
+    retry_execution(deps.as_mut(), env, info, poll_id)
+}
+
+pub fn will_success(deps: &mut CustomDeps, poll_id: u64) -> (Env, MessageInfo, Response) {
+    let env = governance_env();
+    let info = default_sender();
+
+    let response = exec(deps, env.clone(), info.clone(), poll_id).unwrap();
+
+    (env, info, response)
+}
+
+fn failed_poll(deps: &mut CustomDeps) -> u64 {
+    let poll_id = executed_independent_poll(deps);
+
+    let env = governance_env();
+    exec_independent(deps, env.clone(), CwContractResult::Ok(mock_subcall_response())).unwrap();
+    exec_independent(deps, env.clone(), CwContractResult::Err("Mock err".to_string())).unwrap();
+    exec_independent(deps, env, CwContractResult::Ok(mock_subcall_response())).unwrap();
+
+    poll_id
+}
+
+#[test]
+fn succeed() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    let poll_id = failed_poll(&mut deps);
+
+    let (env, _, response) = will_success(&mut deps, poll_id);
+    let failed_exec_msg = mock_exec_msg(2);
+    assert_eq!(response.messages, vec![SubMsg {
+        id: REPLY_INDEPENDENT_EXECUTION,
+        msg: CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: failed_exec_msg.contract,
+            funds: vec![],
+            msg: failed_exec_msg.msg,
+        }),
+        gas_limit: None,
+        reply_on: ReplyOn::Always,
+    }]);
+
+    exec_independent(&mut deps, env, CwContractResult::Ok(mock_subcall_response())).unwrap();
+
+    let poll = Poll::load(&deps.storage, &poll_id).unwrap();
+    assert_eq!(poll.status, PollStatus::Executed);
+    assert!(poll.execution_results.iter().all(|r| r.is_success()));
+}
+
+#[test]
+fn failed_not_failed() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    let poll_id = executed_independent_poll(&mut deps);
+
+    let result = exec(&mut deps, governance_env(), default_sender(), poll_id);
+    expect_generic_err(&result, "Poll is not in failed status");
+}
+
+#[test]
+fn failed_after_retry_period() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    let poll_id = failed_poll(&mut deps);
+
+    let poll = Poll::load(&deps.storage, &poll_id).unwrap();
+    let env = governance_env_height(poll.executed_height.unwrap() + POLL_EXECUTION_RETRY_PERIOD + 1);
+
+    let result = exec(&mut deps, env, default_sender(), poll_id);
+    expect_generic_err(&result, "Execution retry period has expired");
+}
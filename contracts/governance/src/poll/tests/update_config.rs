@@ -1,7 +1,8 @@
-use cosmwasm_std::{Decimal, Env, MessageInfo, Response, Uint128};
+use cosmwasm_std::{attr, Decimal, Env, MessageInfo, Response, Uint128};
 use cosmwasm_std::testing::mock_info;
 
 use valkyrie::common::ContractResult;
+use valkyrie::governance::enumerations::PollCategory;
 use valkyrie::mock_querier::{custom_deps, CustomDeps};
 use valkyrie::test_constants::default_sender;
 use valkyrie::test_constants::governance::*;
@@ -15,34 +16,50 @@ pub fn exec(
     deps: &mut CustomDeps,
     env: Env,
     info: MessageInfo,
+    category: Option<PollCategory>,
     quorum: Option<Decimal>,
     threshold: Option<Decimal>,
     voting_period: Option<u64>,
     execution_delay_period: Option<u64>,
     proposal_deposit: Option<Uint128>,
     snapshot_period: Option<u64>,
+    cancel_grace_period: Option<u64>,
+    cancel_fee_rate: Option<Decimal>,
+    refund_vetoed_deposit: Option<bool>,
+    execution_retry_period: Option<u64>,
 ) -> ContractResult<Response> {
     update_poll_config(
         deps.as_mut(),
         env,
         info,
+        category,
         quorum,
         threshold,
         voting_period,
         execution_delay_period,
         proposal_deposit,
         snapshot_period,
+        cancel_grace_period,
+        cancel_fee_rate,
+        refund_vetoed_deposit,
+        execution_retry_period,
     )
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn will_success(
     deps: &mut CustomDeps,
+    category: Option<PollCategory>,
     quorum: Option<Decimal>,
     threshold: Option<Decimal>,
     voting_period: Option<u64>,
     execution_delay_period: Option<u64>,
     proposal_deposit: Option<Uint128>,
     snapshot_period: Option<u64>,
+    cancel_grace_period: Option<u64>,
+    cancel_fee_rate: Option<Decimal>,
+    refund_vetoed_deposit: Option<bool>,
+    execution_retry_period: Option<u64>,
 ) -> (Env, MessageInfo, Response) {
     let env = governance_env();
     let info = mock_info(GOVERNANCE, &[]);
@@ -51,12 +68,17 @@ pub fn will_success(
         deps,
         env.clone(),
         info.clone(),
+        category,
         quorum,
         threshold,
         voting_period,
         execution_delay_period,
         proposal_deposit,
         snapshot_period,
+        cancel_grace_period,
+        cancel_fee_rate,
+        refund_vetoed_deposit,
+        execution_retry_period,
     ).unwrap();
 
     (env, info, response)
@@ -74,15 +96,22 @@ fn succeed() {
     let execution_delay_period = POLL_EXECUTION_DELAY_PERIOD + 100;
     let proposal_deposit = POLL_PROPOSAL_DEPOSIT + Uint128::new(100);
     let snapshot_period = POLL_SNAPSHOT_PERIOD + 100;
+    let cancel_grace_period = POLL_CANCEL_GRACE_PERIOD + 100;
+    let cancel_fee_rate = Decimal::percent(POLL_CANCEL_FEE_PERCENT * 2);
 
     will_success(
         &mut deps,
+        None,
         Some(quorum),
         Some(threshold),
         Some(voting_period),
         Some(execution_delay_period),
         Some(proposal_deposit),
         Some(snapshot_period),
+        Some(cancel_grace_period),
+        Some(cancel_fee_rate),
+        Some(true),
+        Some(POLL_EXECUTION_RETRY_PERIOD + 100),
     );
 
     let config = PollConfig::load(&deps.storage).unwrap();
@@ -98,6 +127,47 @@ fn succeed() {
     assert_ne!(config.proposal_deposit, POLL_PROPOSAL_DEPOSIT);
     assert_eq!(config.snapshot_period, snapshot_period);
     assert_ne!(config.snapshot_period, POLL_SNAPSHOT_PERIOD);
+    assert_eq!(config.cancel_grace_period, cancel_grace_period);
+    assert_ne!(config.cancel_grace_period, POLL_CANCEL_GRACE_PERIOD);
+    assert_eq!(config.cancel_fee_rate, cancel_fee_rate);
+    assert_ne!(config.cancel_fee_rate, Decimal::percent(POLL_CANCEL_FEE_PERCENT));
+    assert!(config.refund_vetoed_deposit);
+    assert_eq!(config.execution_retry_period, POLL_EXECUTION_RETRY_PERIOD + 100);
+}
+
+#[test]
+fn succeed_category() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    let quorum = Decimal::percent(POLL_QUORUM_PERCENT / 2);
+    let (_, _, response) = will_success(
+        &mut deps,
+        Some(PollCategory::TreasurySpend),
+        Some(quorum),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    assert!(response.attributes.contains(&attr("category", "treasury_spend")));
+
+    let config = PollConfig::load_category(&deps.storage, Some(&PollCategory::TreasurySpend)).unwrap();
+    assert_eq!(config.quorum, quorum);
+    assert_eq!(config.threshold, Decimal::percent(POLL_THRESHOLD_PERCENT));
+    assert_eq!(config.voting_period, POLL_VOTING_PERIOD);
+
+    let config = PollConfig::load(&deps.storage).unwrap();
+    assert_eq!(config.quorum, Decimal::percent(POLL_QUORUM_PERCENT));
+
+    let config = PollConfig::load_category(&deps.storage, Some(&PollCategory::Emergency)).unwrap();
+    assert_eq!(config.quorum, Decimal::percent(POLL_QUORUM_PERCENT));
 }
 
 #[test]
@@ -110,12 +180,17 @@ fn failed_invalid_threshold() {
         &mut deps,
         governance_env(),
         mock_info(GOVERNANCE, &[]),
+        None,
         Some(Decimal::percent(101)),
         Some(Decimal::percent(POLL_THRESHOLD_PERCENT)),
         Some(POLL_VOTING_PERIOD),
         Some(POLL_EXECUTION_DELAY_PERIOD),
         Some(POLL_PROPOSAL_DEPOSIT),
         Some(POLL_SNAPSHOT_PERIOD),
+        None,
+        None,
+        None,
+        None,
     );
 
     expect_generic_err(&result, "quorum must be 0 to 1");
@@ -131,12 +206,17 @@ fn failed_invalid_quorum() {
         &mut deps,
         governance_env(),
         mock_info(GOVERNANCE, &[]),
+        None,
         Some(Decimal::percent(POLL_QUORUM_PERCENT)),
         Some(Decimal::percent(101)),
         Some(POLL_VOTING_PERIOD),
         Some(POLL_EXECUTION_DELAY_PERIOD),
         Some(POLL_PROPOSAL_DEPOSIT),
         Some(POLL_SNAPSHOT_PERIOD),
+        None,
+        None,
+        None,
+        None,
     );
 
     expect_generic_err(&result, "threshold must be 0 to 1");
@@ -158,7 +238,38 @@ fn failed_invalid_permission() {
         None,
         None,
         None,
+        None,
+        None,
+        None,
+        None,
+        None,
     );
 
     expect_unauthorized_err(&result);
+}
+
+#[test]
+fn failed_invalid_cancel_fee_rate() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    let result = exec(
+        &mut deps,
+        governance_env(),
+        mock_info(GOVERNANCE, &[]),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(Decimal::percent(101)),
+        None,
+        None,
+    );
+
+    expect_generic_err(&result, "cancel_fee_rate must be 0 to 1");
 }
\ No newline at end of file
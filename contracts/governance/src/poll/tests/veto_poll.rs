@@ -0,0 +1,188 @@
+use cosmwasm_std::{CosmosMsg, Env, MessageInfo, Response, SubMsg, to_binary, Uint128, WasmMsg};
+use cosmwasm_std::testing::mock_info;
+use cw20::Cw20ExecuteMsg;
+
+use valkyrie::common::ContractResult;
+use valkyrie::governance::enumerations::{PollStatus, VoteOption};
+use valkyrie::mock_querier::{custom_deps, CustomDeps};
+use valkyrie::test_constants::community::COMMUNITY;
+use valkyrie::test_constants::governance::*;
+use valkyrie::test_utils::{expect_generic_err, expect_unauthorized_err};
+
+use crate::poll::executions::veto_poll;
+use crate::poll::queries::query_queue;
+use crate::poll::states::{Poll, PollState};
+use crate::poll::tests::cast_vote::VOTER1;
+use crate::poll::tests::create_poll::{mock_exec_msg, POLL_DESCRIPTION, POLL_LINK, POLL_TITLE, PROPOSER1};
+use crate::tests::init_default;
+
+pub fn exec(deps: &mut CustomDeps, env: Env, info: MessageInfo, poll_id: u64) -> ContractResult<Response> {
+    veto_poll(deps.as_mut(), env, info, poll_id)
+}
+
+pub fn will_success(deps: &mut CustomDeps, poll_id: u64) -> (Env, MessageInfo, Response) {
+    let poll = Poll::load(&deps.storage, &poll_id).unwrap();
+    let env = governance_env_height(poll.end_height + 1);
+    let info = mock_info(GUARDIAN, &[]);
+
+    let response = exec(deps, env.clone(), info.clone(), poll_id).unwrap();
+
+    (env, info, response)
+}
+
+pub fn passed_poll(deps: &mut CustomDeps) -> u64 {
+    crate::common::tests::update_contract_config::will_success(deps, None, Some(GUARDIAN.to_string()), false, false);
+
+    super::create_poll::will_success(
+        deps,
+        PROPOSER1,
+        POLL_PROPOSAL_DEPOSIT,
+        POLL_TITLE,
+        POLL_DESCRIPTION,
+        Some(POLL_LINK),
+        vec![mock_exec_msg(1)],
+    );
+    crate::staking::tests::stake_governance_token_hook::will_success(deps, VOTER1, Uint128::new(100));
+
+    let poll_id = 1u64;
+
+    super::cast_vote::will_success(deps, VOTER1, poll_id, VoteOption::Yes, Uint128::new(100));
+    super::end_poll::will_success(deps, poll_id);
+
+    poll_id
+}
+
+#[test]
+fn succeed() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    let poll_id = passed_poll(&mut deps);
+
+    let poll = Poll::load(&deps.storage, &poll_id).unwrap();
+    assert!(poll.deposit_held);
+    assert_eq!(PollState::load(&deps.storage).unwrap().total_deposit, POLL_PROPOSAL_DEPOSIT);
+
+    let queue = query_queue(deps.as_ref(), governance_env(), None, None, None).unwrap();
+    assert_eq!(queue.polls.len(), 1);
+    assert_eq!(queue.polls[0].poll_id, poll_id);
+    assert_eq!(queue.polls[0].executable_height, poll.end_height + POLL_EXECUTION_DELAY_PERIOD);
+    assert_eq!(queue.polls[0].executions, vec![mock_exec_msg(1)]);
+
+    let (_, _, response) = will_success(&mut deps, poll_id);
+    assert_eq!(response.messages, vec![
+        SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: GOVERNANCE_TOKEN.to_string(),
+            funds: vec![],
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: COMMUNITY.to_string(),
+                amount: POLL_PROPOSAL_DEPOSIT,
+            }).unwrap(),
+        })),
+    ]);
+
+    let poll = Poll::load(&deps.storage, &poll_id).unwrap();
+    assert_eq!(poll.status, PollStatus::Vetoed);
+    assert!(!poll.deposit_held);
+    assert_eq!(PollState::load(&deps.storage).unwrap().total_deposit, Uint128::zero());
+
+    let queue = query_queue(deps.as_ref(), governance_env(), None, None, None).unwrap();
+    assert!(queue.polls.is_empty());
+
+    let polls = Poll::query(&deps.storage, Some(PollStatus::Vetoed), None, None, None).unwrap();
+    assert_eq!(polls.len(), 1);
+}
+
+#[test]
+fn succeed_queue_skips_polls_without_executions() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    let poll_id = passed_poll(&mut deps);
+
+    super::create_poll::default(&mut deps);
+    super::cast_vote::will_success(&mut deps, VOTER1, poll_id + 1, VoteOption::Yes, Uint128::new(100));
+    super::end_poll::will_success(&mut deps, poll_id + 1);
+    assert_eq!(Poll::load(&deps.storage, &(poll_id + 1)).unwrap().status, PollStatus::Passed);
+
+    // the newer poll without executions does not take the page
+    let queue = query_queue(deps.as_ref(), governance_env(), None, Some(1), None).unwrap();
+    assert_eq!(queue.polls.len(), 1);
+    assert_eq!(queue.polls[0].poll_id, poll_id);
+}
+
+#[test]
+fn succeed_refund_deposit() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    super::update_config::will_success(
+        &mut deps,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(true),
+        None,
+    );
+
+    let poll_id = passed_poll(&mut deps);
+
+    let (_, _, response) = will_success(&mut deps, poll_id);
+    assert_eq!(response.messages, vec![
+        SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: GOVERNANCE_TOKEN.to_string(),
+            funds: vec![],
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: PROPOSER1.to_string(),
+                amount: POLL_PROPOSAL_DEPOSIT,
+            }).unwrap(),
+        })),
+    ]);
+}
+
+#[test]
+fn failed_after_timelock() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    let poll_id = passed_poll(&mut deps);
+
+    let poll = Poll::load(&deps.storage, &poll_id).unwrap();
+    let result = exec(
+        &mut deps,
+        governance_env_height(poll.end_height + POLL_EXECUTION_DELAY_PERIOD),
+        mock_info(GUARDIAN, &[]),
+        poll_id,
+    );
+
+    expect_generic_err(&result, "Execution delay period has expired");
+}
+
+#[test]
+fn failed_invalid_permission() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    let poll_id = passed_poll(&mut deps);
+
+    let poll = Poll::load(&deps.storage, &poll_id).unwrap();
+    let result = exec(
+        &mut deps,
+        governance_env_height(poll.end_height + 1),
+        mock_info(PROPOSER1, &[]),
+        poll_id,
+    );
+
+    expect_unauthorized_err(&result);
+}
@@ -1,6 +1,6 @@
 use std::cmp::max;
 
-use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response, StdError, Uint128, SubMsg};
+use cosmwasm_std::{Addr, Decimal, DepsMut, Env, MessageInfo, Response, StdError, StdResult, Storage, Uint128, SubMsg};
 
 use valkyrie::common::ContractResult;
 use valkyrie::errors::ContractError;
@@ -19,15 +19,22 @@ pub fn instantiate(
     _info: MessageInfo,
     msg: StakingConfigInitMsg,
 ) -> ContractResult<Response> {
+    // Validate
+    validate_max_lock_multiplier(msg.max_lock_multiplier)?;
+
     // Execute
     let response = make_response("instantiate");
 
     StakingConfig {
         distributor: msg.distributor.map(|d| deps.api.addr_validate(d.as_str())).transpose()?,
+        unbonding_period: msg.unbonding_period,
+        max_lock_duration: msg.max_lock_duration,
+        max_lock_multiplier: msg.max_lock_multiplier,
     }.save(deps.storage)?;
 
     StakingState {
         total_share: Uint128::zero(),
+        total_unbonding: Uint128::zero(),
     }.save(deps.storage)?;
 
     Ok(response)
@@ -38,6 +45,9 @@ pub fn update_staking_config(
     env: Env,
     info: MessageInfo,
     distributor: Option<String>,
+    unbonding_period: Option<u64>,
+    max_lock_duration: Option<u64>,
+    max_lock_multiplier: Option<Decimal>,
 ) -> ContractResult<Response> {
     // Validate
     if env.contract.address != info.sender {
@@ -54,6 +64,22 @@ pub fn update_staking_config(
         response = response.add_attribute("is_updated_distributor", "true");
     }
 
+    if let Some(unbonding_period) = unbonding_period {
+        config.unbonding_period = unbonding_period;
+        response = response.add_attribute("is_updated_unbonding_period", "true");
+    }
+
+    if let Some(max_lock_duration) = max_lock_duration {
+        config.max_lock_duration = max_lock_duration;
+        response = response.add_attribute("is_updated_max_lock_duration", "true");
+    }
+
+    if let Some(max_lock_multiplier) = max_lock_multiplier {
+        validate_max_lock_multiplier(max_lock_multiplier)?;
+        config.max_lock_multiplier = max_lock_multiplier;
+        response = response.add_attribute("is_updated_max_lock_multiplier", "true");
+    }
+
     config.save(deps.storage)?;
 
     Ok(response)
@@ -133,10 +159,16 @@ pub fn stake_governance_token_hook(
     };
 
     staking_state.total_share += share;
-    staking_state.save(deps.storage)?;
+    staking_state.save_at(deps.storage, env.block.height, contract_available_balance + amount)?;
 
     staker_state.share += share;
-    staker_state.save(deps.storage)?;
+    staker_state.save_at(deps.storage, env.block.height)?;
+
+    if let Some(delegate) = staker_state.delegate.as_ref() {
+        let mut delegate_state = StakerState::load_safe(deps.storage, delegate)?;
+        delegate_state.delegated_share += share;
+        delegate_state.save_at(deps.storage, env.block.height)?;
+    }
 
     response = response.add_attribute("sender", sender.as_str());
     response = response.add_attribute("share", share.to_string());
@@ -199,21 +231,30 @@ pub fn unstake_governance_token_hook(
     let mut staking_state = StakingState::load(deps.storage)?;
 
     staker_state.clean_votes(deps.storage);
+    staker_state.clean_locks(env.block.height);
 
     let contract_available_balance = load_available_balance(deps.as_ref(), env.block.height)?;
     let total_share = staking_state.total_share;
-    let locked_balance = staker_state.get_locked_balance();
-    let locked_share = locked_balance.multiply_ratio(
-        total_share,
-        contract_available_balance,
+    // the delegate's in-progress votes may be using a part of the balance
+    let locked_balance = max(
+        staker_state.get_locked_balance(),
+        staker_state.load_delegation_locked_balance(deps.storage, env.block.height)?,
+    );
+    // locked positions stay staked until unlocked
+    let locked_share = max(
+        locked_balance.multiply_ratio(total_share, contract_available_balance),
+        staker_state.load_locked_share(env.block.height),
     );
     let user_share = staker_state.share;
-    let withdraw_share = amount.map(|v| {
-        max(
+    let withdraw_share = match amount {
+        Some(v) => max(
             v.multiply_ratio(total_share, contract_available_balance),
             Uint128::new(1u128),
-        )
-    }).unwrap_or_else(|| user_share.checked_sub(locked_share).unwrap());
+        ),
+        None => user_share.checked_sub(locked_share).map_err(|_| ContractError::Std(StdError::generic_err(
+            "User is trying to unstake too many tokens.",
+        )))?,
+    };
     let withdraw_amount = amount.unwrap_or_else(|| {
         withdraw_share.multiply_ratio(contract_available_balance, total_share)
     });
@@ -224,21 +265,225 @@ pub fn unstake_governance_token_hook(
         )));
     }
 
+    // unstaked amount is held until the unbonding period passes
+    let staking_config = StakingConfig::load(deps.storage)?;
+    let release_height = if staking_config.unbonding_period == 0 {
+        let contract_config = ContractConfig::load(deps.storage)?;
+        response = response.add_message(message_factories::cw20_transfer(
+            &contract_config.governance_token,
+            &sender,
+            withdraw_amount,
+        ));
+
+        None
+    } else {
+        let release_height = env.block.height + staking_config.unbonding_period;
+        staker_state.unbond(release_height, withdraw_amount);
+        staking_state.total_unbonding += withdraw_amount;
+
+        Some(release_height)
+    };
+
     staker_state.share = user_share.checked_sub(withdraw_share)?;
-    staker_state.save(deps.storage)?;
+    staker_state.save_at(deps.storage, env.block.height)?;
+
+    if let Some(delegate) = staker_state.delegate.as_ref() {
+        let mut delegate_state = StakerState::load(deps.storage, delegate)?;
+        delegate_state.delegated_share = delegate_state.delegated_share.checked_sub(withdraw_share)?;
+        delegate_state.save_at(deps.storage, env.block.height)?;
+    }
 
     staking_state.total_share = total_share.checked_sub(withdraw_share)?;
+    staking_state.save_at(deps.storage, env.block.height, contract_available_balance.checked_sub(withdraw_amount)?)?;
+
+    response = response.add_attribute("unstake_amount", withdraw_amount);
+    response = response.add_attribute("unstake_share", withdraw_share);
+    if let Some(release_height) = release_height {
+        response = response.add_attribute("release_height", release_height.to_string());
+    }
+
+    Ok(response)
+}
+
+pub fn claim_unbonded(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> ContractResult<Response> {
+    // Validate
+    let mut staker_state = StakerState::may_load(deps.storage, &info.sender)?
+        .ok_or(ContractError::Std(StdError::generic_err("Nothing to claim")))?;
+
+    let claim_amount = staker_state.release_unbondings(env.block.height);
+    if claim_amount.is_zero() {
+        return Err(ContractError::Std(StdError::generic_err("Nothing to claim")));
+    }
+
+    // Execute
+    let mut response = make_response("claim_unbonded");
+
+    staker_state.save(deps.storage)?;
+
+    let mut staking_state = StakingState::load(deps.storage)?;
+    staking_state.total_unbonding = staking_state.total_unbonding.checked_sub(claim_amount)?;
     staking_state.save(deps.storage)?;
 
     let contract_config = ContractConfig::load(deps.storage)?;
     response = response.add_message(message_factories::cw20_transfer(
         &contract_config.governance_token,
-        &sender,
-        withdraw_amount,
+        &info.sender,
+        claim_amount,
     ));
 
-    response = response.add_attribute("unstake_amount", withdraw_amount);
-    response = response.add_attribute("unstake_share", withdraw_share);
+    response = response.add_attribute("staker", info.sender.as_str());
+    response = response.add_attribute("amount", claim_amount.to_string());
 
     Ok(response)
 }
+
+pub fn lock_stake(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+    duration: u64,
+) -> ContractResult<Response> {
+    // Validate
+    let staking_config = StakingConfig::load(deps.storage)?;
+
+    if duration == 0 || duration > staking_config.max_lock_duration {
+        return Err(ContractError::Std(StdError::generic_err(
+            format!("Lock duration must be 1 to {}", staking_config.max_lock_duration),
+        )));
+    }
+
+    let mut staker_state = StakerState::may_load(deps.storage, &info.sender)?
+        .ok_or(ContractError::Std(StdError::generic_err("Nothing staked")))?;
+    staker_state.clean_locks(env.block.height);
+
+    let staking_state = StakingState::load(deps.storage)?;
+    let contract_available_balance = load_available_balance(deps.as_ref(), env.block.height)?;
+
+    let share = if contract_available_balance.is_zero() {
+        Uint128::zero()
+    } else {
+        amount.multiply_ratio(staking_state.total_share, contract_available_balance)
+    };
+
+    if share.is_zero() {
+        return Err(ContractError::Std(StdError::generic_err("Insufficient lock amount")));
+    }
+
+    if staker_state.load_locked_share(env.block.height) + share > staker_state.share {
+        return Err(ContractError::Std(StdError::generic_err(
+            "User is trying to lock too many tokens.",
+        )));
+    }
+
+    // Execute
+    let mut response = make_response("lock_stake");
+
+    let unlock_height = env.block.height + duration;
+    staker_state.lock(unlock_height, share);
+    staker_state.save_at(deps.storage, env.block.height)?;
+
+    response = response.add_attribute("staker", info.sender.as_str());
+    response = response.add_attribute("amount", amount.to_string());
+    response = response.add_attribute("share", share.to_string());
+    response = response.add_attribute("unlock_height", unlock_height.to_string());
+
+    Ok(response)
+}
+
+pub fn delegate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    to: String,
+) -> ContractResult<Response> {
+    // Validate
+    let to = deps.api.addr_validate(to.as_str())?;
+    if to == info.sender {
+        return Err(ContractError::Std(StdError::generic_err("Cannot delegate to oneself")));
+    }
+
+    let mut staker_state = StakerState::load_safe(deps.storage, &info.sender)?;
+    validate_delegation_change(deps.storage, &mut staker_state)?;
+
+    // Execute
+    let mut response = make_response("delegate");
+
+    if let Some(prev_delegate) = staker_state.delegate.as_ref() {
+        let mut prev_delegate_state = StakerState::load(deps.storage, prev_delegate)?;
+        prev_delegate_state.delegated_share = prev_delegate_state.delegated_share.checked_sub(staker_state.share)?;
+        prev_delegate_state.save_at(deps.storage, env.block.height)?;
+
+        response = response.add_attribute("prev_delegate", prev_delegate.as_str());
+    }
+
+    let mut delegate_state = StakerState::load_safe(deps.storage, &to)?;
+    delegate_state.delegated_share += staker_state.share;
+    delegate_state.save_at(deps.storage, env.block.height)?;
+
+    staker_state.delegate = Some(to.clone());
+    staker_state.save_at(deps.storage, env.block.height)?;
+
+    response = response.add_attribute("delegator", info.sender.as_str());
+    response = response.add_attribute("delegate", to.as_str());
+    response = response.add_attribute("share", staker_state.share.to_string());
+
+    Ok(response)
+}
+
+pub fn undelegate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> ContractResult<Response> {
+    // Validate
+    let mut staker_state = StakerState::load_safe(deps.storage, &info.sender)?;
+    validate_delegation_change(deps.storage, &mut staker_state)?;
+
+    let delegate = staker_state.delegate.clone()
+        .ok_or_else(|| StdError::generic_err("Not delegated"))?;
+
+    // Execute
+    let mut response = make_response("undelegate");
+
+    let mut delegate_state = StakerState::load(deps.storage, &delegate)?;
+    delegate_state.delegated_share = delegate_state.delegated_share.checked_sub(staker_state.share)?;
+    delegate_state.save_at(deps.storage, env.block.height)?;
+
+    staker_state.delegate = None;
+    staker_state.save_at(deps.storage, env.block.height)?;
+
+    response = response.add_attribute("delegator", info.sender.as_str());
+    response = response.add_attribute("delegate", delegate.as_str());
+    response = response.add_attribute("share", staker_state.share.to_string());
+
+    Ok(response)
+}
+
+// Delegation is fixed while the delegator or its delegate takes part in an in-progress poll
+fn validate_delegation_change(storage: &dyn Storage, staker_state: &mut StakerState) -> StdResult<()> {
+    staker_state.clean_votes(storage);
+    if !staker_state.votes.is_empty() {
+        return Err(StdError::generic_err("Cannot change delegation while voting on an in-progress poll"));
+    }
+
+    if let Some(delegate) = staker_state.delegate.as_ref() {
+        if StakerState::load_safe(storage, delegate)?.has_delegated_votes(storage) {
+            return Err(StdError::generic_err("Cannot change delegation while the delegate is voting on an in-progress poll"));
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_max_lock_multiplier(max_lock_multiplier: Decimal) -> StdResult<()> {
+    if max_lock_multiplier < Decimal::one() {
+        Err(StdError::generic_err("max_lock_multiplier must be at least 1"))
+    } else {
+        Ok(())
+    }
+}
@@ -1,8 +1,8 @@
-use cosmwasm_std::{Decimal, Deps, Env};
+use cosmwasm_std::{Decimal, Deps, Env, Uint128};
 
 use valkyrie::common::ContractResult;
 use valkyrie::governance::models::VoteInfoMsg;
-use valkyrie::governance::query_msgs::{AllStakersResponse, StakerInfoResponse, StakerStateResponse, StakingStateResponse, VotingPowerResponse};
+use valkyrie::governance::query_msgs::{AllStakersResponse, StakerInfoResponse, StakerStateAtResponse, StakerStateResponse, StakingStateResponse, VotingPowerResponse};
 
 use crate::common::states::load_available_balance;
 
@@ -20,6 +20,7 @@ pub fn get_staking_state(deps: Deps, env: Env) -> ContractResult<StakingStateRes
     Ok(StakingStateResponse {
         total_share: staking_state.total_share,
         total_balance: load_available_balance(deps.clone(), env.block.height)?,
+        total_unbonding: staking_state.total_unbonding,
     })
 }
 
@@ -50,6 +51,8 @@ pub fn get_staker_state(
                 voter: vote.voter.to_string(),
                 option: vote.option.clone(),
                 amount: vote.amount,
+                delegated_amount: vote.delegated_amount,
+                votes: vote.weighted_votes(),
             };
 
             (*poll_id, msg)
@@ -60,20 +63,64 @@ pub fn get_staker_state(
         balance,
         share: staker_state.share,
         votes,
+        delegated_in_amount: staker_state.load_delegated_balance(deps.storage, contract_available_balance)?,
+        delegated_out_amount: if staker_state.delegate.is_some() { balance } else { Uint128::zero() },
+        delegate: staker_state.delegate.map(|d| d.to_string()),
+        unbondings: staker_state.unbondings,
+        locks: staker_state.locks,
+    })
+}
+
+pub fn get_staker_state_at(
+    deps: Deps,
+    env: Env,
+    address: String,
+    height: u64,
+) -> ContractResult<StakerStateAtResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let staker_state = StakerState::load_safe(deps.storage, &address)?;
+    let checkpoint = staker_state.load_checkpoint_at(deps.storage, height)?;
+
+    let contract_available_balance = load_available_balance(deps, env.block.height)?;
+    let staking_checkpoint = StakingState::load_checkpoint_at(deps.storage, height, contract_available_balance)?;
+    let balance = staking_checkpoint.share_to_balance(checkpoint.share);
+
+    Ok(StakerStateAtResponse {
+        height,
+        balance,
+        share: checkpoint.share,
+        total_share: staking_checkpoint.total_share,
+        delegated_in_amount: staking_checkpoint.share_to_balance(checkpoint.delegated_share),
+        delegated_out_amount: if checkpoint.delegate.is_some() { balance } else { Uint128::zero() },
+        delegate: checkpoint.delegate.map(|d| d.to_string()),
     })
 }
 
 pub fn get_voting_power(
     deps: Deps,
-    _env: Env,
+    env: Env,
     address: String,
 ) -> ContractResult<VotingPowerResponse> {
     let address = deps.api.addr_validate(&address)?;
+    let staking_config = StakingConfig::load(deps.storage)?;
     let staking_state: StakingState = StakingState::load(deps.storage)?;
     let staker_state: StakerState = StakerState::load(deps.storage, &address)?;
 
+    let boost_share: Uint128 = staker_state.locks.iter()
+        .map(|(unlock_height, share)| staking_config.lock_boost(*share, *unlock_height, env.block.height))
+        .sum();
+
+    let delegated_out_share = if staker_state.delegate.is_some() {
+        staker_state.share
+    } else {
+        Uint128::zero()
+    };
+
     Ok(VotingPowerResponse {
-        voting_power: Decimal::from_ratio(staker_state.share, staking_state.total_share),
+        voting_power: Decimal::from_ratio(staker_state.share + boost_share, staking_state.total_share),
+        boost_voting_power: Decimal::from_ratio(boost_share, staking_state.total_share),
+        delegated_in_voting_power: Decimal::from_ratio(staker_state.delegated_share, staking_state.total_share),
+        delegated_out_voting_power: Decimal::from_ratio(delegated_out_share, staking_state.total_share),
     })
 }
 
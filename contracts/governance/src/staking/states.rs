@@ -1,4 +1,6 @@
-use cosmwasm_std::{Addr, Order, StdResult, Storage, Uint128};
+use std::cmp::{max, min};
+
+use cosmwasm_std::{Addr, Decimal, Order, StdResult, Storage, Uint128};
 use cw_storage_plus::{Bound, Item, Map};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -13,6 +15,14 @@ const STAKING_CONFIG: Item<StakingConfig> = Item::new("staking-config");
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct StakingConfig {
     pub distributor: Option<Addr>,
+    // default for configs saved before unbonding
+    #[serde(default)]
+    pub unbonding_period: u64,
+    // defaults for configs saved before vote-escrow locking
+    #[serde(default)]
+    pub max_lock_duration: u64,
+    #[serde(default)]
+    pub max_lock_multiplier: Decimal,
 }
 
 impl StakingConfig {
@@ -23,13 +33,30 @@ impl StakingConfig {
     pub fn load(storage: &dyn Storage) -> StdResult<StakingConfig> {
         STAKING_CONFIG.load(storage)
     }
+
+    /// Share equivalent of the boost on a locked share.
+    /// Boost is the max multiplier at the max lock duration and decays linearly to zero at unlock.
+    pub fn lock_boost(&self, share: Uint128, unlock_height: u64, height: u64) -> Uint128 {
+        if self.max_lock_duration == 0 || self.max_lock_multiplier <= Decimal::one() || unlock_height <= height {
+            return Uint128::zero()
+        }
+
+        let remain_duration = min(unlock_height - height, self.max_lock_duration);
+        let max_boost = share * (self.max_lock_multiplier - Decimal::one());
+
+        max_boost.multiply_ratio(remain_duration, self.max_lock_duration)
+    }
 }
 
 const STAKING_STATE: Item<StakingState> = Item::new("staking-state");
+const STAKING_CHECKPOINTS: Map<&[u8], StakingCheckpoint> = Map::new("staking-checkpoint");
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct StakingState {
     pub total_share: Uint128,
+    // default for states saved before unbonding
+    #[serde(default)]
+    pub total_unbonding: Uint128, // unstaked but not claimed yet
 }
 
 impl StakingState {
@@ -40,10 +67,89 @@ impl StakingState {
     pub fn load(storage: &dyn Storage) -> StdResult<StakingState> {
         STAKING_STATE.load(storage)
     }
+
+    /// Saves with a checkpoint of the total share and the contract available balance at the height.
+    pub fn save_at(&self, storage: &mut dyn Storage, height: u64, contract_available_balance: Uint128) -> StdResult<()> {
+        // state saved before checkpoints becomes the initial checkpoint at the same balance per share
+        let has_checkpoint = STAKING_CHECKPOINTS.range(storage, None, None, Order::Ascending).next().is_some();
+        if !has_checkpoint {
+            if let Some(prev) = STAKING_STATE.may_load(storage)? {
+                let prev_available_balance = if self.total_share.is_zero() {
+                    Uint128::zero()
+                } else {
+                    contract_available_balance.multiply_ratio(prev.total_share, self.total_share)
+                };
+
+                STAKING_CHECKPOINTS.save(storage, &0u64.to_be_bytes(), &StakingCheckpoint {
+                    total_share: prev.total_share,
+                    available_balance: prev_available_balance,
+                })?;
+            }
+        }
+
+        STAKING_CHECKPOINTS.save(storage, &height.to_be_bytes(), &StakingCheckpoint {
+            total_share: self.total_share,
+            available_balance: contract_available_balance,
+        })?;
+        self.save(storage)
+    }
+
+    pub fn has_checkpoint_after(storage: &dyn Storage, height: u64) -> bool {
+        STAKING_CHECKPOINTS.range(
+            storage,
+            Some(Bound::exclusive(height.to_be_bytes().to_vec())),
+            None,
+            Order::Ascending,
+        ).next().is_some()
+    }
+
+    pub fn load_checkpoint_at(storage: &dyn Storage, height: u64, contract_available_balance: Uint128) -> StdResult<StakingCheckpoint> {
+        let checkpoint = STAKING_CHECKPOINTS.range(
+            storage,
+            None,
+            Some(Bound::inclusive(height.to_be_bytes().to_vec())),
+            Order::Descending,
+        ).next().transpose()?;
+
+        match checkpoint {
+            Some((_, checkpoint)) => Ok(checkpoint),
+            None => Ok(StakingCheckpoint {
+                total_share: StakingState::load(storage)?.total_share,
+                available_balance: contract_available_balance,
+            }),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct StakingCheckpoint {
+    pub total_share: Uint128,
+    pub available_balance: Uint128,
+}
+
+impl StakingCheckpoint {
+    pub fn share_to_balance(&self, share: Uint128) -> Uint128 {
+        if self.total_share.is_zero() {
+            return Uint128::zero()
+        }
+
+        share.multiply_ratio(self.available_balance, self.total_share)
+    }
 }
 
 
 const STAKER_STATES: Map<&Addr, StakerState> = Map::new("staker-state");
+const STAKER_CHECKPOINTS: Map<(&[u8], &[u8]), StakerCheckpoint> = Map::new("staker-checkpoint");
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct StakerCheckpoint {
+    pub share: Uint128,
+    pub delegated_share: Uint128,
+    pub delegate: Option<Addr>,
+    // default for checkpoints saved before vote-escrow locking
+    #[serde(default)]
+    pub locks: Vec<(u64, Uint128)>,
+}
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct StakerState {
@@ -51,6 +157,17 @@ pub struct StakerState {
     pub share: Uint128,
     // total staked balance
     pub votes: Vec<(u64, VoteInfo)>, // maps poll_id to weight voted
+    // defaults for stakers saved before delegation
+    #[serde(default)]
+    pub delegate: Option<Addr>,
+    #[serde(default)]
+    pub delegated_share: Uint128, // total share delegated in
+    // default for stakers saved before unbonding
+    #[serde(default)]
+    pub unbondings: Vec<(u64, Uint128)>, // (release height, amount)
+    // default for stakers saved before vote-escrow locking
+    #[serde(default)]
+    pub locks: Vec<(u64, Uint128)>, // (unlock height, share)
 }
 
 impl StakerState {
@@ -59,6 +176,10 @@ impl StakerState {
             address: address.clone(),
             share: Uint128::zero(),
             votes: vec![],
+            delegate: None,
+            delegated_share: Uint128::zero(),
+            unbondings: vec![],
+            locks: vec![],
         }
     }
 
@@ -70,6 +191,57 @@ impl StakerState {
         STAKER_STATES.load(storage, address)
     }
 
+    /// Saves with a checkpoint of the shares and delegate at the height.
+    pub fn save_at(&self, storage: &mut dyn Storage, height: u64) -> StdResult<()> {
+        let address = self.address.as_str().as_bytes();
+
+        // state saved before checkpoints becomes the initial checkpoint
+        if !self.has_checkpoint(storage) {
+            if let Some(prev) = STAKER_STATES.may_load(storage, &self.address)? {
+                STAKER_CHECKPOINTS.save(storage, (address, &0u64.to_be_bytes()), &prev.checkpoint())?;
+            }
+        }
+
+        STAKER_CHECKPOINTS.save(storage, (address, &height.to_be_bytes()), &self.checkpoint())?;
+        self.save(storage)
+    }
+
+    fn has_checkpoint(&self, storage: &dyn Storage) -> bool {
+        STAKER_CHECKPOINTS.prefix(self.address.as_str().as_bytes())
+            .range(storage, None, None, Order::Ascending)
+            .next()
+            .is_some()
+    }
+
+    fn checkpoint(&self) -> StakerCheckpoint {
+        StakerCheckpoint {
+            share: self.share,
+            delegated_share: self.delegated_share,
+            delegate: self.delegate.clone(),
+            locks: self.locks.clone(),
+        }
+    }
+
+    pub fn load_checkpoint_at(&self, storage: &dyn Storage, height: u64) -> StdResult<StakerCheckpoint> {
+        let mut checkpoints = STAKER_CHECKPOINTS.prefix(self.address.as_str().as_bytes()).range(
+            storage,
+            None,
+            Some(Bound::inclusive(height.to_be_bytes().to_vec())),
+            Order::Descending,
+        );
+
+        if let Some((_, checkpoint)) = checkpoints.next().transpose()? {
+            return Ok(checkpoint);
+        }
+
+        // not staked yet at the height, or never changed since checkpoints were introduced
+        if self.has_checkpoint(storage) {
+            Ok(StakerState::default(&self.address).checkpoint())
+        } else {
+            Ok(self.checkpoint())
+        }
+    }
+
     pub fn may_load(storage: &dyn Storage, address: &Addr) -> StdResult<Option<StakerState>> {
         STAKER_STATES.may_load(storage, address)
     }
@@ -111,22 +283,155 @@ impl StakerState {
         return Ok(staker_balance);
     }
 
+    /// Balance by the share at the height.
+    /// The contract available balance is used when nothing is checkpointed yet.
+    pub fn load_balance_at(&self, storage: &dyn Storage, contract_available_balance: Uint128, height: u64) -> StdResult<Uint128> {
+        let staking_checkpoint = StakingState::load_checkpoint_at(storage, height, contract_available_balance)?;
+
+        Ok(staking_checkpoint.share_to_balance(self.load_checkpoint_at(storage, height)?.share))
+    }
+
+    pub fn load_delegated_balance_at(&self, storage: &dyn Storage, contract_available_balance: Uint128, height: u64) -> StdResult<Uint128> {
+        let staking_checkpoint = StakingState::load_checkpoint_at(storage, height, contract_available_balance)?;
+
+        Ok(staking_checkpoint.share_to_balance(self.load_checkpoint_at(storage, height)?.delegated_share))
+    }
+
+    /// Boost of the locked shares at the height, in balance.
+    pub fn load_boost_at(&self, storage: &dyn Storage, contract_available_balance: Uint128, height: u64) -> StdResult<Uint128> {
+        let staking_config = StakingConfig::load(storage)?;
+        let staking_checkpoint = StakingState::load_checkpoint_at(storage, height, contract_available_balance)?;
+
+        let boost_share = self.load_checkpoint_at(storage, height)?.locks.iter()
+            .map(|(unlock_height, share)| staking_config.lock_boost(*share, *unlock_height, height))
+            .sum();
+
+        Ok(staking_checkpoint.share_to_balance(boost_share))
+    }
+
+    /// Own voting balance at the height, including the boost of the locked shares.
+    pub fn load_voting_balance_at(&self, storage: &dyn Storage, contract_available_balance: Uint128, height: u64) -> StdResult<Uint128> {
+        Ok(self.load_balance_at(storage, contract_available_balance, height)?
+            + self.load_boost_at(storage, contract_available_balance, height)?)
+    }
+
+    pub fn load_delegated_balance(&self, storage: &dyn Storage, contract_available_balance: Uint128) -> StdResult<Uint128> {
+        let staking_state = StakingState::load(storage)?;
+
+        if staking_state.total_share.is_zero() {
+            return Ok(Uint128::zero())
+        }
+
+        Ok(self.delegated_share.multiply_ratio(
+            contract_available_balance,
+            staking_state.total_share,
+        ))
+    }
+
+    // whether any in-progress vote of this staker uses delegated balance
+    pub fn has_delegated_votes(&self, storage: &dyn Storage) -> bool {
+        self.votes.iter()
+            .filter(|(_, v)| !v.delegated_amount.is_zero())
+            .any(|(poll_id, _)| {
+                Poll::load(storage, poll_id).ok()
+                    .map(|p| p.status == PollStatus::InProgress)
+                    .unwrap_or(false)
+            })
+    }
+
+    // the largest share of the delegate's in-progress delegated votes backed by this staker
+    pub fn load_delegation_locked_balance(&self, storage: &dyn Storage, block_height: u64) -> StdResult<Uint128> {
+        let delegate = match self.delegate.as_ref() {
+            Some(delegate) => delegate,
+            None => return Ok(Uint128::zero()),
+        };
+
+        let delegate_state = StakerState::load_safe(storage, delegate)?;
+
+        let mut locked_balance = Uint128::zero();
+        for (poll_id, vote) in delegate_state.votes.iter() {
+            if vote.delegated_amount.is_zero() {
+                continue;
+            }
+
+            let poll = match Poll::load(storage, poll_id) {
+                Ok(poll) if poll.status == PollStatus::InProgress => poll,
+                _ => continue,
+            };
+
+            let height = poll.voting_height(block_height);
+            let checkpoint = self.load_checkpoint_at(storage, height)?;
+            let delegated_share = delegate_state.load_checkpoint_at(storage, height)?.delegated_share;
+            if checkpoint.delegate.as_ref() != Some(delegate) || delegated_share.is_zero() {
+                continue;
+            }
+
+            locked_balance = max(
+                locked_balance,
+                vote.delegated_amount.multiply_ratio(checkpoint.share, delegated_share),
+            );
+        }
+
+        Ok(locked_balance)
+    }
+
     // removes not in-progress poll voter info & unlock tokens
     // and returns the largest locked amount in participated polls.
     pub fn get_locked_balance(&self) -> Uint128 {
         self.votes.iter()
-            .map(|(_, v)| v.amount)
+            .map(|(_, v)| {
+                // delegated and boosted parts of the vote are not backed by the own balance
+                v.amount.checked_sub(v.delegated_amount)
+                    .and_then(|amount| amount.checked_sub(v.boost_amount))
+                    .unwrap_or_default()
+            })
             .max()
             .unwrap_or_default()
     }
 
-    pub fn can_vote(&self, storage: &dyn Storage, contract_available_balance: Uint128, amount: Uint128) -> StdResult<bool> {
-        let balance = self.load_balance(storage, contract_available_balance)?;
+    pub fn can_vote(&self, storage: &dyn Storage, contract_available_balance: Uint128, height: u64, amount: Uint128, delegated_balance: Uint128) -> StdResult<bool> {
+        let balance = self.load_voting_balance_at(storage, contract_available_balance, height)?;
 
-        Ok(balance >= amount)
+        Ok(balance + delegated_balance >= amount)
+    }
+
+    pub fn lock(&mut self, unlock_height: u64, share: Uint128) {
+        self.locks.push((unlock_height, share));
+    }
+
+    // removes unlocked positions
+    pub fn clean_locks(&mut self, height: u64) {
+        self.locks.retain(|(unlock_height, _)| *unlock_height > height);
+    }
+
+    pub fn load_locked_share(&self, height: u64) -> Uint128 {
+        self.locks.iter()
+            .filter(|(unlock_height, _)| *unlock_height > height)
+            .map(|(_, share)| *share)
+            .sum()
+    }
+
+    pub fn unbond(&mut self, release_height: u64, amount: Uint128) {
+        self.unbondings.push((release_height, amount));
+    }
+
+    // removes released unbondings and returns the released amount
+    pub fn release_unbondings(&mut self, height: u64) -> Uint128 {
+        let released_amount = self.unbondings.iter()
+            .filter(|(release_height, _)| *release_height <= height)
+            .map(|(_, amount)| *amount)
+            .sum();
+
+        self.unbondings.retain(|(release_height, _)| *release_height > height);
+
+        released_amount
     }
 
     pub fn vote(&mut self, poll_id: u64, vote: VoteInfo) {
         self.votes.push((poll_id, vote));
     }
+
+    pub fn retract_vote(&mut self, poll_id: u64) {
+        self.votes.retain(|(id, _)| *id != poll_id);
+    }
 }
@@ -0,0 +1,94 @@
+use cosmwasm_std::{Addr, CosmosMsg, Env, MessageInfo, Response, SubMsg, to_binary, Uint128, WasmMsg};
+use cosmwasm_std::testing::mock_info;
+use cw20::Cw20ExecuteMsg;
+
+use valkyrie::common::ContractResult;
+use valkyrie::mock_querier::{custom_deps, CustomDeps};
+use valkyrie::test_constants::governance::{governance_env, governance_env_height, GOVERNANCE_TOKEN};
+use valkyrie::test_utils::expect_generic_err;
+
+use crate::staking::executions::claim_unbonded;
+use crate::staking::queries::{get_staker_state, get_staking_state};
+use crate::staking::states::{StakerState, StakingState};
+use crate::tests::init_default;
+
+use super::stake_governance_token_hook::{STAKER1, STAKER1_STAKE_AMOUNT, STAKER2, STAKER2_STAKE_AMOUNT};
+
+const UNBONDING_PERIOD: u64 = 100;
+
+pub fn exec(deps: &mut CustomDeps, env: Env, info: MessageInfo) -> ContractResult<Response> {
+    claim_unbonded(deps.as_mut(), env, info)
+}
+
+pub fn will_success(deps: &mut CustomDeps, staker: &str, height: u64) -> (Env, MessageInfo, Response) {
+    let env = governance_env_height(height);
+    let info = mock_info(staker, &[]);
+
+    let response = exec(deps, env.clone(), info.clone()).unwrap();
+
+    (env, info, response)
+}
+
+#[test]
+fn succeed() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+    super::update_staking_config::will_success(&mut deps, None, Some(UNBONDING_PERIOD), None, None);
+
+    super::stake_governance_token_hook::will_success(&mut deps, STAKER1, STAKER1_STAKE_AMOUNT);
+    super::stake_governance_token_hook::will_success(&mut deps, STAKER2, STAKER2_STAKE_AMOUNT);
+
+    let (env, _, response) = super::unstake_governance_token_hook::will_success(&mut deps, STAKER1, None);
+    assert_eq!(response.messages, vec![]);
+
+    let release_height = env.block.height + UNBONDING_PERIOD;
+    let staker_state = StakerState::load(&deps.storage, &Addr::unchecked(STAKER1)).unwrap();
+    assert_eq!(staker_state.unbondings, vec![(release_height, STAKER1_STAKE_AMOUNT)]);
+
+    // unbonding tokens are excluded from the available balance and the voting power
+    let staking_state = get_staking_state(deps.as_ref(), governance_env()).unwrap();
+    assert_eq!(staking_state.total_unbonding, STAKER1_STAKE_AMOUNT);
+    assert_eq!(staking_state.total_balance, STAKER2_STAKE_AMOUNT);
+
+    let staker_state = get_staker_state(deps.as_ref(), governance_env(), STAKER1.to_string()).unwrap();
+    assert_eq!(staker_state.balance, Uint128::zero());
+    let staker_state = get_staker_state(deps.as_ref(), governance_env(), STAKER2.to_string()).unwrap();
+    assert_eq!(staker_state.balance, STAKER2_STAKE_AMOUNT);
+
+    let result = exec(&mut deps, governance_env_height(release_height - 1), mock_info(STAKER1, &[]));
+    expect_generic_err(&result, "Nothing to claim");
+
+    let (_, _, response) = will_success(&mut deps, STAKER1, release_height);
+    assert_eq!(response.messages, vec![
+        SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: GOVERNANCE_TOKEN.to_string(),
+            funds: vec![],
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: STAKER1.to_string(),
+                amount: STAKER1_STAKE_AMOUNT,
+            }).unwrap(),
+        })),
+    ]);
+
+    let staker_state = StakerState::load(&deps.storage, &Addr::unchecked(STAKER1)).unwrap();
+    assert_eq!(staker_state.unbondings, vec![]);
+
+    let staking_state = StakingState::load(&deps.storage).unwrap();
+    assert_eq!(staking_state.total_unbonding, Uint128::zero());
+}
+
+#[test]
+fn failed_nothing_to_claim() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    let result = exec(&mut deps, governance_env(), mock_info(STAKER1, &[]));
+    expect_generic_err(&result, "Nothing to claim");
+
+    super::stake_governance_token_hook::will_success(&mut deps, STAKER1, STAKER1_STAKE_AMOUNT);
+
+    let result = exec(&mut deps, governance_env(), mock_info(STAKER1, &[]));
+    expect_generic_err(&result, "Nothing to claim");
+}
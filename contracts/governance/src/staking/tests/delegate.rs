@@ -0,0 +1,157 @@
+use cosmwasm_std::{Addr, Env, MessageInfo, Response, Uint128};
+use cosmwasm_std::testing::mock_info;
+
+use valkyrie::common::ContractResult;
+use valkyrie::governance::enumerations::VoteOption;
+use valkyrie::mock_querier::{custom_deps, CustomDeps};
+use valkyrie::test_constants::governance::{governance_env, governance_env_height};
+use valkyrie::test_utils::expect_generic_err;
+
+use crate::staking::executions::{delegate, undelegate};
+use crate::staking::queries::get_staker_state;
+use crate::staking::states::StakerState;
+use crate::tests::init_default;
+
+use super::stake_governance_token_hook::{STAKER1, STAKER1_STAKE_AMOUNT, STAKER2, STAKER2_STAKE_AMOUNT};
+
+pub const DELEGATE1: &str = "Delegate1";
+pub const DELEGATE2: &str = "Delegate2";
+
+pub fn exec(deps: &mut CustomDeps, env: Env, info: MessageInfo, to: &str) -> ContractResult<Response> {
+    delegate(deps.as_mut(), env, info, to.to_string())
+}
+
+pub fn will_success(deps: &mut CustomDeps, delegator: &str, to: &str) -> (Env, MessageInfo, Response) {
+    let env = governance_env();
+    let info = mock_info(delegator, &[]);
+
+    let response = exec(deps, env.clone(), info.clone(), to).unwrap();
+
+    (env, info, response)
+}
+
+pub fn exec_undelegate(deps: &mut CustomDeps, env: Env, info: MessageInfo) -> ContractResult<Response> {
+    undelegate(deps.as_mut(), env, info)
+}
+
+#[test]
+fn succeed() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    super::stake_governance_token_hook::will_success(&mut deps, STAKER1, STAKER1_STAKE_AMOUNT);
+    super::stake_governance_token_hook::will_success(&mut deps, STAKER2, STAKER2_STAKE_AMOUNT);
+
+    will_success(&mut deps, STAKER1, DELEGATE1);
+    will_success(&mut deps, STAKER2, DELEGATE1);
+
+    let delegate_state = StakerState::load(&deps.storage, &Addr::unchecked(DELEGATE1)).unwrap();
+    assert_eq!(delegate_state.delegated_share, STAKER1_STAKE_AMOUNT + STAKER2_STAKE_AMOUNT);
+
+    // redelegate
+    will_success(&mut deps, STAKER2, DELEGATE2);
+
+    let delegate_state = StakerState::load(&deps.storage, &Addr::unchecked(DELEGATE1)).unwrap();
+    assert_eq!(delegate_state.delegated_share, STAKER1_STAKE_AMOUNT);
+    let delegate_state = StakerState::load(&deps.storage, &Addr::unchecked(DELEGATE2)).unwrap();
+    assert_eq!(delegate_state.delegated_share, STAKER2_STAKE_AMOUNT);
+
+    // stake more
+    super::stake_governance_token_hook::will_success(&mut deps, STAKER1, Uint128::new(5));
+
+    let delegate_state = get_staker_state(deps.as_ref(), governance_env(), DELEGATE1.to_string()).unwrap();
+    assert_eq!(delegate_state.delegated_in_amount, STAKER1_STAKE_AMOUNT + Uint128::new(5));
+
+    let staker_state = get_staker_state(deps.as_ref(), governance_env(), STAKER1.to_string()).unwrap();
+    assert_eq!(staker_state.delegate, Some(DELEGATE1.to_string()));
+    assert_eq!(staker_state.delegated_out_amount, STAKER1_STAKE_AMOUNT + Uint128::new(5));
+
+    exec_undelegate(&mut deps, governance_env(), mock_info(STAKER1, &[])).unwrap();
+
+    let delegate_state = StakerState::load(&deps.storage, &Addr::unchecked(DELEGATE1)).unwrap();
+    assert_eq!(delegate_state.delegated_share, Uint128::zero());
+    let staker_state = StakerState::load(&deps.storage, &Addr::unchecked(STAKER1)).unwrap();
+    assert_eq!(staker_state.delegate, None);
+}
+
+#[test]
+fn failed_delegate_to_oneself() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    let result = exec(&mut deps, governance_env(), mock_info(STAKER1, &[]), STAKER1);
+    expect_generic_err(&result, "Cannot delegate to oneself");
+}
+
+#[test]
+fn failed_undelegate_not_delegated() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    super::stake_governance_token_hook::will_success(&mut deps, STAKER1, STAKER1_STAKE_AMOUNT);
+
+    let result = exec_undelegate(&mut deps, governance_env(), mock_info(STAKER1, &[]));
+    expect_generic_err(&result, "Not delegated");
+}
+
+#[test]
+fn failed_while_delegate_voting() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    crate::poll::tests::create_poll::default(&mut deps);
+    super::stake_governance_token_hook::will_success(&mut deps, STAKER1, STAKER1_STAKE_AMOUNT);
+    will_success(&mut deps, STAKER1, DELEGATE1);
+
+    crate::poll::tests::cast_vote::will_success(&mut deps, DELEGATE1, 1, VoteOption::Yes, STAKER1_STAKE_AMOUNT);
+
+    let result = exec_undelegate(&mut deps, governance_env(), mock_info(STAKER1, &[]));
+    expect_generic_err(&result, "Cannot change delegation while the delegate is voting on an in-progress poll");
+
+    let result = super::unstake_governance_token_hook::exec(
+        &mut deps,
+        governance_env(),
+        mock_info(governance_env().contract.address.as_str(), &[]),
+        STAKER1.to_string(),
+        Some(Uint128::new(1)),
+    );
+    expect_generic_err(&result, "User is trying to unstake too many tokens.");
+}
+
+#[test]
+fn succeed_unstake_over_delegation_lock() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    crate::poll::tests::create_poll::default(&mut deps);
+    super::stake_governance_token_hook::will_success(&mut deps, STAKER1, STAKER1_STAKE_AMOUNT);
+    super::stake_governance_token_hook::will_success(&mut deps, STAKER2, STAKER2_STAKE_AMOUNT);
+    will_success(&mut deps, STAKER1, DELEGATE1);
+    will_success(&mut deps, STAKER2, DELEGATE1);
+
+    // the vote uses a half of the delegated balance, so only a half of each delegator's balance is locked
+    crate::poll::tests::cast_vote::will_success(&mut deps, DELEGATE1, 1, VoteOption::Yes, STAKER1_STAKE_AMOUNT);
+
+    let env = governance_env_height(governance_env().block.height + 1);
+    super::unstake_governance_token_hook::exec(
+        &mut deps,
+        env.clone(),
+        mock_info(env.contract.address.as_str(), &[]),
+        STAKER1.to_string(),
+        Some(Uint128::new(5)),
+    ).unwrap();
+
+    let result = super::unstake_governance_token_hook::exec(
+        &mut deps,
+        env.clone(),
+        mock_info(env.contract.address.as_str(), &[]),
+        STAKER1.to_string(),
+        Some(Uint128::new(1)),
+    );
+    expect_generic_err(&result, "User is trying to unstake too many tokens.");
+}
@@ -1,9 +1,9 @@
-use cosmwasm_std::{Env, MessageInfo, Response, Uint128};
+use cosmwasm_std::{Decimal, Env, MessageInfo, Response, Uint128};
 
 use valkyrie::common::ContractResult;
 use valkyrie::mock_querier::{custom_deps, CustomDeps};
 use valkyrie::test_constants::default_sender;
-use valkyrie::test_constants::governance::governance_env;
+use valkyrie::test_constants::governance::{governance_env, STAKING_MAX_LOCK_DURATION, STAKING_MAX_LOCK_MULTIPLIER_PERCENT};
 
 use crate::staking::executions::instantiate;
 use crate::staking::states::StakingState;
@@ -17,6 +17,9 @@ pub fn exec(
 ) -> ContractResult<Response> {
     let msg = StakingConfigInitMsg {
         distributor,
+        unbonding_period: 0,
+        max_lock_duration: STAKING_MAX_LOCK_DURATION,
+        max_lock_multiplier: Decimal::percent(STAKING_MAX_LOCK_MULTIPLIER_PERCENT),
     };
 
     instantiate(deps.as_mut(), env, info, msg)
@@ -0,0 +1,210 @@
+use cosmwasm_std::{Addr, attr, Decimal, Env, MessageInfo, Response, Uint128};
+use cosmwasm_std::testing::mock_info;
+
+use valkyrie::common::ContractResult;
+use valkyrie::governance::enumerations::{PollStatus, VoteOption};
+use valkyrie::mock_querier::{custom_deps, CustomDeps};
+use valkyrie::test_constants::governance::{governance_env, governance_env_height, STAKING_MAX_LOCK_DURATION};
+use valkyrie::test_utils::expect_generic_err;
+
+use crate::poll::states::{Poll, PollResult};
+use crate::staking::executions::lock_stake;
+use crate::staking::queries::get_voting_power;
+use crate::staking::states::StakerState;
+use crate::tests::init_default;
+
+use super::stake_governance_token_hook::{STAKER1, STAKER2};
+
+pub fn exec(deps: &mut CustomDeps, env: Env, info: MessageInfo, amount: Uint128, duration: u64) -> ContractResult<Response> {
+    lock_stake(deps.as_mut(), env, info, amount, duration)
+}
+
+pub fn will_success(deps: &mut CustomDeps, staker: &str, amount: Uint128, duration: u64) -> (Env, MessageInfo, Response) {
+    let env = governance_env();
+    let info = mock_info(staker, &[]);
+
+    let response = exec(deps, env.clone(), info.clone(), amount, duration).unwrap();
+
+    (env, info, response)
+}
+
+#[test]
+fn succeed() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    super::stake_governance_token_hook::will_success(&mut deps, STAKER1, Uint128::new(100));
+    let (env, _, _) = will_success(&mut deps, STAKER1, Uint128::new(50), STAKING_MAX_LOCK_DURATION);
+
+    let unlock_height = env.block.height + STAKING_MAX_LOCK_DURATION;
+    let staker_state = StakerState::load(&deps.storage, &Addr::unchecked(STAKER1)).unwrap();
+    assert_eq!(staker_state.locks, vec![(unlock_height, Uint128::new(50))]);
+
+    // boost decays toward unlock
+    let voting_power = get_voting_power(deps.as_ref(), env.clone(), STAKER1.to_string()).unwrap();
+    assert_eq!(voting_power.voting_power, Decimal::percent(175));
+    assert_eq!(voting_power.boost_voting_power, Decimal::percent(75));
+
+    let half_env = governance_env_height(env.block.height + STAKING_MAX_LOCK_DURATION / 2);
+    let voting_power = get_voting_power(deps.as_ref(), half_env, STAKER1.to_string()).unwrap();
+    assert_eq!(voting_power.boost_voting_power, Decimal::percent(37));
+
+    let unlocked_env = governance_env_height(unlock_height);
+    let voting_power = get_voting_power(deps.as_ref(), unlocked_env, STAKER1.to_string()).unwrap();
+    assert_eq!(voting_power.voting_power, Decimal::one());
+    assert_eq!(voting_power.boost_voting_power, Decimal::zero());
+}
+
+#[test]
+fn succeed_vote_with_boost() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    super::stake_governance_token_hook::will_success(&mut deps, STAKER1, Uint128::new(100));
+    will_success(&mut deps, STAKER1, Uint128::new(50), STAKING_MAX_LOCK_DURATION);
+    crate::poll::tests::create_poll::default(&mut deps);
+
+    let result = crate::poll::tests::cast_vote::exec(
+        &mut deps,
+        governance_env(),
+        mock_info(STAKER1, &[]),
+        1,
+        VoteOption::Yes,
+        Uint128::new(176),
+    );
+    expect_generic_err(&result, "User does not have enough staked tokens.");
+
+    let (_, _, response) = crate::poll::tests::cast_vote::will_success(&mut deps, STAKER1, 1, VoteOption::Yes, Uint128::new(175));
+    assert!(response.attributes.contains(&attr("delegated_amount", "0")));
+
+    let poll = Poll::load(&deps.storage, &1).unwrap();
+    assert_eq!(poll.yes_votes, Uint128::new(175));
+    assert_eq!(poll.boost_votes, Uint128::new(75));
+
+    // boost is not part of the staked amount, so the quorum stays within it
+    let (quorum, staked_amount) = poll.calculate_quorum(deps.as_ref(), governance_env().block.height).unwrap();
+    assert_eq!(quorum, Decimal::one());
+    assert_eq!(staked_amount, Uint128::new(100));
+
+    crate::poll::tests::retract_vote::will_success(&mut deps, STAKER1, 1);
+
+    let poll = Poll::load(&deps.storage, &1).unwrap();
+    assert_eq!(poll.boost_votes, Uint128::zero());
+}
+
+#[test]
+fn succeed_boost_excluded_from_quorum() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    super::stake_governance_token_hook::will_success(&mut deps, STAKER1, Uint128::new(100));
+    super::stake_governance_token_hook::will_success(&mut deps, STAKER2, Uint128::new(300));
+    will_success(&mut deps, STAKER1, Uint128::new(100), STAKING_MAX_LOCK_DURATION);
+    crate::poll::tests::create_poll::default(&mut deps);
+
+    // 250 of 400 with the boost, but only 100 of 400 staked
+    crate::poll::tests::cast_vote::will_success(&mut deps, STAKER1, 1, VoteOption::Yes, Uint128::new(250));
+    crate::poll::tests::end_poll::will_success(&mut deps, 1);
+
+    let poll = Poll::load(&deps.storage, &1).unwrap();
+    assert_eq!(poll.status, PollStatus::Rejected);
+    let (result, _) = poll.get_result(deps.as_ref(), poll.end_height + 1).unwrap();
+    assert!(result == PollResult::QuorumNotReached);
+}
+
+#[test]
+fn failed_unstake_locked() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    super::stake_governance_token_hook::will_success(&mut deps, STAKER1, Uint128::new(100));
+    let (env, _, _) = will_success(&mut deps, STAKER1, Uint128::new(50), STAKING_MAX_LOCK_DURATION);
+
+    let result = super::unstake_governance_token_hook::exec(
+        &mut deps,
+        governance_env(),
+        mock_info(governance_env().contract.address.as_str(), &[]),
+        STAKER1.to_string(),
+        Some(Uint128::new(51)),
+    );
+    expect_generic_err(&result, "User is trying to unstake too many tokens.");
+
+    super::unstake_governance_token_hook::will_success(&mut deps, STAKER1, Some(Uint128::new(50)));
+
+    let unlocked_env = governance_env_height(env.block.height + STAKING_MAX_LOCK_DURATION);
+    super::unstake_governance_token_hook::exec(
+        &mut deps,
+        unlocked_env.clone(),
+        mock_info(unlocked_env.contract.address.as_str(), &[]),
+        STAKER1.to_string(),
+        Some(Uint128::new(50)),
+    ).unwrap();
+
+    let staker_state = StakerState::load(&deps.storage, &Addr::unchecked(STAKER1)).unwrap();
+    assert_eq!(staker_state.locks, vec![]);
+}
+
+#[test]
+fn succeed_unstake_all_after_boosted_vote() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    super::stake_governance_token_hook::will_success(&mut deps, STAKER1, Uint128::new(100));
+    let (env, _, _) = will_success(&mut deps, STAKER1, Uint128::new(50), STAKING_MAX_LOCK_DURATION);
+    crate::poll::tests::create_poll::default(&mut deps);
+    crate::poll::tests::cast_vote::will_success(&mut deps, STAKER1, 1, VoteOption::Yes, Uint128::new(175));
+
+    // boost is not locked balance, only the own balance in the vote is
+    let unlocked_env = governance_env_height(env.block.height + STAKING_MAX_LOCK_DURATION);
+    super::unstake_governance_token_hook::exec(
+        &mut deps,
+        unlocked_env.clone(),
+        mock_info(unlocked_env.contract.address.as_str(), &[]),
+        STAKER1.to_string(),
+        None,
+    ).unwrap();
+
+    let staker_state = StakerState::load(&deps.storage, &Addr::unchecked(STAKER1)).unwrap();
+    assert_eq!(staker_state.share, Uint128::new(100));
+
+    crate::poll::tests::end_poll::will_success(&mut deps, 1);
+
+    super::unstake_governance_token_hook::exec(
+        &mut deps,
+        unlocked_env.clone(),
+        mock_info(unlocked_env.contract.address.as_str(), &[]),
+        STAKER1.to_string(),
+        None,
+    ).unwrap();
+
+    let staker_state = StakerState::load(&deps.storage, &Addr::unchecked(STAKER1)).unwrap();
+    assert_eq!(staker_state.share, Uint128::zero());
+}
+
+#[test]
+fn failed_invalid_lock() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    let result = exec(&mut deps, governance_env(), mock_info(STAKER1, &[]), Uint128::new(10), 10);
+    expect_generic_err(&result, "Nothing staked");
+
+    super::stake_governance_token_hook::will_success(&mut deps, STAKER1, Uint128::new(100));
+
+    let result = exec(&mut deps, governance_env(), mock_info(STAKER1, &[]), Uint128::new(10), 0);
+    expect_generic_err(&result, &format!("Lock duration must be 1 to {}", STAKING_MAX_LOCK_DURATION));
+
+    let result = exec(&mut deps, governance_env(), mock_info(STAKER1, &[]), Uint128::new(10), STAKING_MAX_LOCK_DURATION + 1);
+    expect_generic_err(&result, &format!("Lock duration must be 1 to {}", STAKING_MAX_LOCK_DURATION));
+
+    will_success(&mut deps, STAKER1, Uint128::new(60), 10);
+
+    let result = exec(&mut deps, governance_env(), mock_info(STAKER1, &[]), Uint128::new(41), 10);
+    expect_generic_err(&result, "User is trying to lock too many tokens.");
+}
@@ -2,5 +2,8 @@ pub mod instantiate;
 pub mod update_staking_config;
 pub mod stake_governance_token_hook;
 pub mod unstake_governance_token_hook;
+pub mod delegate;
+pub mod claim_unbonded;
+pub mod lock_stake;
 
 mod validate;
@@ -1,11 +1,11 @@
-use cosmwasm_std::{Addr, Env, MessageInfo, Response};
+use cosmwasm_std::{Addr, Decimal, Env, MessageInfo, Response};
 use cosmwasm_std::testing::mock_info;
 
 use valkyrie::common::ContractResult;
 use valkyrie::mock_querier::{custom_deps, CustomDeps};
 use valkyrie::test_constants::default_sender;
 use valkyrie::test_constants::governance::*;
-use valkyrie::test_utils::expect_unauthorized_err;
+use valkyrie::test_utils::{expect_generic_err, expect_unauthorized_err};
 use crate::staking::executions::update_staking_config;
 use crate::staking::states::StakingConfig;
 
@@ -16,18 +16,27 @@ pub fn exec(
     env: Env,
     info: MessageInfo,
     distributor: Option<String>,
+    unbonding_period: Option<u64>,
+    max_lock_duration: Option<u64>,
+    max_lock_multiplier: Option<Decimal>,
 ) -> ContractResult<Response> {
     update_staking_config(
         deps.as_mut(),
         env,
         info,
         distributor,
+        unbonding_period,
+        max_lock_duration,
+        max_lock_multiplier,
     )
 }
 
 pub fn will_success(
     deps: &mut CustomDeps,
     distributor: Option<String>,
+    unbonding_period: Option<u64>,
+    max_lock_duration: Option<u64>,
+    max_lock_multiplier: Option<Decimal>,
 ) -> (Env, MessageInfo, Response) {
     let env = governance_env();
     let info = mock_info(GOVERNANCE, &[]);
@@ -37,6 +46,9 @@ pub fn will_success(
         env.clone(),
         info.clone(),
         distributor,
+        unbonding_period,
+        max_lock_duration,
+        max_lock_multiplier,
     ).unwrap();
 
     (env, info, response)
@@ -50,11 +62,14 @@ fn succeed() {
 
     let distributor = "NewDistributor";
 
-    will_success(&mut deps, Some(distributor.to_string()));
+    will_success(&mut deps, Some(distributor.to_string()), Some(100), Some(2000), Some(Decimal::percent(400)));
 
     let config = StakingConfig::load(&deps.storage).unwrap();
     assert_eq!(config, StakingConfig {
         distributor: Some(Addr::unchecked(distributor)),
+        unbonding_period: 100,
+        max_lock_duration: 2000,
+        max_lock_multiplier: Decimal::percent(400),
     });
 }
 
@@ -69,7 +84,28 @@ fn failed_invalid_permission() {
         governance_env(),
         default_sender(),
         None,
+        None,
+        None,
+        None,
     );
 
     expect_unauthorized_err(&result);
-}
\ No newline at end of file
+}
+#[test]
+fn failed_invalid_max_lock_multiplier() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    let result = exec(
+        &mut deps,
+        governance_env(),
+        governance_sender(),
+        None,
+        None,
+        None,
+        Some(Decimal::percent(99)),
+    );
+
+    expect_generic_err(&result, "max_lock_multiplier must be at least 1");
+}
@@ -1,10 +1,11 @@
-use cosmwasm_std::{attr, Uint128};
+use cosmwasm_std::{Addr, attr, Uint128};
+use cosmwasm_std::testing::mock_info;
 
 use valkyrie::mock_querier::custom_deps;
 use valkyrie::test_constants::governance::{GOVERNANCE, governance_env, GOVERNANCE_TOKEN};
 
-use crate::staking::queries::get_staker_state;
-use crate::staking::tests::stake_governance_token_hook::STAKER1;
+use crate::staking::queries::{get_staker_state, get_staker_state_at};
+use crate::staking::tests::stake_governance_token_hook::{STAKER1, STAKER2};
 use crate::tests::init_default;
 
 #[test]
@@ -49,3 +50,55 @@ fn share_calculation() {
     assert_eq!(staker_state.share, Uint128::new(100));
     assert_eq!(staker_state.balance, Uint128::new(200));
 }
+
+#[test]
+fn share_checkpoints() {
+    let mut deps = custom_deps();
+
+    init_default(deps.as_mut());
+
+    let mut env = governance_env();
+    let staked_height = env.block.height;
+
+    super::stake_governance_token_hook::will_success(&mut deps, STAKER1, Uint128::new(100));
+
+    env.block.height += 10;
+    super::stake_governance_token_hook::exec(
+        &mut deps,
+        env.clone(),
+        mock_info(GOVERNANCE, &[]),
+        Addr::unchecked(STAKER2),
+        Uint128::new(100),
+    ).unwrap();
+
+    env.block.height += 10;
+    super::unstake_governance_token_hook::exec(
+        &mut deps,
+        env.clone(),
+        mock_info(GOVERNANCE, &[]),
+        STAKER1.to_string(),
+        Some(Uint128::new(50)),
+    ).unwrap();
+
+    let staker_state = get_staker_state_at(deps.as_ref(), env.clone(), STAKER1.to_string(), staked_height - 1).unwrap();
+    assert_eq!(staker_state.share, Uint128::zero());
+    assert_eq!(staker_state.balance, Uint128::zero());
+
+    let staker_state = get_staker_state_at(deps.as_ref(), env.clone(), STAKER1.to_string(), staked_height).unwrap();
+    assert_eq!(staker_state.share, Uint128::new(100));
+    assert_eq!(staker_state.total_share, Uint128::new(100));
+    assert_eq!(staker_state.balance, Uint128::new(100));
+
+    let staker_state = get_staker_state_at(deps.as_ref(), env.clone(), STAKER2.to_string(), staked_height + 5).unwrap();
+    assert_eq!(staker_state.share, Uint128::zero());
+
+    let staker_state = get_staker_state_at(deps.as_ref(), env.clone(), STAKER1.to_string(), staked_height + 10).unwrap();
+    assert_eq!(staker_state.share, Uint128::new(100));
+    assert_eq!(staker_state.total_share, Uint128::new(200));
+    assert_eq!(staker_state.balance, Uint128::new(100));
+
+    let staker_state = get_staker_state_at(deps.as_ref(), env.clone(), STAKER1.to_string(), env.block.height).unwrap();
+    assert_eq!(staker_state.share, Uint128::new(50));
+    assert_eq!(staker_state.total_share, Uint128::new(150));
+    assert_eq!(staker_state.balance, Uint128::new(50));
+}
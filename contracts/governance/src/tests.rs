@@ -2,6 +2,7 @@ use cosmwasm_std::{Decimal, DepsMut, Env, MessageInfo};
 
 use valkyrie::governance::execute_msgs::{ContractConfigInitMsg, InstantiateMsg, PollConfigInitMsg, StakingConfigInitMsg};
 use valkyrie::test_constants::contract_creator;
+use valkyrie::test_constants::community::COMMUNITY;
 use valkyrie::test_constants::governance::*;
 
 use crate::entrypoints;
@@ -13,6 +14,8 @@ pub fn init_default(deps: DepsMut) -> (Env, MessageInfo) {
     let msg = InstantiateMsg {
         contract_config: ContractConfigInitMsg {
             governance_token: GOVERNANCE_TOKEN.to_string(),
+            community: Some(COMMUNITY.to_string()),
+            guardian: None,
         },
         poll_config: PollConfigInitMsg {
             quorum: Decimal::percent(POLL_QUORUM_PERCENT),
@@ -21,9 +24,16 @@ pub fn init_default(deps: DepsMut) -> (Env, MessageInfo) {
             execution_delay_period: POLL_EXECUTION_DELAY_PERIOD,
             proposal_deposit: POLL_PROPOSAL_DEPOSIT,
             snapshot_period: POLL_SNAPSHOT_PERIOD,
+            cancel_grace_period: POLL_CANCEL_GRACE_PERIOD,
+            cancel_fee_rate: Decimal::percent(POLL_CANCEL_FEE_PERCENT),
+            refund_vetoed_deposit: false,
+            execution_retry_period: POLL_EXECUTION_RETRY_PERIOD,
         },
         staking_config: StakingConfigInitMsg {
             distributor: None,
+            unbonding_period: 0,
+            max_lock_duration: STAKING_MAX_LOCK_DURATION,
+            max_lock_multiplier: Decimal::percent(STAKING_MAX_LOCK_MULTIPLIER_PERCENT),
         }
     };
 
@@ -53,6 +53,9 @@ pub enum ExecuteMsg {
     },
     ClaimParticipationReward {},
     ClaimReferralReward {},
+    ClaimRewardFor {
+        actor: String,
+    },
     Participate {
         actor: String,
         referrer: Option<Referrer>,
@@ -67,6 +67,7 @@ pub struct CampaignStateResponse {
     pub participation_count: u64,
     pub cumulative_participation_reward_amount: Uint128,
     pub cumulative_referral_reward_amount: Uint128,
+    pub cumulative_overflow_amount: Uint128,
     pub locked_balances: Vec<(Denom, Uint128)>,
     pub balances: Vec<(Denom, Uint128)>,
     pub deposit_amount: Uint128,
@@ -92,6 +93,8 @@ pub struct ReferralRewardLimitAmount {
     pub limit_amount: Uint128,
     pub base_limit_amount: Uint128,
     pub actor_limit_amount: Uint128,
+    pub window_usage_amount: Uint128,
+    pub window_reset_height: Option<u64>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
@@ -104,6 +107,7 @@ pub struct ActorResponse {
     pub referral_reward_amounts: Vec<(Uint128, u64)>,
     pub cumulative_participation_reward_amount: Uint128,
     pub cumulative_referral_reward_amount: Uint128,
+    pub cumulative_overflow_amount: Uint128,
     pub participation_count: u64,
     pub referral_count: u64,
     pub last_participated_at: Timestamp,
@@ -120,6 +124,7 @@ impl ActorResponse {
             referral_reward_amounts: vec![],
             cumulative_participation_reward_amount: Uint128::zero(),
             cumulative_referral_reward_amount: Uint128::zero(),
+            cumulative_overflow_amount: Uint128::zero(),
             participation_count: 0,
             referral_count: 0,
             last_participated_at: Timestamp::default(),
@@ -0,0 +1,60 @@
+use std::fmt;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CampaignStatus {
+    Unverified,
+    Verified,
+    Delisted,
+}
+
+impl fmt::Display for CampaignStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CampaignStatus::Unverified => write!(f, "unverified"),
+            CampaignStatus::Verified => write!(f, "verified"),
+            CampaignStatus::Delisted => write!(f, "delisted"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OverflowMode {
+    Recipient,
+    Reuse,
+    Burn,
+}
+
+impl fmt::Display for OverflowMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            OverflowMode::Recipient => write!(f, "recipient"),
+            OverflowMode::Reuse => write!(f, "reuse"),
+            OverflowMode::Burn => write!(f, "burn"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CampaignFilter {
+    Creator(String),
+    Tag(String),
+    CodeId(u64),
+    CreatedHeight {
+        min: Option<u64>,
+        max: Option<u64>,
+    },
+    Status(CampaignStatus),
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FeeSplitRecipient {
+    Burn,
+    Address(String),
+}
@@ -2,6 +2,8 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use cosmwasm_std::{Decimal, Uint128, Binary};
+use cw20::Cw20ReceiveMsg;
+use crate::campaign_manager::enumerations::{CampaignStatus, FeeSplitRecipient, OverflowMode};
 use crate::common::Denom;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -19,6 +21,10 @@ pub struct InstantiateMsg {
     pub valkyrie_token: String,
     pub referral_reward_limit_option: ReferralRewardLimitOptionMsg,
     pub contract_admin: String,
+    pub creation_fee_denom: Denom,
+    pub creation_fee_amount: Uint128,
+    pub swap_fee_max_spread: Decimal,
+    pub swap_fee_keepers: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -26,11 +32,16 @@ pub struct ReferralRewardLimitOptionMsg {
     pub overflow_amount_recipient: Option<String>,
     pub base_count: u8,
     pub percent_for_governance_staking: u16,
+    pub lp_staking: Option<String>,
+    pub percent_for_lp_staking: u16,
+    pub staking_window_period: u64,
+    pub limit_window_period: u64,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
+    Receive(Cw20ReceiveMsg),
     UpdateConfig {
         governance: Option<String>,
         valkyrie_token: Option<String>,
@@ -44,11 +55,23 @@ pub enum ExecuteMsg {
         deactivate_period: Option<u64>,
         key_denom: Option<Denom>,
         contract_admin: Option<String>,
+        creation_fee_denom: Option<Denom>,
+        creation_fee_amount: Option<Uint128>,
+        swap_fee_max_spread: Option<Decimal>,
+        swap_fee_keepers: Option<Vec<String>>,
+        /// Values reward pools by the spot price of the best swap route when no price source is registered.
+        /// Off by default, since a spot price can be moved within a block.
+        spot_price_fallback: Option<bool>,
     },
     UpdateReferralRewardLimitOption {
+        overflow_mode: Option<OverflowMode>,
         overflow_amount_recipient: Option<String>,
         base_count: Option<u8>,
         percent_for_governance_staking: Option<u16>,
+        lp_staking: Option<String>,
+        percent_for_lp_staking: Option<u16>,
+        staking_window_period: Option<u64>,
+        limit_window_period: Option<u64>,
     },
     SetReuseOverflowAmount {},
     CreateCampaign {
@@ -58,6 +81,8 @@ pub enum ExecuteMsg {
         deposit_lock_period: Option<u64>,
         qualifier: Option<String>,
         qualification_description: Option<String>,
+        tags: Option<Vec<String>>,
+        logo_url: Option<String>,
     },
     SpendFee {
         amount: Option<Uint128>,
@@ -66,7 +91,167 @@ pub enum ExecuteMsg {
         denom: Denom,
         amount: Option<Uint128>,
         route: Option<Vec<Denom>>,
+        minimum_receive: Option<Uint128>,
     },
+    ClaimAll {
+        campaigns: Vec<String>,
+    },
+    SetCampaignStatus {
+        address: String,
+        status: CampaignStatus,
+    },
+    /// Migrates up to 10 campaigns with `WasmMsg::Migrate`. Campaigns are instantiated with the manager
+    /// as their wasm admin; campaigns created before that have to transfer their admin to the manager
+    /// (`MsgUpdateAdmin`) first, otherwise they are reported as failures in the replies.
+    MigrateCampaigns {
+        code_id: u64,
+        campaigns: Option<Vec<String>>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        msg: Binary,
+    },
+    MigrateLegacyCampaigns {
+        limit: Option<u32>,
+    },
+    CreateTemplate {
+        name: String,
+        template: CampaignTemplateMsg,
+    },
+    UpdateTemplate {
+        template_id: u64,
+        name: Option<String>,
+        template: Option<CampaignTemplateMsg>,
+    },
+    RemoveTemplate {
+        template_id: u64,
+    },
+    CreateCampaignFromTemplate {
+        template_id: u64,
+        overrides: Option<CampaignTemplateMsg>,
+        tags: Option<Vec<String>>,
+        logo_url: Option<String>,
+    },
+    AddSwapRoute {
+        route: Vec<Denom>,
+    },
+    RemoveSwapRoute {
+        route: Vec<Denom>,
+    },
+    UpdateFeeSplits {
+        splits: Vec<FeeSplitMsg>,
+        dust_recipient: Option<String>,
+    },
+    SetPriceSource {
+        base: Denom,
+        quote: Denom,
+        source: PriceSourceMsg,
+        max_staleness: u64,
+    },
+    RemovePriceSource {
+        base: Denom,
+        quote: Denom,
+    },
+    RecordPrice {
+        base: Denom,
+        quote: Denom,
+    },
+    UpdateAddPoolFeeTiers {
+        tiers: Vec<FeeTierMsg>,
+    },
+    SetCampaignOverrides {
+        campaign: String,
+        overrides: CampaignOverridesMsg,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[allow(clippy::large_enum_variant)]
+pub enum Cw20HookMsg {
+    CreateCampaign {
+        config_msg: Binary,
+        deposit_denom: Option<Denom>,
+        deposit_amount: Option<Uint128>,
+        deposit_lock_period: Option<u64>,
+        qualifier: Option<String>,
+        qualification_description: Option<String>,
+        tags: Option<Vec<String>>,
+        logo_url: Option<String>,
+    },
+    CreateCampaignFromTemplate {
+        template_id: u64,
+        overrides: Option<CampaignTemplateMsg>,
+        tags: Option<Vec<String>>,
+        logo_url: Option<String>,
+    },
+}
+
+/// Partial campaign config and deposit/qualifier defaults.
+/// Fields left empty must be given by overrides when a campaign is created from the template.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Default)]
+pub struct CampaignTemplateMsg {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub url: Option<String>,
+    pub parameter_key: Option<String>,
+    pub participation_reward_denom: Option<Denom>,
+    pub participation_reward_amount: Option<Uint128>,
+    pub participation_reward_lock_period: Option<u64>,
+    pub referral_reward_amounts: Option<Vec<Uint128>>,
+    pub referral_reward_lock_period: Option<u64>,
+    pub deposit_denom: Option<Denom>,
+    pub deposit_amount: Option<Uint128>,
+    pub deposit_lock_period: Option<u64>,
+    pub qualifier: Option<String>,
+    pub qualification_description: Option<String>,
+}
+
+/// A share of the spent fee. When `msg` is given, the share is sent to the recipient with Cw20 `Send`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FeeSplitMsg {
+    pub recipient: FeeSplitRecipient,
+    pub weight: Decimal,
+    pub msg: Option<Binary>,
+}
+
+/// `Fixed` is a price set by governance.
+/// `Twap` is a time weighted average price over `window` blocks, recorded by keepers with `RecordPrice`.
+/// Each record samples the spot price of the best swap route and holds it until the next record,
+/// so keepers are trusted to record regularly and not in a block where the spot price is moved.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PriceSourceMsg {
+    Fixed {
+        price: Decimal,
+    },
+    Twap {
+        window: u64,
+    },
+}
+
+/// Add pool fee rate applied when the governance staking amount is at least `min_staking_amount`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FeeTierMsg {
+    pub min_staking_amount: Uint128,
+    pub fee_rate: Decimal,
+}
+
+/// Campaign specific values of global config and referral reward limit option.
+/// Empty fields fall back to the global values.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Default)]
+pub struct CampaignOverridesMsg {
+    pub add_pool_fee_rate: Option<Decimal>,
+    pub add_pool_min_referral_reward_rate: Option<Decimal>,
+    pub remove_pool_fee_rate: Option<Decimal>,
+    pub deactivate_period: Option<u64>,
+    pub overflow_mode: Option<OverflowMode>,
+    pub overflow_amount_recipient: Option<String>,
+    pub base_count: Option<u8>,
+    pub percent_for_governance_staking: Option<u16>,
+    pub lp_staking: Option<String>,
+    pub percent_for_lp_staking: Option<u16>,
+    pub staking_window_period: Option<u64>,
+    pub limit_window_period: Option<u64>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -1,2 +1,3 @@
+pub mod enumerations;
 pub mod execute_msgs;
-pub mod query_msgs;
\ No newline at end of file
+pub mod query_msgs;
@@ -1,6 +1,8 @@
-use cosmwasm_std::Decimal;
+use cosmwasm_std::{Decimal, Uint128};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use crate::campaign_manager::enumerations::{CampaignFilter, CampaignStatus, OverflowMode};
+use crate::campaign_manager::execute_msgs::{CampaignOverridesMsg, CampaignTemplateMsg, FeeSplitMsg, FeeTierMsg, PriceSourceMsg};
 use crate::common::{OrderBy, Denom};
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -12,16 +14,61 @@ use crate::test_constants::campaign_manager::*;
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
-    Config {},
+    Config {
+        campaign: Option<String>,
+    },
     ReferralRewardLimitOption {},
     Campaign {
         address: String,
     },
     Campaigns {
+        filter: Option<CampaignFilter>,
         start_after: Option<String>,
         limit: Option<u32>,
         order_by: Option<OrderBy>,
     },
+    PendingRewards {
+        address: String,
+        campaigns: Vec<String>,
+    },
+    Template {
+        template_id: u64,
+    },
+    Templates {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+        order_by: Option<OrderBy>,
+    },
+    SwapRoutes {
+        offer: Denom,
+        ask: Denom,
+    },
+    SimulateSwap {
+        offer: Denom,
+        ask: Denom,
+        amount: Uint128,
+    },
+    FeeSplits {},
+    Price {
+        base: Denom,
+        quote: Denom,
+    },
+    /// Same as `Price`, but `None` instead of an error when no price source is registered.
+    RegisteredPrice {
+        base: Denom,
+        quote: Denom,
+    },
+    AddPoolFeeTiers {},
+    FeeQuote {
+        campaign: Option<String>,
+        addresses: Vec<String>,
+    },
+    CampaignOverrides {
+        campaign: String,
+    },
+    EffectiveConfig {
+        campaign: String,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
@@ -38,6 +85,11 @@ pub struct ConfigResponse {
     pub deactivate_period: u64,
     pub key_denom: Denom,
     pub contract_admin: String,
+    pub creation_fee_denom: Denom,
+    pub creation_fee_amount: Uint128,
+    pub swap_fee_max_spread: Decimal,
+    pub swap_fee_keepers: Vec<String>,
+    pub spot_price_fallback: bool,
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -56,37 +108,124 @@ impl Default for ConfigResponse {
             deactivate_period: CAMPAIGN_DEACTIVATE_PERIOD,
             key_denom: Denom::Native(KEY_DENOM_NATIVE.to_string()),
             contract_admin: governance::GOVERNANCE.to_string(),
+            creation_fee_denom: Denom::Native(CREATION_FEE_DENOM_NATIVE.to_string()),
+            creation_fee_amount: CREATION_FEE_AMOUNT,
+            swap_fee_max_spread: Decimal::percent(SWAP_FEE_MAX_SPREAD_PERCENT),
+            swap_fee_keepers: vec![SWAP_FEE_KEEPER.to_string()],
+            spot_price_fallback: false,
         }
     }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct ReferralRewardLimitOptionResponse {
+    pub overflow_mode: OverflowMode,
     pub overflow_amount_recipient: Option<String>,
     pub base_count: u8,
     pub percent_for_governance_staking: u16,
+    pub lp_staking: Option<String>,
+    pub percent_for_lp_staking: u16,
+    pub staking_window_period: u64,
+    pub limit_window_period: u64,
 }
 
 #[cfg(not(target_arch = "wasm32"))]
 impl Default for ReferralRewardLimitOptionResponse {
     fn default() -> Self {
         ReferralRewardLimitOptionResponse {
+            overflow_mode: OverflowMode::Reuse,
             overflow_amount_recipient: None,
             base_count: REFERRAL_REWARD_LIMIT_BASE_COUNT,
             percent_for_governance_staking: REFERRAL_REWARD_LIMIT_STAKING_PERCENT,
+            lp_staking: None,
+            percent_for_lp_staking: 0,
+            staking_window_period: 0,
+            limit_window_period: 0,
         }
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct CampaignResponse {
     pub code_id: u64,
     pub address: String,
     pub creator: String,
     pub created_height: u64,
+    pub tags: Vec<String>,
+    pub logo_url: Option<String>,
+    pub status: CampaignStatus,
 }
 
-#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct CampaignsResponse {
     pub campaigns: Vec<CampaignResponse>,
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PendingRewardsResponse {
+    pub address: String,
+    pub rewards: Vec<(Denom, Uint128)>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TemplateResponse {
+    pub id: u64,
+    pub name: String,
+    pub owner: String,
+    pub template: CampaignTemplateMsg,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TemplatesResponse {
+    pub templates: Vec<TemplateResponse>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SwapRoutesResponse {
+    pub routes: Vec<Vec<Denom>>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SimulateSwapResponse {
+    pub route: Vec<Denom>,
+    pub amount: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FeeSplitsResponse {
+    pub splits: Vec<FeeSplitMsg>,
+    pub dust_recipient: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PriceResponse {
+    pub price: Decimal,
+    pub source: PriceSourceMsg,
+    pub updated_height: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AddPoolFeeTiersResponse {
+    pub tiers: Vec<FeeTierMsg>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FeeQuoteResponse {
+    pub address: Option<String>,
+    pub staking_amount: Uint128,
+    pub tier: Option<u32>,
+    pub fee_rate: Decimal,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CampaignOverridesResponse {
+    pub campaign: String,
+    pub overrides: CampaignOverridesMsg,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
+pub struct EffectiveConfigResponse {
+    pub config: ConfigResponse,
+    pub referral_reward_limit_option: ReferralRewardLimitOptionResponse,
+    pub delisted: bool,
+}
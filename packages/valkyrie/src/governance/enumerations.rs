@@ -29,10 +29,32 @@ pub enum PollStatus {
     Rejected,
     Executed,
     Failed,
+    Cancelled,
+    Vetoed,
 }
 
 impl fmt::Display for PollStatus {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{:?}", self)
     }
-}
\ No newline at end of file
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PollCategory {
+    Text,
+    ParameterChange,
+    TreasurySpend,
+    Emergency,
+}
+
+impl fmt::Display for PollCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PollCategory::Text => write!(f, "text"),
+            PollCategory::ParameterChange => write!(f, "parameter_change"),
+            PollCategory::TreasurySpend => write!(f, "treasury_spend"),
+            PollCategory::Emergency => write!(f, "emergency"),
+        }
+    }
+}
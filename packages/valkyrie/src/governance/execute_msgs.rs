@@ -3,7 +3,7 @@ use cw20::Cw20ReceiveMsg;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use super::enumerations::VoteOption;
+use super::enumerations::{PollCategory, VoteOption};
 use crate::common::ExecutionMsg;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -16,6 +16,8 @@ pub struct InstantiateMsg {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct ContractConfigInitMsg {
     pub governance_token: String,
+    pub community: Option<String>,
+    pub guardian: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -26,27 +28,48 @@ pub struct PollConfigInitMsg {
     pub execution_delay_period: u64,
     pub proposal_deposit: Uint128,
     pub snapshot_period: u64,
+    pub cancel_grace_period: u64,
+    pub cancel_fee_rate: Decimal,
+    pub refund_vetoed_deposit: bool,
+    pub execution_retry_period: u64,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct StakingConfigInitMsg {
     pub distributor: Option<String>,
+    pub unbonding_period: u64,
+    pub max_lock_duration: u64,
+    pub max_lock_multiplier: Decimal,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
     Receive(Cw20ReceiveMsg),
+    UpdateContractConfig {
+        community: Option<String>,
+        guardian: Option<String>,
+        remove_community: Option<bool>,
+        remove_guardian: Option<bool>,
+    },
     UpdateStakingConfig {
         distributor: Option<String>,
+        unbonding_period: Option<u64>,
+        max_lock_duration: Option<u64>,
+        max_lock_multiplier: Option<Decimal>,
     },
     UpdatePollConfig {
+        category: Option<PollCategory>,
         quorum: Option<Decimal>,
         threshold: Option<Decimal>,
         voting_period: Option<u64>,
         execution_delay_period: Option<u64>,
         proposal_deposit: Option<Uint128>,
         snapshot_period: Option<u64>,
+        cancel_grace_period: Option<u64>,
+        cancel_fee_rate: Option<Decimal>,
+        refund_vetoed_deposit: Option<bool>,
+        execution_retry_period: Option<u64>,
     },
     StakeGovernanceTokenHook {
         staker: String,
@@ -57,14 +80,29 @@ pub enum ExecuteMsg {
         staker: String,
         amount: Option<Uint128>,
     },
+    ClaimUnbonded {},
+    LockStake {
+        amount: Uint128,
+        duration: u64,
+    },
+    Delegate { to: String },
+    Undelegate {},
     CastVote {
         poll_id: u64,
         vote: VoteOption,
         amount: Uint128,
     },
+    CastWeightedVote {
+        poll_id: u64,
+        votes: Vec<(VoteOption, Uint128)>,
+    },
+    RetractVote { poll_id: u64 },
+    CancelPoll { poll_id: u64 },
     SnapshotPoll { poll_id: u64 },
     EndPoll { poll_id: u64 },
+    VetoPoll { poll_id: u64 },
     ExecutePoll { poll_id: u64 },
+    RetryExecution { poll_id: u64 },
     RunExecution { executions: Vec<ExecutionMsg> },
 }
 
@@ -76,7 +114,9 @@ pub enum Cw20HookMsg {
         title: String,
         description: String,
         link: Option<String>,
+        category: Option<PollCategory>,
         executions: Vec<ExecutionMsg>,
+        independent_executions: Option<bool>,
     },
 }
 
@@ -11,4 +11,13 @@ pub struct VoteInfoMsg {
     pub voter: String,
     pub option: VoteOption,
     pub amount: Uint128,
+    pub delegated_amount: Uint128,
+    pub votes: Vec<(VoteOption, Uint128)>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ExecutionResultMsg {
+    pub order: u64,
+    pub success: bool,
+    pub error: Option<String>,
 }
@@ -3,15 +3,17 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::super::common::OrderBy;
-use super::enumerations::PollStatus;
-use super::models::VoteInfoMsg;
+use super::enumerations::{PollCategory, PollStatus};
+use super::models::{ExecutionResultMsg, VoteInfoMsg};
 use crate::common::ExecutionMsg;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
     ContractConfig {},
-    PollConfig {},
+    PollConfig {
+        category: Option<PollCategory>,
+    },
     PollState {},
     Poll {
         poll_id: u64,
@@ -22,6 +24,11 @@ pub enum QueryMsg {
         limit: Option<u32>,
         order_by: Option<OrderBy>,
     },
+    Queue {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+        order_by: Option<OrderBy>,
+    },
     Voters {
         poll_id: u64,
         start_after: Option<String>,
@@ -33,6 +40,10 @@ pub enum QueryMsg {
     StakerState {
         address: String,
     },
+    StakerStateAt {
+        address: String,
+        height: u64,
+    },
     AllStaker {
         start_after: Option<String>,
         limit: Option<u32>,
@@ -45,12 +56,15 @@ pub enum QueryMsg {
 #[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
 pub struct ContractConfigResponse {
     pub governance_token: String,
+    pub community: Option<String>,
+    pub guardian: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
 pub struct StakingStateResponse {
     pub total_share: Uint128,
     pub total_balance: Uint128,
+    pub total_unbonding: Uint128,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -58,6 +72,11 @@ pub struct StakerStateResponse {
     pub balance: Uint128,
     pub share: Uint128,
     pub votes: Vec<(u64, VoteInfoMsg)>,
+    pub delegate: Option<String>,
+    pub delegated_in_amount: Uint128,
+    pub delegated_out_amount: Uint128,
+    pub unbondings: Vec<(u64, Uint128)>, // (release height, amount)
+    pub locks: Vec<(u64, Uint128)>, // (unlock height, share)
 }
 
 impl Default for StakerStateResponse {
@@ -66,10 +85,27 @@ impl Default for StakerStateResponse {
             balance: Uint128::zero(),
             share: Uint128::zero(),
             votes: vec![],
+            delegate: None,
+            delegated_in_amount: Uint128::zero(),
+            delegated_out_amount: Uint128::zero(),
+            unbondings: vec![],
+            locks: vec![],
         }
     }
 }
 
+/// Shares at the height, valued at the balance per share at the height
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct StakerStateAtResponse {
+    pub height: u64,
+    pub balance: Uint128,
+    pub share: Uint128,
+    pub total_share: Uint128,
+    pub delegate: Option<String>,
+    pub delegated_in_amount: Uint128,
+    pub delegated_out_amount: Uint128,
+}
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
 pub struct AllStakersResponse {
     pub stakers: Vec<StakerInfoResponse>,
@@ -89,6 +125,10 @@ pub struct PollConfigResponse {
     pub execution_delay_period: u64,
     pub proposal_deposit: Uint128,
     pub snapshot_period: u64,
+    pub cancel_grace_period: u64,
+    pub cancel_fee_rate: Decimal,
+    pub refund_vetoed_deposit: bool,
+    pub execution_retry_period: u64,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
@@ -111,8 +151,14 @@ pub struct PollResponse {
     pub abstain_votes: Uint128,
     pub end_height: u64,
     pub status: PollStatus,
+    pub category: Option<PollCategory>,
     pub staked_amount: Option<Uint128>,
     pub total_balance_at_end_poll: Option<Uint128>,
+    pub independent_executions: bool,
+    pub executed_height: Option<u64>,
+    /// Per execution results are only meaningful with `independent_executions`.
+    /// Otherwise the executions run as one batch, so every execution carries the batch result.
+    pub execution_results: Vec<ExecutionResultMsg>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -120,6 +166,18 @@ pub struct PollsResponse {
     pub polls: Vec<PollResponse>,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct QueuedPollResponse {
+    pub poll_id: u64,
+    pub executable_height: u64,
+    pub executions: Vec<ExecutionMsg>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct QueueResponse {
+    pub polls: Vec<QueuedPollResponse>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct PollCountResponse {
     pub poll_count: u64,
@@ -132,5 +190,8 @@ pub struct VotersResponse {
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct VotingPowerResponse {
-    pub voting_power: Decimal,
+    pub voting_power: Decimal, // including the boost of locked shares
+    pub boost_voting_power: Decimal,
+    pub delegated_in_voting_power: Decimal,
+    pub delegated_out_voting_power: Decimal,
 }
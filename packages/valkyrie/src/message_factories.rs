@@ -45,6 +45,14 @@ pub fn wasm_instantiate(code_id: u64, admin: Option<Addr>, msg: Binary) -> Cosmo
     })
 }
 
+pub fn wasm_migrate(contract: &Addr, new_code_id: u64, msg: Binary) -> CosmosMsg {
+    CosmosMsg::Wasm(WasmMsg::Migrate {
+        contract_addr: contract.to_string(),
+        new_code_id,
+        msg,
+    })
+}
+
 pub fn wasm_execute<T>(contract: &Addr, msg: &T) -> CosmosMsg
 where
     T: Serialize + ?Sized {
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::str::FromStr;
 
 use cosmwasm_std::{Api, Binary, CanonicalAddr, Coin, ContractResult, Decimal, from_slice, OwnedDeps, Querier, QuerierResult, QueryRequest, SystemError, SystemResult, to_binary, Uint128, WasmQuery, from_binary, BankQuery, AllBalanceResponse, Addr, QuerierWrapper};
 use cosmwasm_std::testing::{MOCK_CONTRACT_ADDR, MockApi, MockQuerier, MockStorage};
@@ -6,13 +7,15 @@ use cw20::{TokenInfoResponse, Cw20QueryMsg};
 use terra_cosmwasm::{TaxCapResponse, TaxRateResponse, TerraQuery, TerraQueryWrapper, TerraRoute};
 use crate::governance::query_msgs::{QueryMsg as GovQueryMsg, VotingPowerResponse, ContractConfigResponse as GovContractConfigResponse, StakerStateResponse};
 use crate::terra::calc_tax_one_plus;
-use crate::campaign::query_msgs::{CampaignStateResponse, QueryMsg};
-use crate::campaign_manager::query_msgs::{QueryMsg as CampaignManagerQueryMsg, ConfigResponse, ReferralRewardLimitOptionResponse};
+use crate::campaign::query_msgs::{ActorResponse, CampaignStateResponse, QueryMsg, RewardConfigResponse};
+use crate::campaign_manager::query_msgs::{QueryMsg as CampaignManagerQueryMsg, ConfigResponse, EffectiveConfigResponse, PriceResponse, FeeQuoteResponse, ReferralRewardLimitOptionResponse, SimulateSwapResponse};
+use crate::campaign_manager::execute_msgs::{FeeTierMsg, PriceSourceMsg};
+use crate::lp_staking::query_msgs::{QueryMsg as LpStakingQueryMsg, StakerInfoResponse};
 
 use terraswap::router::{QueryMsg as TerraswapRouterQueryMsg, SwapOperation, SimulateSwapOperationsResponse};
 use crate::test_constants::campaign_manager::CAMPAIGN_MANAGER;
 use crate::test_constants::governance::GOVERNANCE;
-use crate::test_constants::TERRASWAP_ROUTER;
+use crate::test_constants::{LP_STAKING, TERRASWAP_ROUTER};
 
 pub type CustomDeps = OwnedDeps<MockStorage, MockApi, WasmMockQuerier>;
 
@@ -39,6 +42,7 @@ pub struct WasmMockQuerier {
     governance_querier: GovConfigQuerier,
     campaign_state_querier: CampaignStateQuerier,
     terraswap_router_querier: TerraswapRouterQuerier,
+    lp_staking_querier: LpStakingQuerier,
 }
 
 #[derive(Clone, Default)]
@@ -66,6 +70,9 @@ pub(crate) fn powers_to_map(powers: &[(&String, &Decimal)]) -> HashMap<String, D
 pub struct CampaignManagerConfigQuerier {
     config: ConfigResponse,
     referral_reward_limit_option: ReferralRewardLimitOptionResponse,
+    delisted_campaigns: Vec<String>,
+    fee_tiers: Vec<FeeTierMsg>,
+    price_sources: HashMap<(String, String), Decimal>,
 }
 
 impl CampaignManagerConfigQuerier {
@@ -76,6 +83,9 @@ impl CampaignManagerConfigQuerier {
         CampaignManagerConfigQuerier {
             config,
             referral_reward_limit_option,
+            delisted_campaigns: vec![],
+            fee_tiers: vec![],
+            price_sources: HashMap::new(),
         }
     }
 }
@@ -98,17 +108,30 @@ impl GovConfigQuerier {
     }
 }
 
+#[derive(Clone, Default)]
+pub struct LpStakingQuerier {
+    bond_amounts: HashMap<String, Uint128>,
+}
+
 #[derive(Clone, Default)]
 pub struct CampaignStateQuerier {
     states: HashMap<String, CampaignStateResponse>,
+    reward_configs: HashMap<String, RewardConfigResponse>,
+    actors: HashMap<(String, String), ActorResponse>,
 }
 
 impl CampaignStateQuerier {
     pub fn new() -> Self {
         CampaignStateQuerier {
             states: HashMap::new(),
+            reward_configs: HashMap::new(),
+            actors: HashMap::new(),
         }
     }
+
+    fn contains(&self, campaign: &str) -> bool {
+        self.states.contains_key(campaign) || self.reward_configs.contains_key(campaign)
+    }
 }
 
 #[derive(Clone, Default)]
@@ -260,6 +283,10 @@ impl WasmMockQuerier {
             result = self.handle_wasm_smart_terraswap_router(contract_addr, msg);
         }
 
+        if result.is_none() {
+            result = self.handle_wasm_smart_lp_staking(contract_addr, msg);
+        }
+
         if result.is_none() {
             result = self.handle_cw20(contract_addr, msg);
         }
@@ -279,16 +306,107 @@ impl WasmMockQuerier {
         }
 
         match from_binary(msg) {
-            Ok(CampaignManagerQueryMsg::Config {}) => {
+            Ok(CampaignManagerQueryMsg::Config { campaign }) => {
+                if campaign.map_or(false, |c| self.campaign_manager_config_querier.delisted_campaigns.contains(&c)) {
+                    return Some(SystemResult::Ok(ContractResult::Err("Delisted campaign".to_string())));
+                }
+
                 Some(SystemResult::Ok(ContractResult::from(to_binary(
                     &self.campaign_manager_config_querier.config,
                 ))))
             }
+            Ok(CampaignManagerQueryMsg::EffectiveConfig { campaign }) => {
+                Some(SystemResult::Ok(ContractResult::from(to_binary(
+                    &EffectiveConfigResponse {
+                        config: self.campaign_manager_config_querier.config.clone(),
+                        referral_reward_limit_option: self.campaign_manager_config_querier.referral_reward_limit_option.clone(),
+                        delisted: self.campaign_manager_config_querier.delisted_campaigns.contains(&campaign),
+                    }
+                ))))
+            }
             Ok(CampaignManagerQueryMsg::ReferralRewardLimitOption {}) => {
                 Some(SystemResult::Ok(ContractResult::from(to_binary(
                     &self.campaign_manager_config_querier.referral_reward_limit_option,
                 ))))
             }
+            Ok(CampaignManagerQueryMsg::SimulateSwap { offer, ask, amount }) => {
+                if offer == ask {
+                    return Some(SystemResult::Ok(ContractResult::from(to_binary(
+                        &SimulateSwapResponse {
+                            route: vec![offer],
+                            amount,
+                        }
+                    ))));
+                }
+
+                let price = match self.terraswap_router_querier.prices
+                    .get(&(offer.to_string(), ask.to_string())) {
+                    Some(price) => price,
+                    None => return Some(SystemResult::Ok(ContractResult::Err("No available swap route".to_string()))),
+                };
+
+                Some(SystemResult::Ok(ContractResult::from(to_binary(
+                    &SimulateSwapResponse {
+                        route: vec![offer, ask],
+                        amount: Uint128::new((amount.u128() as f64 * *price) as u128),
+                    }
+                ))))
+            }
+            Ok(CampaignManagerQueryMsg::FeeQuote { addresses, .. }) => {
+                let mut staker: Option<(String, Uint128)> = None;
+                for address in addresses.into_iter() {
+                    let staking_amount = self.governance_querier.staker_state.get(&address)
+                        .map(|state| state.balance)
+                        .unwrap_or_default();
+                    if staker.as_ref().map_or(true, |(_, max_amount)| staking_amount > *max_amount) {
+                        staker = Some((address, staking_amount));
+                    }
+                }
+
+                let config = &self.campaign_manager_config_querier;
+                let staking_amount = staker.as_ref().map(|(_, amount)| *amount).unwrap_or_default();
+                let tier = config.fee_tiers.iter().rposition(|tier| tier.min_staking_amount <= staking_amount);
+
+                Some(SystemResult::Ok(ContractResult::from(to_binary(
+                    &FeeQuoteResponse {
+                        address: staker.map(|(address, _)| address),
+                        staking_amount,
+                        tier: tier.map(|tier| tier as u32),
+                        fee_rate: tier.map(|tier| config.fee_tiers[tier].fee_rate)
+                            .unwrap_or(config.config.add_pool_fee_rate),
+                    }
+                ))))
+            }
+            Ok(CampaignManagerQueryMsg::Price { base, quote }) => {
+                let price = match self.terraswap_router_querier.prices
+                    .get(&(base.to_string(), quote.to_string())) {
+                    Some(price) => price,
+                    None => return Some(SystemResult::Ok(ContractResult::Err("Price source not registered".to_string()))),
+                };
+
+                Some(SystemResult::Ok(ContractResult::from(to_binary(
+                    &PriceResponse {
+                        price: Decimal::from_str(&price.to_string()).unwrap(),
+                        source: PriceSourceMsg::Fixed {
+                            price: Decimal::from_str(&price.to_string()).unwrap(),
+                        },
+                        updated_height: 0,
+                    }
+                ))))
+            }
+            Ok(CampaignManagerQueryMsg::RegisteredPrice { base, quote }) => {
+                let price = self.campaign_manager_config_querier.price_sources
+                    .get(&(base.to_string(), quote.to_string()))
+                    .map(|price| PriceResponse {
+                        price: *price,
+                        source: PriceSourceMsg::Fixed {
+                            price: *price,
+                        },
+                        updated_height: 0,
+                    });
+
+                Some(SystemResult::Ok(ContractResult::from(to_binary(&price))))
+            }
             Ok(_) => Some(QuerierResult::Err(SystemError::UnsupportedRequest {
                 kind: "handle_wasm_smart:campaign_manager".to_string(),
             })),
@@ -305,6 +423,8 @@ impl WasmMockQuerier {
             Ok(GovQueryMsg::ContractConfig {}) => {
                 let response = GovContractConfigResponse {
                     governance_token: self.governance_querier.token_contract.clone(),
+                    community: None,
+                    guardian: None,
                 };
 
                 Some(SystemResult::Ok(ContractResult::from(to_binary(&response))))
@@ -320,7 +440,12 @@ impl WasmMockQuerier {
                     }
                 };
 
-                let response = VotingPowerResponse { voting_power };
+                let response = VotingPowerResponse {
+                    voting_power,
+                    boost_voting_power: Decimal::zero(),
+                    delegated_in_voting_power: Decimal::zero(),
+                    delegated_out_voting_power: Decimal::zero(),
+                };
 
                 Some(SystemResult::Ok(ContractResult::from(to_binary(&response))))
             }
@@ -340,15 +465,34 @@ impl WasmMockQuerier {
     }
 
     fn handle_wasm_smart_campaign(&self, contract_addr: &String, msg: &Binary) -> Option<QuerierResult> {
-        if !self.campaign_state_querier.states.contains_key(contract_addr) {
+        if !self.campaign_state_querier.contains(contract_addr) {
             return None;
         }
 
         match from_binary(msg) {
             Ok(QueryMsg::CampaignState {}) => {
-                Some(SystemResult::Ok(ContractResult::from(to_binary(
-                    &self.campaign_state_querier.states[contract_addr],
-                ))))
+                match self.campaign_state_querier.states.get(contract_addr) {
+                    Some(state) => Some(SystemResult::Ok(ContractResult::from(to_binary(state)))),
+                    None => Some(QuerierResult::Err(SystemError::UnsupportedRequest {
+                        kind: "handle_wasm_smart:campaign".to_string(),
+                    })),
+                }
+            }
+            Ok(QueryMsg::RewardConfig {}) => {
+                match self.campaign_state_querier.reward_configs.get(contract_addr) {
+                    Some(config) => Some(SystemResult::Ok(ContractResult::from(to_binary(config)))),
+                    None => Some(QuerierResult::Err(SystemError::UnsupportedRequest {
+                        kind: "handle_wasm_smart:campaign".to_string(),
+                    })),
+                }
+            }
+            Ok(QueryMsg::Actor { address }) => {
+                let default = ActorResponse::new(address.clone(), None);
+                let response = self.campaign_state_querier.actors
+                    .get(&(contract_addr.to_string(), address))
+                    .unwrap_or(&default);
+
+                Some(SystemResult::Ok(ContractResult::from(to_binary(response))))
             }
             Ok(_) => Some(QuerierResult::Err(SystemError::UnsupportedRequest {
                 kind: "handle_wasm_smart:campaign".to_string(),
@@ -366,9 +510,11 @@ impl WasmMockQuerier {
             Ok(TerraswapRouterQueryMsg::SimulateSwapOperations { offer_amount, operations }) => {
                 let mut amount = offer_amount.u128();
                 for operation in operations.iter() {
-                    let price = self.terraswap_router_querier.prices
-                        .get(&terraswap_operation_to_string(operation))
-                        .unwrap();
+                    let price = match self.terraswap_router_querier.prices
+                        .get(&terraswap_operation_to_string(operation)) {
+                        Some(price) => price,
+                        None => return Some(SystemResult::Ok(ContractResult::Err("No pair".to_string()))),
+                    };
 
                     amount = (amount as f64 * *price) as u128;
                 }
@@ -386,6 +532,31 @@ impl WasmMockQuerier {
         }
     }
 
+    fn handle_wasm_smart_lp_staking(&self, contract_addr: &String, msg: &Binary) -> Option<QuerierResult> {
+        if contract_addr != LP_STAKING {
+            return None;
+        }
+
+        match from_binary(msg) {
+            Ok(LpStakingQueryMsg::StakerInfo { staker }) => {
+                let response = StakerInfoResponse {
+                    bond_amount: self.lp_staking_querier.bond_amounts.get(&staker)
+                        .cloned()
+                        .unwrap_or_default(),
+                    staker,
+                    reward_index: Decimal::zero(),
+                    pending_reward: Uint128::zero(),
+                };
+
+                Some(SystemResult::Ok(ContractResult::from(to_binary(&response))))
+            }
+            Ok(_) => Some(QuerierResult::Err(SystemError::UnsupportedRequest {
+                kind: "handle_wasm_smart:lp_staking".to_string(),
+            })),
+            Err(_) => None,
+        }
+    }
+
     fn handle_cw20(&self, contract_addr: &String, msg: &Binary) -> Option<QuerierResult> {
         match from_binary(msg) {
             Ok(Cw20QueryMsg::Balance { address }) => {
@@ -517,6 +688,7 @@ impl WasmMockQuerier {
             governance_querier: GovConfigQuerier::default(),
             campaign_state_querier: CampaignStateQuerier::default(),
             terraswap_router_querier: TerraswapRouterQuerier::default(),
+            lp_staking_querier: LpStakingQuerier::default(),
         }
     }
 
@@ -532,6 +704,29 @@ impl WasmMockQuerier {
         self.campaign_manager_config_querier.config = config;
     }
 
+    pub fn with_delisted_campaign(
+        &mut self,
+        campaign: &str,
+    ) {
+        self.campaign_manager_config_querier.delisted_campaigns.push(campaign.to_string());
+    }
+
+    pub fn with_add_pool_fee_tiers(
+        &mut self,
+        tiers: Vec<FeeTierMsg>,
+    ) {
+        self.campaign_manager_config_querier.fee_tiers = tiers;
+    }
+
+    pub fn with_price_source(
+        &mut self,
+        base: String,
+        quote: String,
+        price: Decimal,
+    ) {
+        self.campaign_manager_config_querier.price_sources.insert((base, quote), price);
+    }
+
     pub fn with_referral_reward_limit_option(
         &mut self,
         option: ReferralRewardLimitOptionResponse,
@@ -554,6 +749,14 @@ impl WasmMockQuerier {
         self.governance_querier.staker_state.insert(address.to_string(), state);
     }
 
+    pub fn with_lp_staker_bond_amount(
+        &mut self,
+        address: &str,
+        bond_amount: Uint128,
+    ) {
+        self.lp_staking_querier.bond_amounts.insert(address.to_string(), bond_amount);
+    }
+
     pub fn with_voting_powers(&mut self, powers: &[(&String, &Decimal)]) {
         self.voting_powers_querier = VotingPowerQuerier::new(powers);
     }
@@ -566,6 +769,22 @@ impl WasmMockQuerier {
         self.campaign_state_querier.states.insert(campaign, state);
     }
 
+    pub fn with_campaign_reward_config(
+        &mut self,
+        campaign: String,
+        config: RewardConfigResponse,
+    ) {
+        self.campaign_state_querier.reward_configs.insert(campaign, config);
+    }
+
+    pub fn with_campaign_actor(
+        &mut self,
+        campaign: String,
+        actor: ActorResponse,
+    ) {
+        self.campaign_state_querier.actors.insert((campaign, actor.address.clone()), actor);
+    }
+
     pub fn plus_token_balances(&mut self, balances: &[(&str, &[(&str, &Uint128)])]) {
         for (token_contract, balances) in balances.iter() {
             let token_contract = token_contract.to_string();
@@ -5,6 +5,7 @@ pub const DEFAULT_SENDER: &str = "DefaultSender";
 pub const CONTRACT_CREATOR: &str = "ContractCreator";
 pub const VALKYRIE_TOKEN: &str = "ValkyrieToken";
 pub const TERRASWAP_ROUTER: &str = "TerraswapRouter";
+pub const LP_STAKING: &str = "LpStaking";
 
 pub fn default_sender() -> MessageInfo {
     mock_info(DEFAULT_SENDER, &[])
@@ -29,6 +30,7 @@ pub mod governance {
 
     // common config
     pub const GOVERNANCE_TOKEN: &str = VALKYRIE_TOKEN;
+    pub const GUARDIAN: &str = "Guardian";
 
     // poll config
     pub const POLL_QUORUM_PERCENT: u64 = 30;
@@ -37,6 +39,13 @@ pub mod governance {
     pub const POLL_EXECUTION_DELAY_PERIOD: u64 = 10000u64;
     pub const POLL_PROPOSAL_DEPOSIT: Uint128 = Uint128::new(10000000000u128);
     pub const POLL_SNAPSHOT_PERIOD: u64 = 10u64;
+    pub const POLL_CANCEL_GRACE_PERIOD: u64 = 100u64;
+    pub const POLL_CANCEL_FEE_PERCENT: u64 = 10;
+    pub const POLL_EXECUTION_RETRY_PERIOD: u64 = 1000u64;
+
+    // staking config
+    pub const STAKING_MAX_LOCK_DURATION: u64 = 1000u64;
+    pub const STAKING_MAX_LOCK_MULTIPLIER_PERCENT: u64 = 250;
 
     pub fn governance_env() -> Env {
         mock_env_contract(GOVERNANCE)
@@ -99,7 +108,7 @@ pub mod distributor {
 }
 
 pub mod campaign_manager {
-    use cosmwasm_std::{Env, MessageInfo};
+    use cosmwasm_std::{Env, MessageInfo, Uint128};
     use cosmwasm_std::testing::mock_info;
 
     use crate::test_utils::mock_env_contract;
@@ -117,6 +126,10 @@ pub mod campaign_manager {
     pub const KEY_DENOM_NATIVE: &str = "uusd";
     pub const REFERRAL_REWARD_LIMIT_BASE_COUNT: u8 = 5;
     pub const REFERRAL_REWARD_LIMIT_STAKING_PERCENT: u16 = 50;
+    pub const CREATION_FEE_DENOM_NATIVE: &str = "uusd";
+    pub const CREATION_FEE_AMOUNT: Uint128 = Uint128::new(100);
+    pub const SWAP_FEE_MAX_SPREAD_PERCENT: u64 = 1;
+    pub const SWAP_FEE_KEEPER: &str = "SwapFeeKeeper";
 
     pub fn campaign_manager_env() -> Env {
         mock_env_contract(CAMPAIGN_MANAGER)
@@ -125,6 +138,10 @@ pub mod campaign_manager {
     pub fn campaign_manager_sender() -> MessageInfo {
         mock_info(CAMPAIGN_MANAGER, &[])
     }
+
+    pub fn swap_fee_keeper_sender() -> MessageInfo {
+        mock_info(SWAP_FEE_KEEPER, &[])
+    }
 }
 
 pub mod campaign {